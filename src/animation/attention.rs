@@ -0,0 +1,272 @@
+//! Animations that briefly draw attention to a mobject.
+
+use crate::core::{Color, Vector2D};
+use crate::mobject::geometry::Line;
+use crate::mobject::{Mobject, MobjectGroup};
+
+use super::easing::there_and_back;
+use super::{Animation, Scale};
+
+/// Briefly enlarges and tints a mobject, then returns it to normal.
+///
+/// Both effects follow [`there_and_back`]: they peak halfway through and are
+/// fully undone at the end, so the mobject finishes exactly as it started.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::{Animation, Indicate};
+/// use manim_rs::mobject::geometry::Circle;
+/// use manim_rs::mobject::Mobject;
+///
+/// let mut circle = Circle::builder().radius(1.0).no_stroke().build();
+/// let mut indicate = Indicate::new(1.0).scale_factor(1.5);
+///
+/// indicate.begin(&circle);
+/// indicate.interpolate(&mut circle, 0.5);
+/// assert!((circle.bounding_box().width() - 3.0).abs() < 1e-10);
+/// indicate.interpolate(&mut circle, 1.0);
+/// assert!((circle.bounding_box().width() - 2.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Indicate {
+    color: Color,
+    duration: f64,
+    scale: Scale,
+}
+
+impl Indicate {
+    /// Creates an indication lasting `duration` seconds that scales by 1.2
+    /// and tints yellow at its peak.
+    pub fn new(duration: f64) -> Self {
+        Self {
+            color: Color::YELLOW,
+            duration,
+            scale: Scale::new(1.2, duration),
+        }
+    }
+
+    /// Sets the scale reached at the peak of the pulse.
+    pub fn scale_factor(mut self, factor: f64) -> Self {
+        self.scale = Scale::new(factor, self.duration);
+        self
+    }
+
+    /// Sets the tint color reached at the peak of the pulse.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl Animation for Indicate {
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn begin(&mut self, mobject: &dyn Mobject) {
+        self.scale.begin(mobject);
+    }
+
+    fn interpolate(&mut self, mobject: &mut dyn Mobject, alpha: f64) {
+        let pulse = there_and_back(alpha);
+        self.scale.interpolate(mobject, pulse);
+        mobject.set_tint(self.color, pulse);
+    }
+}
+
+/// Radial lines bursting outward from a point.
+///
+/// [`Flash::lines`] builds the burst: `n_lines` evenly spaced segments
+/// running from half of `radius` out to `radius`. Animating that group with
+/// the flash pushes the lines outward by half again while fading them out.
+///
+/// A flash never changes the mobject it highlights: add the group from
+/// [`Flash::lines`] to the scene and play the flash on that group.
+/// [`Flash::around`] places the burst just outside an existing mobject.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::{Animation, Flash};
+/// use manim_rs::core::Vector2D;
+/// use manim_rs::mobject::Mobject;
+///
+/// let mut flash = Flash::new(Vector2D::ZERO, 1.0, 8, 0.5);
+/// let mut lines = flash.lines();
+/// assert_eq!(lines.len(), 8);
+///
+/// flash.begin(&lines);
+/// flash.interpolate(&mut lines, 1.0);
+/// assert_eq!(lines.opacity(), 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Flash {
+    center: Vector2D,
+    radius: f64,
+    n_lines: usize,
+    color: Color,
+    duration: f64,
+    scale: Scale,
+    start_opacity: Option<f64>,
+}
+
+impl Flash {
+    /// Creates a yellow flash of `n_lines` lines around `center` lasting
+    /// `duration` seconds.
+    pub fn new(center: Vector2D, radius: f64, n_lines: usize, duration: f64) -> Self {
+        Self {
+            center,
+            radius,
+            n_lines,
+            color: Color::YELLOW,
+            duration,
+            scale: Scale::new(1.5, duration).about(center),
+            start_opacity: None,
+        }
+    }
+
+    /// Creates a flash around `target`, centered on its bounding box.
+    ///
+    /// The lines start on the circle through the corners of the box, so
+    /// they clear the target, and reach twice as far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::animation::Flash;
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Square;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut square = Square::new(2.0);
+    /// square.set_position(Vector2D::new(3.0, 0.0));
+    ///
+    /// let flash = Flash::around(&square, 8, 0.5);
+    /// // Every line starts outside the square's corners
+    /// for point in flash.lines().get_points() {
+    ///     assert!((point - Vector2D::new(3.0, 0.0)).magnitude() > 1.4);
+    /// }
+    /// ```
+    pub fn around(target: &dyn Mobject, n_lines: usize, duration: f64) -> Self {
+        let bounds = target.bounding_box();
+        Self::new(bounds.center(), bounds.diagonal(), n_lines, duration)
+    }
+
+    /// Sets the color of the lines.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Builds the line segments to animate.
+    pub fn lines(&self) -> MobjectGroup {
+        let mut group = MobjectGroup::new();
+        let step = std::f64::consts::TAU / self.n_lines.max(1) as f64;
+        for i in 0..self.n_lines {
+            let angle = step * i as f64;
+            let direction = Vector2D::new(angle.cos(), angle.sin());
+            let mut line = Line::new(
+                self.center + direction * (self.radius * 0.5),
+                self.center + direction * self.radius,
+            );
+            line.set_color(self.color);
+            group.add(Box::new(line));
+        }
+        group
+    }
+}
+
+impl Animation for Flash {
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn begin(&mut self, mobject: &dyn Mobject) {
+        self.scale.begin(mobject);
+        self.start_opacity = Some(mobject.opacity());
+    }
+
+    fn interpolate(&mut self, mobject: &mut dyn Mobject, alpha: f64) {
+        let start_opacity = *self.start_opacity.get_or_insert_with(|| mobject.opacity());
+        self.scale.interpolate(mobject, alpha);
+        mobject.set_opacity(start_opacity * (1.0 - alpha));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::BoundingBox;
+    use crate::mobject::geometry::Circle;
+    use crate::scene::snapshot::SnapshotRenderer;
+    use approx::assert_relative_eq;
+
+    fn snapshot(mobject: &dyn Mobject) -> String {
+        let mut renderer = SnapshotRenderer::new(100, 100);
+        mobject.render(&mut renderer).unwrap();
+        renderer.finish()
+    }
+
+    #[test]
+    fn test_indicate_restores_size_and_color() {
+        let mut circle = Circle::builder()
+            .radius(1.0)
+            .no_stroke()
+            .fill_color(Color::BLUE)
+            .build();
+        let before = snapshot(&circle);
+        let mut indicate = Indicate::new(1.0).color(Color::RED);
+
+        indicate.begin(&circle);
+        indicate.interpolate(&mut circle, 0.5);
+        assert_relative_eq!(circle.bounding_box().width(), 2.4, epsilon = 1e-10);
+        assert!(snapshot(&circle).contains(&Color::RED.to_hex_rgba()));
+
+        indicate.interpolate(&mut circle, 1.0);
+        assert_relative_eq!(circle.bounding_box().width(), 2.0, epsilon = 1e-10);
+        assert_eq!(snapshot(&circle), before);
+    }
+
+    #[test]
+    fn test_flash_generates_requested_lines() {
+        let flash = Flash::new(Vector2D::new(1.0, 1.0), 2.0, 12, 1.0);
+        let lines = flash.lines();
+        assert_eq!(lines.len(), 12);
+        for line in lines.iter() {
+            let distance = (line.bounding_box().center() - Vector2D::new(1.0, 1.0)).magnitude();
+            assert!(distance > 1.0 && distance < 2.0);
+        }
+    }
+
+    #[test]
+    fn test_flash_fades_outward() {
+        let mut flash = Flash::new(Vector2D::ZERO, 1.0, 4, 1.0);
+        let mut lines = flash.lines();
+        let extent = |group: &MobjectGroup| {
+            BoundingBox::from_points(group.get_points())
+                .unwrap()
+                .width()
+        };
+        let width = extent(&lines);
+
+        flash.begin(&lines);
+        flash.interpolate(&mut lines, 1.0);
+        assert_relative_eq!(extent(&lines), width * 1.5, epsilon = 1e-10);
+        assert_eq!(lines.opacity(), 0.0);
+    }
+
+    #[test]
+    fn test_flash_around_clears_target() {
+        let mut circle = Circle::new(1.0);
+        circle.set_position(Vector2D::new(-2.0, 1.0));
+        let flash = Flash::around(&circle, 6, 1.0);
+
+        for line in flash.lines().iter() {
+            for point in line.get_points() {
+                let distance = (point - Vector2D::new(-2.0, 1.0)).magnitude();
+                assert!(distance >= 1.0 - 1e-10);
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Animations that draw a mobject onto the scene.
+
+use crate::mobject::Mobject;
+
+use super::Animation;
+
+/// Portion of a [`Write`] spent tracing the outline before the fill fades in.
+const STROKE_PORTION: f64 = 0.5;
+
+/// Draws a mobject as if by hand: the outline is traced first, then the fill
+/// fades in.
+///
+/// Over the first half of the animation the outline is revealed along its
+/// length; over the second half the fill opacity rises from zero. Text is
+/// written out character by character during the first half. At the end the
+/// mobject is shown exactly as styled.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::{Animation, Write};
+/// use manim_rs::mobject::geometry::Circle;
+///
+/// let mut circle = Circle::new(1.0);
+/// let mut write = Write::new(1.0);
+///
+/// write.begin(&circle);
+/// write.interpolate(&mut circle, 0.25);
+/// write.interpolate(&mut circle, 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Write {
+    duration: f64,
+}
+
+impl Write {
+    /// Creates a write lasting `duration` seconds.
+    pub fn new(duration: f64) -> Self {
+        Self { duration }
+    }
+}
+
+impl Animation for Write {
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn interpolate(&mut self, mobject: &mut dyn Mobject, alpha: f64) {
+        let stroke = (alpha / STROKE_PORTION).clamp(0.0, 1.0);
+        let fill = ((alpha - STROKE_PORTION) / (1.0 - STROKE_PORTION)).clamp(0.0, 1.0);
+        mobject.set_reveal(stroke, fill);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::{DrawCommand, RecordingRenderer};
+    use crate::core::Color;
+    use crate::mobject::geometry::Square;
+    use crate::mobject::Text;
+    use crate::renderer::{Path, PathStyle, TextStyle};
+    use crate::scene::snapshot::SnapshotRenderer;
+
+    fn record(mobject: &dyn Mobject) -> Vec<DrawCommand> {
+        let mut renderer = RecordingRenderer::new(100, 100);
+        mobject.render(&mut renderer).unwrap();
+        renderer.commands().to_vec()
+    }
+
+    fn paths(mobject: &dyn Mobject) -> Vec<(Path, PathStyle)> {
+        record(mobject)
+            .into_iter()
+            .filter_map(|command| match command {
+                DrawCommand::Path { path, style } => Some((*path, style)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn texts(mobject: &dyn Mobject) -> Vec<String> {
+        record(mobject)
+            .into_iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn snapshot(mobject: &dyn Mobject) -> String {
+        let mut renderer = SnapshotRenderer::new(100, 100);
+        mobject.render(&mut renderer).unwrap();
+        renderer.finish()
+    }
+
+    #[test]
+    fn test_write_traces_outline_then_fills() {
+        let mut square = Square::builder()
+            .side_length(2.0)
+            .fill_color(Color::BLUE)
+            .build();
+        let before = snapshot(&square);
+        let full_length = paths(&square)[0].0.len();
+        let mut write = Write::new(1.0);
+
+        write.begin(&square);
+        write.interpolate(&mut square, 0.0);
+        assert!(paths(&square).is_empty());
+
+        write.interpolate(&mut square, 0.25);
+        let drawn = paths(&square);
+        let (path, style) = &drawn[0];
+        assert!(path.len() < full_length);
+        assert_eq!(style.fill_opacity(), 0.0);
+
+        write.interpolate(&mut square, 0.75);
+        let (path, style) = &paths(&square)[0];
+        assert_eq!(path.len(), full_length);
+        assert_eq!(style.fill_opacity(), 0.5);
+
+        write.interpolate(&mut square, 1.0);
+        assert_eq!(snapshot(&square), before);
+    }
+
+    #[test]
+    fn test_write_reveals_text_per_character() {
+        let mut text = Text::new("abcd", TextStyle::default());
+        let mut write = Write::new(1.0);
+
+        write.begin(&text);
+        write.interpolate(&mut text, 0.0);
+        assert!(texts(&text).is_empty());
+
+        write.interpolate(&mut text, 0.25);
+        assert_eq!(texts(&text), vec!["ab"]);
+
+        write.interpolate(&mut text, 1.0);
+        assert_eq!(texts(&text), vec!["abcd"]);
+    }
+}
@@ -0,0 +1,80 @@
+//! Easing (rate) functions.
+//!
+//! Each function maps linear progress `t` in [0, 1] to eased progress. Inputs
+//! outside that range are clamped.
+
+use crate::utils::clamp;
+
+/// Returns `t` unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::easing::linear;
+///
+/// assert_eq!(linear(0.25), 0.25);
+/// ```
+#[inline]
+pub fn linear(t: f64) -> f64 {
+    clamp(t, 0.0, 1.0)
+}
+
+/// Eases in and out with a cubic smoothstep.
+///
+/// Starts and ends with zero velocity and passes through 0.5 at the midpoint.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::easing::smooth;
+///
+/// assert_eq!(smooth(0.0), 0.0);
+/// assert_eq!(smooth(0.5), 0.5);
+/// assert_eq!(smooth(1.0), 1.0);
+/// ```
+#[inline]
+pub fn smooth(t: f64) -> f64 {
+    let t = clamp(t, 0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Rises smoothly to 1 at the midpoint and falls back to 0 at the end.
+///
+/// Animations driven by it end where they started, which suits temporary
+/// effects such as pulses and highlights.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::easing::there_and_back;
+///
+/// assert_eq!(there_and_back(0.0), 0.0);
+/// assert_eq!(there_and_back(0.5), 1.0);
+/// assert_eq!(there_and_back(1.0), 0.0);
+/// ```
+#[inline]
+pub fn there_and_back(t: f64) -> f64 {
+    let t = clamp(t, 0.0, 1.0);
+    smooth(if t < 0.5 { 2.0 * t } else { 2.0 - 2.0 * t })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_easing_clamps_input() {
+        assert_eq!(linear(-1.0), 0.0);
+        assert_eq!(smooth(2.0), 1.0);
+        assert_eq!(there_and_back(1.5), 0.0);
+    }
+
+    #[test]
+    fn test_there_and_back_is_symmetric() {
+        for t in [0.1, 0.2, 0.3, 0.45] {
+            assert_relative_eq!(there_and_back(t), there_and_back(1.0 - t), epsilon = 1e-12);
+        }
+        assert!(there_and_back(0.25) < there_and_back(0.4));
+    }
+}
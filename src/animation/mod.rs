@@ -1,8 +1,57 @@
-//! Animation primitives and timing functions.
+//! Animation primitives.
 //!
-//! This module will provide the core animation system, including:
-//! - Timeline management
-//! - Easing functions
-//! - Animation composition
+//! An [`Animation`] changes a mobject over time. Callers drive it with a
+//! progress value `alpha` that runs from 0.0 at the start to 1.0 at the end;
+//! timing and easing are applied by whoever maps time to `alpha`.
+//!
+//! # Examples
+//!
+//! ```
+//! use manim_rs::animation::{Animation, Shift};
+//! use manim_rs::core::Vector2D;
+//! use manim_rs::mobject::geometry::Circle;
+//! use manim_rs::mobject::Mobject;
+//!
+//! let mut circle = Circle::new(1.0);
+//! let mut shift = Shift::new(Vector2D::new(4.0, 0.0), 1.0);
+//!
+//! shift.begin(&circle);
+//! shift.interpolate(&mut circle, 0.5);
+//! assert_eq!(circle.position(), Vector2D::new(2.0, 0.0));
+//! shift.interpolate(&mut circle, 1.0);
+//! assert_eq!(circle.position(), Vector2D::new(4.0, 0.0));
+//! ```
+
+mod attention;
+mod creation;
+pub mod easing;
+mod transform;
+
+pub use attention::{Flash, Indicate};
+pub use creation::Write;
+pub use transform::{Rotate, Scale, Shift};
+
+use crate::mobject::Mobject;
+
+/// A change applied to a mobject over time.
+///
+/// Call [`begin`](Animation::begin) once with the mobject in its starting
+/// state, then [`interpolate`](Animation::interpolate) with any sequence of
+/// progress values. Each call leaves the mobject in the state for that
+/// progress, measured from the starting state rather than from the previous
+/// call, so frames can be skipped or repeated without drift.
+pub trait Animation: Send + Sync {
+    /// Returns the duration of the animation in seconds.
+    fn duration(&self) -> f64;
+
+    /// Captures the starting state of `mobject`.
+    ///
+    /// Calling it again restarts the animation from the mobject's current
+    /// state. The default implementation does nothing.
+    fn begin(&mut self, _mobject: &dyn Mobject) {}
 
-// TODO: Implement animation system
+    /// Updates `mobject` to the state at progress `alpha`.
+    ///
+    /// `alpha` runs from 0.0 (the starting state) to 1.0 (the final state).
+    fn interpolate(&mut self, mobject: &mut dyn Mobject, alpha: f64);
+}
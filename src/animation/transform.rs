@@ -0,0 +1,374 @@
+//! Animations that move, turn, or resize a mobject.
+//!
+//! [`Rotate`], [`Scale`] and [`Shift`] all remember the transform they have
+//! applied since [`Animation::begin`]. Each frame undoes it and applies the
+//! target transform for `alpha` in a single step, so the mobject always ends
+//! up at the starting state transformed by the target, however the frames
+//! are sampled.
+
+use crate::core::{Radians, Transform, Vector2D};
+use crate::mobject::Mobject;
+
+use super::Animation;
+
+/// Smallest scale factor [`Scale`] applies.
+///
+/// A scale by zero collapses the mobject and cannot be undone, so factors
+/// closer to zero are pushed out to this size, keeping the same sign.
+const MIN_SCALE: f64 = 1e-6;
+
+/// The transform an animation has applied since it began.
+#[derive(Debug, Clone, Copy)]
+struct Applied(Transform);
+
+impl Applied {
+    fn new() -> Self {
+        Self(Transform::identity())
+    }
+
+    /// Moves `mobject` from the transform applied so far to `target`.
+    fn apply(&mut self, mobject: &mut dyn Mobject, target: Transform) {
+        let undo = self
+            .0
+            .inverse()
+            .expect("animation transforms are never singular");
+        mobject.apply_transform(&(target * undo));
+        self.0 = target;
+    }
+}
+
+/// Returns a rotation by `angle` radians about `pivot`.
+fn rotate_about(angle: f64, pivot: Vector2D) -> Transform {
+    Transform::translate(pivot.x, pivot.y)
+        * Transform::rotate(angle)
+        * Transform::translate(-pivot.x, -pivot.y)
+}
+
+/// Rotates a mobject by an angle about a pivot.
+///
+/// The pivot defaults to the center of the mobject's bounding box when the
+/// animation begins. Angles beyond a full turn spin the mobject that many
+/// times.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::{Animation, Rotate};
+/// use manim_rs::core::{Radians, Vector2D};
+/// use manim_rs::mobject::geometry::Line;
+/// use manim_rs::mobject::Mobject;
+///
+/// let mut line = Line::new(Vector2D::new(1.0, 0.0), Vector2D::new(2.0, 0.0));
+/// let mut rotate = Rotate::new(Radians(std::f64::consts::PI), 1.0).about(Vector2D::ZERO);
+///
+/// rotate.begin(&line);
+/// rotate.interpolate(&mut line, 1.0);
+/// assert!((line.bounding_box().center().x + 1.5).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rotate {
+    angle: Radians,
+    about: Option<Vector2D>,
+    duration: f64,
+    pivot: Option<Vector2D>,
+    applied: Applied,
+}
+
+impl Rotate {
+    /// Creates a rotation by `angle`, counter-clockwise for positive angles,
+    /// lasting `duration` seconds.
+    pub fn new(angle: Radians, duration: f64) -> Self {
+        Self {
+            angle,
+            about: None,
+            duration,
+            pivot: None,
+            applied: Applied::new(),
+        }
+    }
+
+    /// Rotates about a fixed point instead of the mobject's center.
+    pub fn about(mut self, point: Vector2D) -> Self {
+        self.about = Some(point);
+        self
+    }
+}
+
+impl Animation for Rotate {
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn begin(&mut self, mobject: &dyn Mobject) {
+        self.pivot = Some(
+            self.about
+                .unwrap_or_else(|| mobject.bounding_box().center()),
+        );
+        self.applied = Applied::new();
+    }
+
+    fn interpolate(&mut self, mobject: &mut dyn Mobject, alpha: f64) {
+        let pivot = *self
+            .pivot
+            .get_or_insert_with(|| self.about.unwrap_or(mobject.bounding_box().center()));
+        // Interpolate the angle itself: `Transform::lerp` takes the shortest
+        // path, which would collapse a full turn to no rotation at all
+        let target = rotate_about(self.angle.0 * alpha, pivot);
+        self.applied.apply(mobject, target);
+    }
+}
+
+/// Scales a mobject uniformly about a pivot.
+///
+/// The pivot defaults to the center of the mobject's bounding box when the
+/// animation begins. Factors of 0 or below pass through a collapsed state,
+/// which no transform can undo, so frames near it shrink the mobject to a
+/// millionth of its size instead of a single point.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::{Animation, Scale};
+/// use manim_rs::mobject::geometry::Square;
+/// use manim_rs::mobject::Mobject;
+///
+/// let mut square = Square::builder().side_length(2.0).no_stroke().build();
+/// let mut scale = Scale::new(3.0, 1.0);
+///
+/// scale.begin(&square);
+/// scale.interpolate(&mut square, 1.0);
+/// assert!((square.bounding_box().width() - 6.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scale {
+    factor: f64,
+    about: Option<Vector2D>,
+    duration: f64,
+    pivot: Option<Vector2D>,
+    applied: Applied,
+}
+
+impl Scale {
+    /// Creates a uniform scale by `factor` lasting `duration` seconds.
+    pub fn new(factor: f64, duration: f64) -> Self {
+        Self {
+            factor,
+            about: None,
+            duration,
+            pivot: None,
+            applied: Applied::new(),
+        }
+    }
+
+    /// Scales about a fixed point instead of the mobject's center.
+    pub fn about(mut self, point: Vector2D) -> Self {
+        self.about = Some(point);
+        self
+    }
+}
+
+impl Animation for Scale {
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn begin(&mut self, mobject: &dyn Mobject) {
+        self.pivot = Some(
+            self.about
+                .unwrap_or_else(|| mobject.bounding_box().center()),
+        );
+        self.applied = Applied::new();
+    }
+
+    fn interpolate(&mut self, mobject: &mut dyn Mobject, alpha: f64) {
+        let pivot = *self
+            .pivot
+            .get_or_insert_with(|| self.about.unwrap_or(mobject.bounding_box().center()));
+        let mut factor = 1.0 + (self.factor - 1.0) * alpha;
+        if factor.abs() < MIN_SCALE {
+            factor = MIN_SCALE.copysign(factor);
+        }
+        let target = Transform::scale_about(factor, factor, pivot);
+        self.applied.apply(mobject, target);
+    }
+}
+
+/// Moves a mobject by a fixed offset.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::animation::{Animation, Shift};
+/// use manim_rs::core::Vector2D;
+/// use manim_rs::mobject::geometry::Circle;
+/// use manim_rs::mobject::Mobject;
+///
+/// let mut circle = Circle::new(1.0);
+/// let mut shift = Shift::new(Vector2D::new(0.0, 2.0), 0.5);
+///
+/// shift.begin(&circle);
+/// shift.interpolate(&mut circle, 1.0);
+/// assert_eq!(circle.position(), Vector2D::new(0.0, 2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Shift {
+    delta: Vector2D,
+    duration: f64,
+    applied: Applied,
+}
+
+impl Shift {
+    /// Creates a shift by `delta` lasting `duration` seconds.
+    pub fn new(delta: Vector2D, duration: f64) -> Self {
+        Self {
+            delta,
+            duration,
+            applied: Applied::new(),
+        }
+    }
+}
+
+impl Animation for Shift {
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn begin(&mut self, _mobject: &dyn Mobject) {
+        self.applied = Applied::new();
+    }
+
+    fn interpolate(&mut self, mobject: &mut dyn Mobject, alpha: f64) {
+        let target = self.delta * alpha;
+        self.applied
+            .apply(mobject, Transform::translate(target.x, target.y));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mobject::geometry::Polygon;
+    use std::f64::consts::TAU;
+
+    fn triangle() -> Polygon {
+        Polygon::new(vec![
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(3.0, 1.0),
+            Vector2D::new(2.0, 4.0),
+        ])
+    }
+
+    fn assert_points_close(actual: &[Vector2D], expected: &[Vector2D]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((*a - *e).magnitude() < 1e-9, "{} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn test_rotate_full_turn_returns_to_start() {
+        let mut polygon = triangle();
+        let start = polygon.vertices().to_vec();
+        let mut rotate = Rotate::new(Radians(TAU), 1.0);
+
+        rotate.begin(&polygon);
+        rotate.interpolate(&mut polygon, 0.5);
+        // Halfway through a full turn the triangle is upside down
+        assert!((polygon.vertices()[0] - start[0]).magnitude() > 1.0);
+
+        rotate.interpolate(&mut polygon, 1.0);
+        assert_points_close(polygon.vertices(), &start);
+    }
+
+    #[test]
+    fn test_rotate_does_not_accumulate() {
+        let mut once = triangle();
+        let mut repeated = triangle();
+        let mut a = Rotate::new(Radians(1.0), 1.0).about(Vector2D::ZERO);
+        let mut b = a.clone();
+
+        a.begin(&once);
+        a.interpolate(&mut once, 0.75);
+
+        b.begin(&repeated);
+        for alpha in [0.25, 0.75, 0.75, 0.5, 0.75] {
+            b.interpolate(&mut repeated, alpha);
+        }
+        assert_points_close(repeated.vertices(), once.vertices());
+    }
+
+    #[test]
+    fn test_scale_about_center() {
+        let mut polygon = triangle();
+        let center = polygon.bounding_box().center();
+        let mut scale = Scale::new(2.0, 1.0);
+
+        scale.begin(&polygon);
+        scale.interpolate(&mut polygon, 0.5);
+        scale.interpolate(&mut polygon, 1.0);
+
+        let expected: Vec<Vector2D> = triangle()
+            .vertices()
+            .iter()
+            .map(|v| center + (*v - center) * 2.0)
+            .collect();
+        assert_points_close(polygon.vertices(), &expected);
+
+        scale.interpolate(&mut polygon, 0.0);
+        assert_points_close(polygon.vertices(), triangle().vertices());
+    }
+
+    fn scaled_triangle(factor: f64) -> Vec<Vector2D> {
+        let center = triangle().bounding_box().center();
+        triangle()
+            .vertices()
+            .iter()
+            .map(|v| center + (*v - center) * factor)
+            .collect()
+    }
+
+    #[test]
+    fn test_scale_negative_factor() {
+        let mut polygon = triangle();
+        let mut scale = Scale::new(-2.0, 1.0);
+
+        scale.begin(&polygon);
+        scale.interpolate(&mut polygon, 0.25);
+        assert_points_close(polygon.vertices(), &scaled_triangle(0.25));
+        scale.interpolate(&mut polygon, 1.0);
+        assert_points_close(polygon.vertices(), &scaled_triangle(-2.0));
+    }
+
+    #[test]
+    fn test_scale_through_zero_recovers() {
+        let mut polygon = triangle();
+        let mut scale = Scale::new(-1.0, 1.0);
+
+        scale.begin(&polygon);
+        scale.interpolate(&mut polygon, 0.5);
+        // Collapsed as far as can still be undone
+        let center = triangle().bounding_box().center();
+        for vertex in polygon.vertices() {
+            assert!((*vertex - center).magnitude() < 1e-5);
+        }
+
+        scale.interpolate(&mut polygon, 1.0);
+        assert_points_close(polygon.vertices(), &scaled_triangle(-1.0));
+        scale.interpolate(&mut polygon, 0.0);
+        assert_points_close(polygon.vertices(), triangle().vertices());
+    }
+
+    #[test]
+    fn test_shift_interpolates_linearly() {
+        let mut polygon = triangle();
+        let mut shift = Shift::new(Vector2D::new(4.0, -2.0), 2.0);
+        assert_eq!(shift.duration(), 2.0);
+
+        shift.begin(&polygon);
+        shift.interpolate(&mut polygon, 0.25);
+        assert_points_close(&polygon.vertices()[..1], &[Vector2D::new(2.0, -0.5)]);
+
+        shift.interpolate(&mut polygon, 1.0);
+        assert_points_close(&polygon.vertices()[..1], &[Vector2D::new(5.0, -2.0)]);
+    }
+}
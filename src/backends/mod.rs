@@ -1,6 +1,7 @@
 //! Rendering backend implementations.
 //!
 //! This module provides concrete implementations for different output formats:
+//! - **Recording** - Captures draw calls for inspection and replay (always available)
 //! - **SVG** - Vector graphics (behind `svg` feature flag)
 //! - **Raster** - Bitmap rendering via tiny-skia (behind `raster` feature flag)
 //!
@@ -51,10 +52,13 @@
 //! # }
 //! ```
 
+mod recording;
 #[cfg(feature = "svg")]
 mod svg;
+
+pub use recording::{DrawCommand, RecordingRenderer};
 #[cfg(feature = "svg")]
-pub use svg::SvgRenderer;
+pub use svg::{CoordinateSystem, SvgRenderer};
 
 #[cfg(feature = "raster")]
 mod raster;
@@ -47,8 +47,10 @@
 
 use std::fs;
 
-use crate::core::{Color, Error, Result, Vector2D};
-use crate::renderer::{Path, PathStyle, Renderer, TextStyle};
+use crate::core::{Color, Error, Result, Transform, Vector2D};
+use crate::renderer::{
+    Path, PathStyle, RenderConfig, Renderer, RendererCapabilities, TextStyle, TransformStack,
+};
 
 mod path_converter;
 mod style_converter;
@@ -83,6 +85,11 @@ pub struct RasterRenderer {
     width: u32,
     height: u32,
     pixmap: tiny_skia::Pixmap,
+    /// Opacities of the currently open groups, innermost last
+    group_opacities: Vec<f64>,
+    /// Transforms pushed through the default `push_transform`
+    transforms: TransformStack,
+    config: RenderConfig,
 }
 
 impl RasterRenderer {
@@ -104,15 +111,118 @@ impl RasterRenderer {
     /// # Panics
     ///
     /// Panics if the dimensions are invalid (zero or too large for allocation).
+    /// Use [`try_new`](RasterRenderer::try_new) for user-supplied sizes.
     pub fn new(width: u32, height: u32) -> Self {
-        let pixmap = tiny_skia::Pixmap::new(width, height)
-            .expect("Failed to allocate pixmap - dimensions may be invalid");
+        Self::try_new(width, height).expect("Failed to allocate pixmap - dimensions may be invalid")
+    }
 
-        Self {
+    /// Creates a new raster renderer, failing on invalid dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if either dimension is zero or the
+    /// pixmap would be too large to allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::RasterRenderer;
+    ///
+    /// # #[cfg(feature = "raster")]
+    /// # {
+    /// assert!(RasterRenderer::try_new(0, 100).is_err());
+    /// assert!(RasterRenderer::try_new(100, 100).is_ok());
+    /// # }
+    /// ```
+    pub fn try_new(width: u32, height: u32) -> Result<Self> {
+        Self::try_with_config(width, height, RenderConfig::default())
+    }
+
+    /// Creates a new raster renderer with the given quality settings.
+    ///
+    /// The pixmap holds `width * pixel_ratio` by `height * pixel_ratio`
+    /// pixels, while [`dimensions`](Renderer::dimensions) and scene
+    /// coordinates stay at `width` by `height`. Setting
+    /// [`antialias`](RenderConfig::antialias) to `false` draws hard
+    /// pixel edges. SVG precision is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::RasterRenderer;
+    /// use manim_rs::renderer::{RenderConfig, Renderer};
+    ///
+    /// # #[cfg(feature = "raster")]
+    /// # {
+    /// let config = RenderConfig {
+    ///     pixel_ratio: 2.0,
+    ///     ..RenderConfig::default()
+    /// };
+    /// let renderer = RasterRenderer::with_config(400, 300, config);
+    /// assert_eq!(renderer.dimensions(), (400, 300));
+    /// assert_eq!(renderer.pixmap().width(), 800);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scaled dimensions are invalid. Use
+    /// [`try_with_config`](RasterRenderer::try_with_config) for
+    /// user-supplied settings.
+    pub fn with_config(width: u32, height: u32, config: RenderConfig) -> Self {
+        Self::try_with_config(width, height, config)
+            .expect("Failed to allocate pixmap - dimensions may be invalid")
+    }
+
+    /// Creates a new raster renderer with the given quality settings,
+    /// failing on invalid dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the pixel ratio is not a
+    /// positive finite number, or if the scaled pixmap would be empty or
+    /// too large to allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::RasterRenderer;
+    /// use manim_rs::renderer::RenderConfig;
+    ///
+    /// # #[cfg(feature = "raster")]
+    /// # {
+    /// let config = RenderConfig {
+    ///     pixel_ratio: 0.0,
+    ///     ..RenderConfig::default()
+    /// };
+    /// assert!(RasterRenderer::try_with_config(100, 100, config).is_err());
+    /// # }
+    /// ```
+    pub fn try_with_config(width: u32, height: u32, config: RenderConfig) -> Result<Self> {
+        let ratio = config.pixel_ratio;
+        if !(ratio.is_finite() && ratio > 0.0) {
+            return Err(Error::InvalidArgument(format!(
+                "Pixel ratio must be positive and finite, got {}",
+                ratio
+            )));
+        }
+        let pixel_width = (width as f64 * ratio).round() as u32;
+        let pixel_height = (height as f64 * ratio).round() as u32;
+        let pixmap = tiny_skia::Pixmap::new(pixel_width, pixel_height).ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "Invalid raster dimensions {}x{}",
+                pixel_width, pixel_height
+            ))
+        })?;
+
+        Ok(Self {
             width,
             height,
             pixmap,
-        }
+            group_opacities: Vec::new(),
+            transforms: TransformStack::new(),
+            config,
+        })
     }
 
     /// Saves the rendered image as a PNG file.
@@ -162,33 +272,37 @@ impl RasterRenderer {
         self.pixmap.data()
     }
 
-    /// Converts screen coordinates to pixmap coordinates.
-    ///
-    /// Manim uses centered coordinates with Y-up, while pixmap uses top-left origin with Y-down.
-    #[allow(dead_code)]
-    #[inline]
-    fn to_pixmap_coords(&self, x: f64, y: f64) -> (f32, f32) {
-        let half_width = self.width as f64 / 2.0;
-        let half_height = self.height as f64 / 2.0;
-
-        // Convert from centered coords with Y-up to top-left with Y-down
-        let px = (x + half_width) as f32;
-        let py = (half_height - y) as f32;
-
-        (px, py)
-    }
-
     /// Creates a transform for converting from manim coordinates to pixmap coordinates.
     fn create_transform(&self) -> tiny_skia::Transform {
         let half_width = self.width as f32 / 2.0;
         let half_height = self.height as f32 / 2.0;
-
-        // Translate to center and flip Y-axis
-        tiny_skia::Transform::from_translate(half_width, half_height)
-            .post_concat(tiny_skia::Transform::from_scale(1.0, -1.0))
+        let ratio = self.config.pixel_ratio as f32;
+
+        // Flip the Y-axis, move the origin to the center, then scale to
+        // device pixels
+        let base = tiny_skia::Transform::from_scale(1.0, -1.0)
+            .post_concat(tiny_skia::Transform::from_translate(
+                half_width,
+                half_height,
+            ))
+            .post_scale(ratio, ratio);
+
+        base.pre_concat(to_skia(&self.transforms.current()))
     }
 }
 
+/// Converts a scene transform to tiny-skia's single-precision form.
+fn to_skia(t: &Transform) -> tiny_skia::Transform {
+    tiny_skia::Transform::from_row(
+        t.a as f32,
+        t.b as f32,
+        t.c as f32,
+        t.d as f32,
+        t.tx as f32,
+        t.ty as f32,
+    )
+}
+
 impl Renderer for RasterRenderer {
     fn begin_frame(&mut self) -> Result<()> {
         // No-op: pixmap is persistent
@@ -200,6 +314,11 @@ impl Renderer for RasterRenderer {
         Ok(())
     }
 
+    fn flush(&mut self) -> Result<()> {
+        // No-op: the pixmap is only written on save
+        Ok(())
+    }
+
     fn clear(&mut self, color: Color) -> Result<()> {
         let skia_color = color_to_skia_color(&color, 1.0);
         self.pixmap.fill(skia_color);
@@ -213,8 +332,20 @@ impl Renderer for RasterRenderer {
         let transform = self.create_transform();
         let fill_rule = fill_rule_to_skia(style.fill_rule);
 
+        // Group opacity is approximated by attenuating each draw call, so
+        // overlapping shapes inside a group blend with each other.
+        let group_opacity: f64 = self.group_opacities.iter().product();
+        let attenuated;
+        let style = if group_opacity < 1.0 {
+            attenuated = style.clone().with_opacity(style.opacity * group_opacity);
+            &attenuated
+        } else {
+            style
+        };
+
         // Draw fill first
-        if let Some(fill_paint) = path_style_to_fill_paint(style) {
+        if let Some(mut fill_paint) = path_style_to_fill_paint(style) {
+            fill_paint.anti_alias = self.config.antialias;
             self.pixmap.fill_path(
                 &skia_path,
                 &fill_paint,
@@ -225,10 +356,11 @@ impl Renderer for RasterRenderer {
         }
 
         // Draw stroke on top
-        if let (Some(stroke_paint), Some(stroke)) = (
+        if let (Some(mut stroke_paint), Some(stroke)) = (
             path_style_to_stroke_paint(style),
             path_style_to_stroke(style),
         ) {
+            stroke_paint.anti_alias = self.config.antialias;
             self.pixmap.stroke_path(
                 &skia_path,
                 &stroke_paint,
@@ -247,7 +379,8 @@ impl Renderer for RasterRenderer {
         // In a production system, you'd want to:
         // 1. Use a font rasterization library like `fontdue` or `ab_glyph`
         // 2. Convert text to paths
-        // 3. Render those paths
+        // 3. Render those paths, stroking `style.stroke_color` outlines
+        //    before filling so the halo sits behind the glyphs
         eprintln!(
             "Warning: Text rendering not fully implemented in raster backend. Text: \"{}\"",
             text
@@ -259,6 +392,83 @@ impl Renderer for RasterRenderer {
         Ok(())
     }
 
+    fn draw_image(
+        &mut self,
+        pixels: &[u8],
+        width: u32,
+        height: u32,
+        placement: &Transform,
+        opacity: f64,
+    ) -> Result<()> {
+        if pixels.len() != width as usize * height as usize * 4 {
+            return Err(Error::Render(format!(
+                "Image data has {} bytes, expected {}x{} RGBA",
+                pixels.len(),
+                width,
+                height
+            )));
+        }
+        let size = tiny_skia::IntSize::from_wh(width, height)
+            .ok_or_else(|| Error::Render("Image dimensions must be non-zero".to_string()))?;
+
+        // tiny-skia stores premultiplied pixels
+        let premultiplied: Vec<u8> = pixels
+            .chunks_exact(4)
+            .flat_map(|px| {
+                let color = tiny_skia::ColorU8::from_rgba(px[0], px[1], px[2], px[3]).premultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+        let image = tiny_skia::Pixmap::from_vec(premultiplied, size)
+            .ok_or_else(|| Error::Render("Failed to create image pixmap".to_string()))?;
+
+        // Pixel rows run downwards from y = 1 in the unit square, which
+        // `placement` then maps into the scene
+        let pixels_to_unit = Transform::translate(0.0, 1.0)
+            * Transform::scale(1.0 / width as f64, -1.0 / height as f64);
+        let transform = self
+            .create_transform()
+            .pre_concat(to_skia(&(*placement * pixels_to_unit)));
+
+        let group_opacity: f64 = self.group_opacities.iter().product();
+        let paint = tiny_skia::PixmapPaint {
+            opacity: (opacity * group_opacity).clamp(0.0, 1.0) as f32,
+            quality: tiny_skia::FilterQuality::Bilinear,
+            ..tiny_skia::PixmapPaint::default()
+        };
+
+        self.pixmap
+            .draw_pixmap(0, 0, image.as_ref(), &paint, transform, None);
+        Ok(())
+    }
+
+    fn push_group_opacity(&mut self, opacity: f64) -> Result<()> {
+        self.group_opacities.push(opacity.clamp(0.0, 1.0));
+        Ok(())
+    }
+
+    fn pop_group_opacity(&mut self) -> Result<()> {
+        self.group_opacities
+            .pop()
+            .map(|_| ())
+            .ok_or_else(|| Error::Render("No open group to close".to_string()))
+    }
+
+    fn transform_stack(&mut self) -> Option<&mut TransformStack> {
+        Some(&mut self.transforms)
+    }
+
+    fn capabilities(&self) -> RendererCapabilities {
+        // Text is logged but not drawn until glyph rendering lands
+        RendererCapabilities {
+            text: false,
+            images: true,
+            clipping: false,
+            gradients: false,
+            transparency: true,
+        }
+    }
+
     fn dimensions(&self) -> (u32, u32) {
         (self.width, self.height)
     }
@@ -274,6 +484,21 @@ mod tests {
         assert_eq!(renderer.dimensions(), (1920, 1080));
     }
 
+    #[test]
+    fn test_try_new() {
+        assert!(matches!(
+            RasterRenderer::try_new(0, 100),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            RasterRenderer::try_new(u32::MAX, u32::MAX),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let renderer = RasterRenderer::try_new(100, 100).unwrap();
+        assert_eq!(renderer.dimensions(), (100, 100));
+    }
+
     #[test]
     fn test_clear() {
         let mut renderer = RasterRenderer::new(100, 100);
@@ -320,22 +545,245 @@ mod tests {
     }
 
     #[test]
-    fn test_to_pixmap_coords() {
+    fn test_create_transform_maps_to_pixmap() {
         let renderer = RasterRenderer::new(800, 600);
+        let mut points = [
+            // Center should map to middle of pixmap
+            tiny_skia::Point::from_xy(0.0, 0.0),
+            // Top-right corner
+            tiny_skia::Point::from_xy(400.0, 300.0),
+            // Bottom-left corner
+            tiny_skia::Point::from_xy(-400.0, -300.0),
+        ];
+        renderer.create_transform().map_points(&mut points);
+
+        assert_eq!((points[0].x, points[0].y), (400.0, 300.0));
+        assert_eq!((points[1].x, points[1].y), (800.0, 0.0));
+        assert_eq!((points[2].x, points[2].y), (0.0, 600.0));
+    }
 
-        // Center should map to middle of pixmap
-        let (px, py) = renderer.to_pixmap_coords(0.0, 0.0);
-        assert_eq!(px, 400.0);
-        assert_eq!(py, 300.0);
+    #[test]
+    fn test_group_opacity_stack() {
+        let mut renderer = RasterRenderer::new(10, 10);
 
-        // Top-right corner
-        let (px, py) = renderer.to_pixmap_coords(400.0, 300.0);
-        assert_eq!(px, 800.0);
-        assert_eq!(py, 0.0);
+        renderer.push_group_opacity(0.5).unwrap();
+        renderer.push_group_opacity(0.5).unwrap();
+        assert_eq!(renderer.group_opacities.iter().product::<f64>(), 0.25);
+
+        renderer.pop_group_opacity().unwrap();
+        renderer.pop_group_opacity().unwrap();
+        assert!(renderer.pop_group_opacity().is_err());
+    }
+
+    #[test]
+    fn test_draw_image() {
+        let mut renderer = RasterRenderer::new(100, 100);
+        renderer.clear(Color::WHITE).unwrap();
+
+        // 2x2 solid red image stretched over the top-right quadrant
+        let pixels = [255, 0, 0, 255].repeat(4);
+        let placement = Transform::scale(50.0, 50.0);
+        renderer.draw_image(&pixels, 2, 2, &placement, 1.0).unwrap();
+
+        let pixel = |x: usize, y: usize| {
+            let i = (y * 100 + x) * 4;
+            &renderer.data()[i..i + 4]
+        };
+        assert_eq!(pixel(75, 25), &[255, 0, 0, 255]);
+        assert_eq!(pixel(25, 75), &[255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_draw_image_rejects_bad_length() {
+        let mut renderer = RasterRenderer::new(10, 10);
+        let placement = Transform::identity();
+        assert!(renderer.draw_image(&[0; 3], 1, 1, &placement, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_draw_image_rotated() {
+        let mut renderer = RasterRenderer::new(100, 100);
+        renderer.clear(Color::WHITE).unwrap();
+
+        // 2x1 image, red then blue, turned a quarter turn counter-clockwise
+        // so red ends up below blue
+        let pixels = [255, 0, 0, 255, 0, 0, 255, 255];
+        let placement = Transform::rotate(std::f64::consts::FRAC_PI_2)
+            * Transform::translate(-40.0, -20.0)
+            * Transform::scale(80.0, 40.0);
+        renderer.draw_image(&pixels, 2, 1, &placement, 1.0).unwrap();
+
+        let pixel = |x: usize, y: usize| {
+            let i = (y * 100 + x) * 4;
+            &renderer.data()[i..i + 4]
+        };
+        assert_eq!(pixel(50, 80), &[255, 0, 0, 255]);
+        assert_eq!(pixel(50, 20), &[0, 0, 255, 255]);
+        assert_eq!(pixel(20, 50), &[255, 255, 255, 255]);
+    }
+
+    /// A filled 10x10 square centered on the origin
+    fn unit_square() -> Path {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(-5.0, -5.0))
+            .line_to(Vector2D::new(5.0, -5.0))
+            .line_to(Vector2D::new(5.0, 5.0))
+            .line_to(Vector2D::new(-5.0, 5.0))
+            .close();
+        path
+    }
+
+    fn is_red(renderer: &RasterRenderer, x: u32, y: u32) -> bool {
+        let pixel = renderer.pixmap().pixel(x, y).unwrap();
+        pixel.red() == 255 && pixel.green() == 0
+    }
+
+    #[test]
+    fn test_draw_path_maps_origin_to_center() {
+        let mut renderer = RasterRenderer::new(100, 100);
+        renderer.clear(Color::WHITE).unwrap();
+        renderer
+            .draw_path(&unit_square(), &PathStyle::fill(Color::RED))
+            .unwrap();
+
+        assert!(is_red(&renderer, 50, 50));
+        assert!(!is_red(&renderer, 10, 10));
+    }
 
-        // Bottom-left corner
-        let (px, py) = renderer.to_pixmap_coords(-400.0, -300.0);
-        assert_eq!(px, 0.0);
-        assert_eq!(py, 600.0);
+    #[test]
+    fn test_draw_path_y_axis_points_up() {
+        let mut renderer = RasterRenderer::new(100, 100);
+        renderer.clear(Color::WHITE).unwrap();
+
+        // Paths and images share one mapping: positive Y is the top half
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(50.0, 0.0))
+            .line_to(Vector2D::new(50.0, 50.0))
+            .line_to(Vector2D::new(0.0, 50.0))
+            .close();
+        renderer
+            .draw_path(&path, &PathStyle::fill(Color::RED))
+            .unwrap();
+
+        assert!(is_red(&renderer, 75, 25));
+        assert!(!is_red(&renderer, 75, 75));
+        assert!(!is_red(&renderer, 25, 25));
+    }
+
+    #[test]
+    fn test_push_transform_shifts_paths() {
+        let mut renderer = RasterRenderer::new(100, 100);
+        renderer.clear(Color::WHITE).unwrap();
+
+        // Up 30 units in the Y-up scene is 30 pixels toward the top row
+        renderer
+            .push_transform(&Transform::translate(0.0, 30.0))
+            .unwrap();
+        renderer
+            .draw_path(&unit_square(), &PathStyle::fill(Color::RED))
+            .unwrap();
+        renderer.pop_transform().unwrap();
+
+        assert!(is_red(&renderer, 50, 20));
+        assert!(!is_red(&renderer, 50, 50));
+        assert!(renderer.pop_transform().is_err());
+    }
+
+    #[test]
+    fn test_push_transform_nests() {
+        let mut renderer = RasterRenderer::new(100, 100);
+        renderer.clear(Color::WHITE).unwrap();
+
+        // The inner scale applies first, then the outer translation
+        renderer
+            .push_transform(&Transform::translate(-30.0, 0.0))
+            .unwrap();
+        renderer
+            .push_transform(&Transform::scale(0.5, 0.5))
+            .unwrap();
+        renderer
+            .draw_path(&unit_square(), &PathStyle::fill(Color::RED))
+            .unwrap();
+        renderer.pop_transform().unwrap();
+        renderer.pop_transform().unwrap();
+
+        assert!(is_red(&renderer, 20, 50));
+        assert!(!is_red(&renderer, 24, 50));
+    }
+
+    #[test]
+    fn test_raster_capabilities() {
+        let caps = RasterRenderer::new(100, 100).capabilities();
+        assert_eq!(
+            caps,
+            RendererCapabilities {
+                text: false,
+                images: true,
+                clipping: false,
+                gradients: false,
+                transparency: true,
+            }
+        );
+    }
+
+    /// Returns `true` if any pixel is only partly covered
+    fn has_partial_alpha(renderer: &RasterRenderer) -> bool {
+        renderer
+            .data()
+            .chunks_exact(4)
+            .any(|px| px[3] != 0 && px[3] != 255)
+    }
+
+    fn draw_circle(renderer: &mut RasterRenderer) {
+        let mut cursor = crate::renderer::PathCursor::new();
+        cursor.circle(Vector2D::ZERO, 15.0);
+        renderer
+            .draw_path(cursor.path(), &PathStyle::fill(Color::RED))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_with_config_antialias_off() {
+        let mut smooth = RasterRenderer::new(40, 40);
+        draw_circle(&mut smooth);
+        assert!(has_partial_alpha(&smooth));
+
+        let config = RenderConfig {
+            antialias: false,
+            ..RenderConfig::default()
+        };
+        let mut hard = RasterRenderer::with_config(40, 40, config);
+        draw_circle(&mut hard);
+        assert!(!has_partial_alpha(&hard));
+        assert!(is_red(&hard, 20, 20));
+    }
+
+    #[test]
+    fn test_with_config_pixel_ratio() {
+        let config = RenderConfig {
+            pixel_ratio: 2.0,
+            ..RenderConfig::default()
+        };
+        let mut renderer = RasterRenderer::with_config(40, 40, config);
+        assert_eq!(renderer.dimensions(), (40, 40));
+        assert_eq!(renderer.pixmap().width(), 80);
+
+        // A 10x10 square at the origin covers 20x20 device pixels
+        renderer
+            .draw_path(&unit_square(), &PathStyle::fill(Color::RED))
+            .unwrap();
+        assert!(is_red(&renderer, 40, 40));
+        assert!(is_red(&renderer, 45, 35));
+        assert!(!is_red(&renderer, 60, 40));
+
+        let bad = RenderConfig {
+            pixel_ratio: f64::NAN,
+            ..RenderConfig::default()
+        };
+        assert!(matches!(
+            RasterRenderer::try_with_config(40, 40, bad),
+            Err(Error::InvalidArgument(_))
+        ));
     }
 }
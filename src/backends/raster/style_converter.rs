@@ -32,7 +32,7 @@ pub fn color_to_skia_color(color: &Color, opacity: f64) -> tiny_skia::Color {
 pub fn path_style_to_fill_paint(style: &PathStyle) -> Option<tiny_skia::Paint<'static>> {
     let fill_color = style.fill_color.as_ref()?;
 
-    let skia_color = color_to_skia_color(fill_color, style.opacity);
+    let skia_color = color_to_skia_color(fill_color, style.opacity * style.fill_opacity());
 
     let paint = tiny_skia::Paint {
         shader: tiny_skia::Shader::SolidColor(skia_color),
@@ -50,7 +50,7 @@ pub fn path_style_to_fill_paint(style: &PathStyle) -> Option<tiny_skia::Paint<'s
 pub fn path_style_to_stroke_paint(style: &PathStyle) -> Option<tiny_skia::Paint<'static>> {
     let stroke_color = style.stroke_color.as_ref()?;
 
-    let skia_color = color_to_skia_color(stroke_color, style.opacity);
+    let skia_color = color_to_skia_color(stroke_color, style.opacity * style.stroke_opacity());
 
     let paint = tiny_skia::Paint {
         shader: tiny_skia::Shader::SolidColor(skia_color),
@@ -180,4 +180,23 @@ mod tests {
             tiny_skia::FillRule::EvenOdd
         );
     }
+
+    #[test]
+    fn test_path_style_fill_opacity_separate_from_stroke() {
+        let style = PathStyle::default()
+            .with_stroke(Color::BLUE, 2.0)
+            .with_fill(Color::RED)
+            .with_fill_opacity(0.5);
+
+        let fill = path_style_to_fill_paint(&style).unwrap();
+        let stroke = path_style_to_stroke_paint(&style).unwrap();
+
+        match (fill.shader, stroke.shader) {
+            (tiny_skia::Shader::SolidColor(f), tiny_skia::Shader::SolidColor(s)) => {
+                assert!((f.alpha() - 0.5).abs() < 0.02);
+                assert!(s.alpha() > 0.99);
+            }
+            _ => panic!("expected solid color shaders"),
+        }
+    }
 }
@@ -0,0 +1,316 @@
+//! Recording rendering backend.
+//!
+//! [`RecordingRenderer`] draws nothing. It stores every call it receives as a
+//! [`DrawCommand`], which makes it useful for asserting on what a mobject or
+//! scene draws and for capturing a frame once and replaying it into other
+//! backends.
+
+use crate::core::{Color, Result, Transform, Vector2D};
+use crate::renderer::{Path, PathStyle, Renderer, RendererCapabilities, TextStyle};
+
+/// A single call recorded by a [`RecordingRenderer`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    /// [`Renderer::begin_frame`]
+    BeginFrame,
+
+    /// [`Renderer::end_frame`]
+    EndFrame,
+
+    /// [`Renderer::flush`]
+    Flush,
+
+    /// [`Renderer::clear`] with the clear color
+    Clear(Color),
+
+    /// [`Renderer::draw_path`]
+    Path {
+        /// Path that was drawn, boxed to keep the other variants small
+        path: Box<Path>,
+        /// Style it was drawn with
+        style: PathStyle,
+    },
+
+    /// [`Renderer::draw_text`]
+    Text {
+        /// Text that was drawn
+        text: String,
+        /// Anchor position of the text
+        position: Vector2D,
+        /// Style it was drawn with
+        style: TextStyle,
+    },
+
+    /// [`Renderer::draw_image`]
+    Image {
+        /// Unpremultiplied RGBA bytes, top row first
+        pixels: Vec<u8>,
+        /// Width of the image in pixels
+        width: u32,
+        /// Height of the image in pixels
+        height: u32,
+        /// Placement of the unit square the image covers
+        transform: Transform,
+        /// Opacity applied to the whole image
+        opacity: f64,
+    },
+
+    /// [`Renderer::push_group_opacity`]
+    PushGroupOpacity(f64),
+
+    /// [`Renderer::pop_group_opacity`]
+    PopGroupOpacity,
+
+    /// [`Renderer::push_transform`]
+    PushTransform(Transform),
+
+    /// [`Renderer::pop_transform`]
+    PopTransform,
+}
+
+/// Renderer that records draw calls instead of drawing.
+///
+/// Every call, including frame boundaries, group opacity and transforms, is
+/// kept in order. [`RecordingRenderer::replay_into`] sends the same calls to
+/// another renderer, which then produces the output it would have produced
+/// had it been drawn to directly.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::backends::{DrawCommand, RecordingRenderer};
+/// use manim_rs::core::Color;
+/// use manim_rs::mobject::geometry::Circle;
+/// use manim_rs::mobject::Mobject;
+/// use manim_rs::renderer::Renderer;
+///
+/// let mut renderer = RecordingRenderer::new(800, 600);
+/// renderer.clear(Color::BLACK).unwrap();
+/// Circle::new(1.0).render(&mut renderer).unwrap();
+///
+/// assert_eq!(renderer.commands()[0], DrawCommand::Clear(Color::BLACK));
+/// assert!(matches!(renderer.commands()[1], DrawCommand::Path { .. }));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RecordingRenderer {
+    width: u32,
+    height: u32,
+    commands: Vec<DrawCommand>,
+}
+
+impl RecordingRenderer {
+    /// Creates a recorder reporting the given dimensions.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            commands: Vec::new(),
+        }
+    }
+
+    /// Returns the recorded commands in call order.
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Discards all recorded commands.
+    pub fn clear_commands(&mut self) {
+        self.commands.clear();
+    }
+
+    /// Sends every recorded command to `other`, in order.
+    ///
+    /// # Errors
+    ///
+    /// Stops at and returns the first error raised by `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::RecordingRenderer;
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::Renderer;
+    ///
+    /// let mut recording = RecordingRenderer::new(800, 600);
+    /// recording.clear(Color::WHITE).unwrap();
+    ///
+    /// let mut copy = RecordingRenderer::new(800, 600);
+    /// recording.replay_into(&mut copy).unwrap();
+    /// assert_eq!(copy.commands(), recording.commands());
+    /// ```
+    pub fn replay_into(&self, other: &mut dyn Renderer) -> Result<()> {
+        for command in &self.commands {
+            match command {
+                DrawCommand::BeginFrame => other.begin_frame()?,
+                DrawCommand::EndFrame => other.end_frame()?,
+                DrawCommand::Flush => other.flush()?,
+                DrawCommand::Clear(color) => other.clear(*color)?,
+                DrawCommand::Path { path, style } => other.draw_path(path, style)?,
+                DrawCommand::Text {
+                    text,
+                    position,
+                    style,
+                } => other.draw_text(text, *position, style)?,
+                DrawCommand::Image {
+                    pixels,
+                    width,
+                    height,
+                    transform,
+                    opacity,
+                } => other.draw_image(pixels, *width, *height, transform, *opacity)?,
+                DrawCommand::PushGroupOpacity(opacity) => other.push_group_opacity(*opacity)?,
+                DrawCommand::PopGroupOpacity => other.pop_group_opacity()?,
+                DrawCommand::PushTransform(transform) => other.push_transform(transform)?,
+                DrawCommand::PopTransform => other.pop_transform()?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Renderer for RecordingRenderer {
+    fn begin_frame(&mut self) -> Result<()> {
+        self.commands.push(DrawCommand::BeginFrame);
+        Ok(())
+    }
+
+    fn end_frame(&mut self) -> Result<()> {
+        self.commands.push(DrawCommand::EndFrame);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.commands.push(DrawCommand::Flush);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Color) -> Result<()> {
+        self.commands.push(DrawCommand::Clear(color));
+        Ok(())
+    }
+
+    fn draw_path(&mut self, path: &Path, style: &PathStyle) -> Result<()> {
+        self.commands.push(DrawCommand::Path {
+            path: Box::new(path.clone()),
+            style: style.clone(),
+        });
+        Ok(())
+    }
+
+    fn draw_text(&mut self, text: &str, position: Vector2D, style: &TextStyle) -> Result<()> {
+        self.commands.push(DrawCommand::Text {
+            text: text.to_string(),
+            position,
+            style: style.clone(),
+        });
+        Ok(())
+    }
+
+    fn draw_image(
+        &mut self,
+        pixels: &[u8],
+        width: u32,
+        height: u32,
+        transform: &Transform,
+        opacity: f64,
+    ) -> Result<()> {
+        self.commands.push(DrawCommand::Image {
+            pixels: pixels.to_vec(),
+            width,
+            height,
+            transform: *transform,
+            opacity,
+        });
+        Ok(())
+    }
+
+    fn push_group_opacity(&mut self, opacity: f64) -> Result<()> {
+        self.commands.push(DrawCommand::PushGroupOpacity(opacity));
+        Ok(())
+    }
+
+    fn pop_group_opacity(&mut self) -> Result<()> {
+        self.commands.push(DrawCommand::PopGroupOpacity);
+        Ok(())
+    }
+
+    fn push_transform(&mut self, transform: &Transform) -> Result<()> {
+        self.commands.push(DrawCommand::PushTransform(*transform));
+        Ok(())
+    }
+
+    fn pop_transform(&mut self) -> Result<()> {
+        self.commands.push(DrawCommand::PopTransform);
+        Ok(())
+    }
+
+    fn capabilities(&self) -> RendererCapabilities {
+        // Clipping and gradients have no draw calls to record yet
+        RendererCapabilities {
+            text: true,
+            images: true,
+            transparency: true,
+            ..RendererCapabilities::default()
+        }
+    }
+
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw_sample(renderer: &mut dyn Renderer) -> Result<()> {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(10.0, 5.0));
+
+        renderer.begin_frame()?;
+        renderer.clear(Color::BLACK)?;
+        renderer.draw_path(&path, &PathStyle::stroke(Color::RED, 2.0))?;
+        renderer.push_group_opacity(0.5)?;
+        renderer.draw_text("hi", Vector2D::new(1.0, 2.0), &TextStyle::default())?;
+        renderer.pop_group_opacity()?;
+        renderer.end_frame()
+    }
+
+    #[test]
+    fn test_records_calls_in_order() {
+        let mut renderer = RecordingRenderer::new(100, 50);
+        draw_sample(&mut renderer).unwrap();
+
+        let commands = renderer.commands();
+        assert_eq!(commands.len(), 7);
+        assert_eq!(commands[0], DrawCommand::BeginFrame);
+        assert_eq!(commands[1], DrawCommand::Clear(Color::BLACK));
+        assert!(matches!(
+            &commands[2],
+            DrawCommand::Path { style, .. } if style.stroke_color == Some(Color::RED)
+        ));
+        assert_eq!(commands[3], DrawCommand::PushGroupOpacity(0.5));
+        assert!(matches!(&commands[4], DrawCommand::Text { text, .. } if text == "hi"));
+        assert_eq!(commands[6], DrawCommand::EndFrame);
+
+        renderer.clear_commands();
+        assert!(renderer.commands().is_empty());
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn test_replay_into_svg_matches_direct_drawing() {
+        use crate::backends::SvgRenderer;
+
+        let mut direct = SvgRenderer::new(100, 50);
+        draw_sample(&mut direct).unwrap();
+
+        let mut recording = RecordingRenderer::new(100, 50);
+        draw_sample(&mut recording).unwrap();
+        let mut replayed = SvgRenderer::new(100, 50);
+        recording.replay_into(&mut replayed).unwrap();
+
+        assert_eq!(replayed.to_svg_string(), direct.to_svg_string());
+    }
+}
@@ -26,6 +26,18 @@ pub(crate) enum SvgElement {
         position: Vector2D,
         attrs: Vec<(String, String)>,
     },
+    /// An embedded raster image
+    Image {
+        width: u32,
+        height: u32,
+        href: String,
+        attrs: Vec<(String, String)>,
+    },
+    /// A group element wrapping nested elements
+    Group {
+        attrs: Vec<(String, String)>,
+        children: Vec<SvgElement>,
+    },
 }
 
 impl SvgElement {
@@ -71,6 +83,36 @@ impl SvgElement {
                 result.push_str("</text>");
                 result
             }
+            SvgElement::Image {
+                width,
+                height,
+                href,
+                attrs,
+            } => {
+                let mut result = format!(
+                    "{}<image width=\"{}\" height=\"{}\"",
+                    indent_str, width, height
+                );
+                for (key, value) in attrs {
+                    result.push_str(&format!(" {}=\"{}\"", key, value));
+                }
+                result.push_str(&format!(" href=\"{}\" />", href));
+                result
+            }
+            SvgElement::Group { attrs, children } => {
+                let mut result = format!("{}<g", indent_str);
+                for (key, value) in attrs {
+                    result.push_str(&format!(" {}=\"{}\"", key, value));
+                }
+                result.push_str(">\n");
+                for child in children {
+                    result.push_str(&child.to_svg_string(indent + 1));
+                    result.push('\n');
+                }
+                result.push_str(&indent_str);
+                result.push_str("</g>");
+                result
+            }
         }
     }
 }
@@ -152,4 +194,20 @@ mod tests {
         let svg2 = rect.to_svg_string(2);
         assert!(svg2.starts_with("    "));
     }
+
+    #[test]
+    fn test_group_element() {
+        let group = SvgElement::Group {
+            attrs: vec![("opacity".to_string(), "0.5".to_string())],
+            children: vec![SvgElement::Path {
+                d: "M 0 0 L 1 1".to_string(),
+                attrs: vec![],
+            }],
+        };
+
+        let svg = group.to_svg_string(1);
+        assert!(svg.starts_with("  <g opacity=\"0.5\">\n"));
+        assert!(svg.contains("\n    <path d=\"M 0 0 L 1 1\" />\n"));
+        assert!(svg.ends_with("  </g>"));
+    }
 }
@@ -41,16 +41,20 @@
 //!
 //! The SVG renderer uses a centered coordinate system where (0, 0) is at the center
 //! of the canvas, with positive Y pointing up (opposite to standard SVG coordinates).
-//! This matches the mathematical convention used in Manim.
+//! This matches the mathematical convention used in Manim. Use
+//! [`SvgRenderer::with_coordinate_system`] with
+//! [`CoordinateSystem::TopLeftYDown`] for plain SVG coordinates instead.
 
+use std::collections::HashMap;
 use std::fs;
-use std::io::Write;
+use std::io::{BufWriter, Write};
 
-use crate::core::{Color, Result, Vector2D};
-use crate::renderer::{Path, PathStyle, Renderer, TextStyle};
+use crate::core::{Color, Error, Result, Transform, Vector2D};
+use crate::renderer::{Path, PathStyle, RenderConfig, Renderer, RendererCapabilities, TextStyle};
 
 mod elements;
 mod path_converter;
+mod png;
 mod style_converter;
 
 pub use path_converter::path_to_svg_d;
@@ -59,6 +63,25 @@ pub use style_converter::{color_to_svg, path_style_to_svg_attrs, text_style_to_s
 use elements::SvgElement;
 use style_converter::escape_xml;
 
+/// Placement of the origin and direction of the Y axis in SVG output.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::backends::{CoordinateSystem, SvgRenderer};
+///
+/// let renderer = SvgRenderer::new(800, 600).with_coordinate_system(CoordinateSystem::TopLeftYDown);
+/// assert!(renderer.to_svg_string().contains("viewBox=\"0 0 800 600\""));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordinateSystem {
+    /// Origin at the center of the canvas with Y pointing up, as in Manim
+    #[default]
+    CenteredYUp,
+    /// Origin at the top-left corner with Y pointing down, as in plain SVG
+    TopLeftYDown,
+}
+
 /// SVG rendering backend.
 ///
 /// Renders scenes to SVG (Scalable Vector Graphics) format. SVG is ideal for
@@ -85,6 +108,34 @@ pub struct SvgRenderer {
     height: u32,
     background: Color,
     elements: Vec<SvgElement>,
+    /// Groups that have been opened but not yet closed, innermost last
+    open_groups: Vec<OpenGroup>,
+    /// Whether path styles are emitted as shared CSS classes
+    css_classes: bool,
+    /// CSS declarations per class, indexed by class number
+    style_rules: Vec<String>,
+    /// Maps the declarations of each rule in `style_rules` to its class
+    /// number, so styles share a class only when their output is identical
+    style_lookup: HashMap<String, usize>,
+    coordinate_system: CoordinateSystem,
+    /// `@font-face` rules for embedded fonts
+    font_faces: Vec<String>,
+    config: RenderConfig,
+}
+
+/// Which push opened a group, so pops must match it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupKind {
+    Opacity,
+    Transform,
+}
+
+/// A `<g>` element whose children are still being collected.
+#[derive(Debug, Clone)]
+struct OpenGroup {
+    kind: GroupKind,
+    attrs: Vec<(String, String)>,
+    children: Vec<SvgElement>,
 }
 
 impl SvgRenderer {
@@ -100,11 +151,131 @@ impl SvgRenderer {
     /// assert_eq!(renderer.dimensions(), (1920, 1080));
     /// ```
     pub fn new(width: u32, height: u32) -> Self {
+        Self::with_config(width, height, RenderConfig::default())
+    }
+
+    /// Creates a new SVG renderer with the given quality settings.
+    ///
+    /// Path coordinates are written with
+    /// [`config.precision`](RenderConfig::precision) decimal places.
+    /// Antialiasing and pixel ratio are left to the SVG viewer and ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::SvgRenderer;
+    /// use manim_rs::core::{Color, Vector2D};
+    /// use manim_rs::renderer::{Path, PathStyle, RenderConfig, Renderer};
+    ///
+    /// let config = RenderConfig {
+    ///     precision: 1,
+    ///     ..RenderConfig::default()
+    /// };
+    /// let mut renderer = SvgRenderer::with_config(800, 600, config);
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::ZERO).line_to(Vector2D::new(1.0 / 3.0, 2.0));
+    /// renderer.draw_path(&path, &PathStyle::stroke(Color::RED, 1.0)).unwrap();
+    ///
+    /// assert!(renderer.to_svg_string().contains("d=\"M 0 0 L 0.3 2\""));
+    /// ```
+    pub fn with_config(width: u32, height: u32, config: RenderConfig) -> Self {
         Self {
             width,
             height,
             background: Color::BLACK,
             elements: Vec::new(),
+            open_groups: Vec::new(),
+            css_classes: false,
+            style_rules: Vec::new(),
+            style_lookup: HashMap::new(),
+            coordinate_system: CoordinateSystem::default(),
+            font_faces: Vec::new(),
+            config,
+        }
+    }
+
+    /// Enables or disables CSS class deduplication of path styles.
+    ///
+    /// When enabled, every distinct [`PathStyle`] becomes a class in a
+    /// `<style>` block and paths reference it with `class="sN"` instead of
+    /// repeating the style as inline attributes. Styles share a class when
+    /// their written declarations are identical. This keeps files small when
+    /// many paths share a style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::SvgRenderer;
+    /// use manim_rs::core::{Color, Vector2D};
+    /// use manim_rs::renderer::{Path, PathStyle, Renderer};
+    ///
+    /// let mut renderer = SvgRenderer::new(800, 600).with_css_classes(true);
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::ZERO).line_to(Vector2D::new(10.0, 0.0));
+    /// renderer.draw_path(&path, &PathStyle::stroke(Color::RED, 2.0)).unwrap();
+    ///
+    /// let svg = renderer.to_svg_string();
+    /// assert!(svg.contains("<style>"));
+    /// assert!(svg.contains("class=\"s0\""));
+    /// ```
+    pub fn with_css_classes(mut self, enabled: bool) -> Self {
+        self.css_classes = enabled;
+        self
+    }
+
+    /// Sets where the origin lies and which way the Y axis points.
+    ///
+    /// The default, [`CoordinateSystem::CenteredYUp`], centers the `viewBox`
+    /// on the origin and flips the Y axis so it points up.
+    /// [`CoordinateSystem::TopLeftYDown`] emits `viewBox="0 0 W H"` without
+    /// the flip, which is what most web tooling expects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::{CoordinateSystem, SvgRenderer};
+    ///
+    /// let renderer = SvgRenderer::new(800, 600).with_coordinate_system(CoordinateSystem::TopLeftYDown);
+    /// assert!(!renderer.to_svg_string().contains("scale(1, -1)"));
+    /// ```
+    pub fn with_coordinate_system(mut self, system: CoordinateSystem) -> Self {
+        self.coordinate_system = system;
+        self
+    }
+
+    /// Embeds a TrueType font in the output under the name `family`.
+    ///
+    /// The font is base64-encoded into an `@font-face` rule in the
+    /// document's `<style>` block, so text styled with that font family
+    /// renders the same in any viewer, whether or not the font is installed.
+    /// Embedded fonts are kept when the renderer is cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::SvgRenderer;
+    ///
+    /// // Normally the contents of a .ttf file
+    /// let font_bytes = [0x00, 0x01, 0x00, 0x00];
+    /// let mut renderer = SvgRenderer::new(800, 600);
+    /// renderer.embed_font("My Font", &font_bytes);
+    ///
+    /// let svg = renderer.to_svg_string();
+    /// assert!(svg.contains("@font-face { font-family: \"My Font\";"));
+    /// ```
+    pub fn embed_font(&mut self, family: &str, ttf_bytes: &[u8]) -> &mut Self {
+        self.font_faces
+            .push(style_converter::font_face_rule(family, ttf_bytes));
+        self
+    }
+
+    /// Returns the top-left corner of the `viewBox`.
+    fn view_origin(&self) -> Vector2D {
+        match self.coordinate_system {
+            CoordinateSystem::CenteredYUp => {
+                Vector2D::new(-(self.width as f64) / 2.0, -(self.height as f64) / 2.0)
+            }
+            CoordinateSystem::TopLeftYDown => Vector2D::ZERO,
         }
     }
 
@@ -126,38 +297,88 @@ impl SvgRenderer {
     /// assert!(svg.contains("height=\"600\""));
     /// ```
     pub fn to_svg_string(&self) -> String {
-        let mut result = String::with_capacity(1024 + self.elements.len() * 128);
-
-        // SVG header with centered coordinate system
-        result.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
-        result.push_str("<svg ");
-        result.push_str(&format!("width=\"{}\" ", self.width));
-        result.push_str(&format!("height=\"{}\" ", self.height));
-
-        // ViewBox: center coordinate system at (0, 0)
-        let half_width = self.width as f64 / 2.0;
-        let half_height = self.height as f64 / 2.0;
-        result.push_str(&format!(
+        let mut buffer = Vec::with_capacity(1024 + self.elements.len() * 128);
+        self.write_to(&mut buffer)
+            .expect("writing to a Vec<u8> cannot fail");
+        String::from_utf8(buffer).expect("SVG output is valid UTF-8")
+    }
+
+    /// Streams the SVG document to a writer.
+    ///
+    /// The header, each element, and the footer are written directly, so
+    /// large scenes never build the whole document in memory. The output is
+    /// byte-identical to [`to_svg_string`](SvgRenderer::to_svg_string).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::SvgRenderer;
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::Renderer;
+    ///
+    /// # fn main() -> manim_rs::core::Result<()> {
+    /// let mut renderer = SvgRenderer::new(800, 600);
+    /// renderer.clear(Color::WHITE)?;
+    ///
+    /// let mut buffer = Vec::new();
+    /// renderer.write_to(&mut buffer)?;
+    /// assert!(buffer.starts_with(b"<?xml"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        // SVG header
+        writer.write_all(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")?;
+        write!(
+            writer,
+            "<svg width=\"{}\" height=\"{}\" ",
+            self.width, self.height
+        )?;
+
+        let origin = self.view_origin();
+        write!(
+            writer,
             "viewBox=\"{} {} {} {}\" ",
-            -half_width, -half_height, self.width, self.height
-        ));
+            origin.x, origin.y, self.width, self.height
+        )?;
 
-        result.push_str("xmlns=\"http://www.w3.org/2000/svg\" ");
-        result.push_str("version=\"1.1\">\n");
+        writer.write_all(b"xmlns=\"http://www.w3.org/2000/svg\" ")?;
+        writer.write_all(b"version=\"1.1\">\n")?;
 
-        // Add a group for coordinate system transformation (flip Y axis)
-        result.push_str("  <g transform=\"scale(1, -1)\">\n");
+        // Embedded fonts and shared path styles
+        if !self.font_faces.is_empty() || !self.style_rules.is_empty() {
+            writer.write_all(b"  <style>\n")?;
+            for rule in &self.font_faces {
+                writeln!(writer, "    {}", rule)?;
+            }
+            for (index, declarations) in self.style_rules.iter().enumerate() {
+                writeln!(writer, "    .s{} {{ {} }}", index, declarations)?;
+            }
+            writer.write_all(b"  </style>\n")?;
+        }
 
-        // Render all elements
+        // Add a group for coordinate system transformation
+        match self.coordinate_system {
+            CoordinateSystem::CenteredYUp => {
+                writer.write_all(b"  <g transform=\"scale(1, -1)\">\n")?
+            }
+            CoordinateSystem::TopLeftYDown => writer.write_all(b"  <g>\n")?,
+        }
+
+        // Stream all elements
         for element in &self.elements {
-            result.push_str(&element.to_svg_string(2));
-            result.push('\n');
+            writer.write_all(element.to_svg_string(2).as_bytes())?;
+            writer.write_all(b"\n")?;
         }
 
-        result.push_str("  </g>\n");
-        result.push_str("</svg>\n");
+        writer.write_all(b"  </g>\n")?;
+        writer.write_all(b"</svg>\n")?;
 
-        result
+        Ok(())
     }
 
     /// Saves the SVG to a file.
@@ -187,34 +408,110 @@ impl SvgRenderer {
             fs::create_dir_all(parent)?;
         }
 
-        let mut file = fs::File::create(path)?;
-        file.write_all(self.to_svg_string().as_bytes())?;
+        let mut file = BufWriter::new(fs::File::create(path)?);
+        self.write_to(&mut file)?;
+        file.flush()?;
         Ok(())
     }
+
+    /// Returns the class name for `style`, registering a new class if needed.
+    fn style_class(&mut self, style: &PathStyle) -> String {
+        let declarations = path_style_to_svg_attrs(style)
+            .into_iter()
+            .map(|(key, value)| format!("{}: {};", key, value))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let next = self.style_rules.len();
+        let index = *self
+            .style_lookup
+            .entry(declarations)
+            .or_insert_with_key(|declarations| {
+                self.style_rules.push(declarations.clone());
+                next
+            });
+        format!("s{}", index)
+    }
+
+    /// Adds an element to the innermost open group, or to the top level.
+    fn push_element(&mut self, element: SvgElement) {
+        match self.open_groups.last_mut() {
+            Some(group) => group.children.push(element),
+            None => self.elements.push(element),
+        }
+    }
+
+    /// Opens a group of `kind` with the given attributes.
+    fn open_group(&mut self, kind: GroupKind, attrs: Vec<(String, String)>) {
+        self.open_groups.push(OpenGroup {
+            kind,
+            attrs,
+            children: Vec::new(),
+        });
+    }
+
+    /// Closes the innermost open group, which must be of `kind`.
+    ///
+    /// Fails without closing anything if no group is open or the innermost
+    /// one was opened by the other kind of push.
+    fn close_group(&mut self, kind: GroupKind) -> Result<()> {
+        match self.open_groups.last() {
+            None => return Err(Error::Render("No open group to close".to_string())),
+            Some(group) if group.kind != kind => {
+                return Err(Error::Render(format!(
+                    "Cannot close a {:?} group while a {:?} group is innermost",
+                    kind, group.kind
+                )))
+            }
+            Some(_) => {}
+        }
+        self.close_innermost_group();
+        Ok(())
+    }
+
+    /// Closes the innermost open group, if any, emitting it as a `<g>`
+    /// element.
+    fn close_innermost_group(&mut self) {
+        if let Some(group) = self.open_groups.pop() {
+            self.push_element(SvgElement::Group {
+                attrs: group.attrs,
+                children: group.children,
+            });
+        }
+    }
 }
 
 impl Renderer for SvgRenderer {
     fn begin_frame(&mut self) -> Result<()> {
         // Clear elements for new frame
         self.elements.clear();
+        self.open_groups.clear();
+        self.style_rules.clear();
+        self.style_lookup.clear();
         Ok(())
     }
 
     fn end_frame(&mut self) -> Result<()> {
-        // No-op for SVG (everything is already stored)
+        // Close any groups left open so the output stays well-formed
+        while !self.open_groups.is_empty() {
+            self.close_innermost_group();
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        // No-op: the document is only written on save
         Ok(())
     }
 
     fn clear(&mut self, color: Color) -> Result<()> {
         self.background = color;
 
-        // Add background rectangle
-        let half_width = self.width as f64 / 2.0;
-        let half_height = self.height as f64 / 2.0;
+        // Add background rectangle covering the viewBox
+        let origin = self.view_origin();
 
-        self.elements.push(SvgElement::Rect {
-            x: -half_width,
-            y: -half_height,
+        self.push_element(SvgElement::Rect {
+            x: origin.x,
+            y: origin.y,
             width: self.width as f64,
             height: self.height as f64,
             fill: color_to_svg(&color),
@@ -224,20 +521,22 @@ impl Renderer for SvgRenderer {
     }
 
     fn draw_path(&mut self, path: &Path, style: &PathStyle) -> Result<()> {
-        let d = path_to_svg_d(path);
+        let d = path_to_svg_d(path, self.config.precision);
         if d.is_empty() {
             return Ok(());
         }
 
-        let svg_attrs = path_style_to_svg_attrs(style);
+        let attrs: Vec<(String, String)> = if self.css_classes {
+            vec![("class".to_string(), self.style_class(style))]
+        } else {
+            // Convert to owned strings for storage
+            path_style_to_svg_attrs(style)
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect()
+        };
 
-        // Convert to owned strings for storage
-        let attrs: Vec<(String, String)> = svg_attrs
-            .into_iter()
-            .map(|(k, v)| (k.to_string(), v))
-            .collect();
-
-        self.elements.push(SvgElement::Path { d, attrs });
+        self.push_element(SvgElement::Path { d, attrs });
 
         Ok(())
     }
@@ -252,7 +551,7 @@ impl Renderer for SvgRenderer {
             .map(|(k, v)| (k.to_string(), v))
             .collect();
 
-        self.elements.push(SvgElement::Text {
+        self.push_element(SvgElement::Text {
             content,
             position,
             attrs,
@@ -261,6 +560,97 @@ impl Renderer for SvgRenderer {
         Ok(())
     }
 
+    fn draw_image(
+        &mut self,
+        pixels: &[u8],
+        width: u32,
+        height: u32,
+        placement: &Transform,
+        opacity: f64,
+    ) -> Result<()> {
+        if width == 0 || height == 0 {
+            return Err(Error::Render(
+                "Image dimensions must be non-zero".to_string(),
+            ));
+        }
+        if pixels.len() != width as usize * height as usize * 4 {
+            return Err(Error::Render(format!(
+                "Image data has {} bytes, expected {}x{} RGBA",
+                pixels.len(),
+                width,
+                height
+            )));
+        }
+
+        // `<image>` lays pixel rows downwards from its origin, while the
+        // renderer contract puts the top row at y = 1 of the unit square
+        let t = *placement
+            * Transform::translate(0.0, 1.0)
+            * Transform::scale(1.0 / width as f64, -1.0 / height as f64);
+        let mut attrs = vec![
+            ("preserveAspectRatio".to_string(), "none".to_string()),
+            (
+                "transform".to_string(),
+                format!("matrix({} {} {} {} {} {})", t.a, t.b, t.c, t.d, t.tx, t.ty),
+            ),
+        ];
+        if opacity < 1.0 {
+            attrs.push((
+                "opacity".to_string(),
+                format!("{}", opacity.clamp(0.0, 1.0)),
+            ));
+        }
+
+        self.push_element(SvgElement::Image {
+            width,
+            height,
+            href: png::png_data_url(pixels, width, height),
+            attrs,
+        });
+        Ok(())
+    }
+
+    fn push_group_opacity(&mut self, opacity: f64) -> Result<()> {
+        let attrs = vec![(
+            "opacity".to_string(),
+            format!("{}", opacity.clamp(0.0, 1.0)),
+        )];
+        self.open_group(GroupKind::Opacity, attrs);
+        Ok(())
+    }
+
+    fn pop_group_opacity(&mut self) -> Result<()> {
+        self.close_group(GroupKind::Opacity)
+    }
+
+    fn push_transform(&mut self, transform: &Transform) -> Result<()> {
+        // Content is already in the Y-up frame of the root group, so the
+        // matrix applies unchanged
+        let attrs = vec![(
+            "transform".to_string(),
+            format!(
+                "matrix({} {} {} {} {} {})",
+                transform.a, transform.b, transform.c, transform.d, transform.tx, transform.ty
+            ),
+        )];
+        self.open_group(GroupKind::Transform, attrs);
+        Ok(())
+    }
+
+    fn pop_transform(&mut self) -> Result<()> {
+        self.close_group(GroupKind::Transform)
+    }
+
+    fn capabilities(&self) -> RendererCapabilities {
+        RendererCapabilities {
+            text: true,
+            images: true,
+            clipping: false,
+            gradients: false,
+            transparency: true,
+        }
+    }
+
     fn dimensions(&self) -> (u32, u32) {
         (self.width, self.height)
     }
@@ -373,4 +763,307 @@ mod tests {
         // Should have Y-axis flip transformation
         assert!(svg.contains("scale(1, -1)"));
     }
+
+    #[test]
+    fn test_flush_mid_frame_keeps_elements() {
+        let mut renderer = SvgRenderer::new(800, 600);
+
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(10.0, 10.0));
+
+        renderer.begin_frame().unwrap();
+        renderer.draw_path(&path, &PathStyle::default()).unwrap();
+        renderer.push_group_opacity(0.5).unwrap();
+        renderer.flush().unwrap();
+        renderer.draw_path(&path, &PathStyle::default()).unwrap();
+        renderer.pop_group_opacity().unwrap();
+        renderer.end_frame().unwrap();
+        renderer.flush().unwrap();
+
+        let svg = renderer.to_svg_string();
+        assert_eq!(svg.matches("<path").count(), 2);
+        assert_eq!(svg.matches("<g opacity").count(), 1);
+    }
+
+    #[test]
+    fn test_write_to_matches_svg_string() {
+        let mut renderer = SvgRenderer::new(800, 600);
+
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(10.0, 10.0));
+
+        renderer.begin_frame().unwrap();
+        renderer.clear(Color::BLACK).unwrap();
+        renderer.push_group_opacity(0.5).unwrap();
+        renderer.draw_path(&path, &PathStyle::default()).unwrap();
+        renderer.pop_group_opacity().unwrap();
+        renderer
+            .draw_text("Hi", Vector2D::ZERO, &TextStyle::default())
+            .unwrap();
+        renderer.end_frame().unwrap();
+
+        let mut buffer = Vec::new();
+        renderer.write_to(&mut buffer).unwrap();
+
+        assert_eq!(buffer, renderer.to_svg_string().into_bytes());
+    }
+
+    #[test]
+    fn test_group_opacity() {
+        let mut renderer = SvgRenderer::new(800, 600);
+
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(10.0, 10.0));
+
+        renderer.begin_frame().unwrap();
+        renderer.push_group_opacity(0.5).unwrap();
+        renderer.draw_path(&path, &PathStyle::default()).unwrap();
+        renderer.pop_group_opacity().unwrap();
+        renderer.end_frame().unwrap();
+
+        let svg = renderer.to_svg_string();
+        let group_start = svg.find("<g opacity=\"0.5\">").unwrap();
+        let path_start = svg.find("<path").unwrap();
+        let group_end = svg.rfind("</g>\n  </g>").unwrap();
+        assert!(group_start < path_start && path_start < group_end);
+    }
+
+    #[test]
+    fn test_group_opacity_unbalanced() {
+        let mut renderer = SvgRenderer::new(800, 600);
+        assert!(renderer.pop_group_opacity().is_err());
+
+        // Groups left open are closed at the end of the frame
+        renderer.begin_frame().unwrap();
+        renderer.push_group_opacity(0.5).unwrap();
+        renderer.end_frame().unwrap();
+
+        let svg = renderer.to_svg_string();
+        assert_eq!(svg.matches("<g").count(), svg.matches("</g>").count());
+    }
+
+    #[test]
+    fn test_css_classes_deduplicate_styles() {
+        let mut renderer = SvgRenderer::new(800, 600).with_css_classes(true);
+        let style = PathStyle::stroke(Color::RED, 2.0).with_fill(Color::BLUE);
+
+        renderer.begin_frame().unwrap();
+        for i in 0..100 {
+            let mut path = Path::new();
+            path.move_to(Vector2D::new(i as f64, 0.0))
+                .line_to(Vector2D::new(i as f64, 10.0));
+            renderer.draw_path(&path, &style).unwrap();
+        }
+        renderer.end_frame().unwrap();
+
+        let svg = renderer.to_svg_string();
+        assert_eq!(svg.matches("<style>").count(), 1);
+        assert_eq!(svg.matches(".s0 {").count(), 1);
+        assert!(!svg.contains(".s1 {"));
+        assert_eq!(svg.matches("class=\"s0\"").count(), 100);
+        // Style attributes live only in the class definition
+        assert_eq!(svg.matches("stroke-width").count(), 1);
+        assert!(!svg.contains("stroke=\""));
+    }
+
+    #[test]
+    fn test_css_classes_match_by_output() {
+        let mut renderer = SvgRenderer::new(800, 600).with_css_classes(true);
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .line_to(Vector2D::new(1.0, 0.0));
+
+        // Different styles that write the same declarations share a class
+        let style = PathStyle::stroke(Color::RED, 2.0).with_opacity(0.5);
+        let nearly = style.clone().with_opacity(0.5001);
+        assert_ne!(style.cache_key(), nearly.cache_key());
+
+        renderer.begin_frame().unwrap();
+        renderer.draw_path(&path, &style).unwrap();
+        renderer.draw_path(&path, &nearly).unwrap();
+        renderer.end_frame().unwrap();
+
+        let svg = renderer.to_svg_string();
+        assert_eq!(svg.matches("class=\"s0\"").count(), 2);
+        assert!(!svg.contains(".s1 {"));
+    }
+
+    #[test]
+    fn test_css_classes_distinct_styles() {
+        let mut renderer = SvgRenderer::new(800, 600).with_css_classes(true);
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .line_to(Vector2D::new(1.0, 1.0));
+
+        renderer.begin_frame().unwrap();
+        renderer
+            .draw_path(&path, &PathStyle::stroke(Color::RED, 2.0))
+            .unwrap();
+        renderer
+            .draw_path(&path, &PathStyle::stroke(Color::BLUE, 2.0))
+            .unwrap();
+        renderer
+            .draw_path(&path, &PathStyle::stroke(Color::RED, 2.0))
+            .unwrap();
+        renderer.end_frame().unwrap();
+
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains(".s0 { stroke: #FF0000;"));
+        assert!(svg.contains(".s1 { stroke: #0000FF;"));
+        assert_eq!(svg.matches("class=\"s0\"").count(), 2);
+        assert_eq!(svg.matches("class=\"s1\"").count(), 1);
+
+        // A new frame starts with a fresh set of classes
+        renderer.begin_frame().unwrap();
+        renderer.end_frame().unwrap();
+        assert!(!renderer.to_svg_string().contains("<style>"));
+    }
+
+    #[test]
+    fn test_push_transform_wraps_in_group() {
+        let mut renderer = SvgRenderer::new(800, 600);
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .line_to(Vector2D::new(1.0, 0.0));
+
+        renderer.begin_frame().unwrap();
+        renderer
+            .push_transform(&Transform::translate(10.0, 20.0))
+            .unwrap();
+        renderer.draw_path(&path, &PathStyle::default()).unwrap();
+        renderer.pop_transform().unwrap();
+        renderer.draw_path(&path, &PathStyle::default()).unwrap();
+        renderer.end_frame().unwrap();
+
+        let svg = renderer.to_svg_string();
+        let group_start = svg.find("<g transform=\"matrix(1 0 0 1 10 20)\">").unwrap();
+        let first_path = svg.find("<path").unwrap();
+        let group_end = svg[group_start..].find("</g>").unwrap() + group_start;
+        let second_path = svg.rfind("<path").unwrap();
+        assert!(group_start < first_path && first_path < group_end);
+        assert!(second_path > group_end);
+
+        assert!(renderer.pop_transform().is_err());
+    }
+
+    #[test]
+    fn test_draw_image_keeps_rotation() {
+        let mut renderer = SvgRenderer::new(800, 600);
+        let quarter_turn = Transform {
+            a: 0.0,
+            b: 1.0,
+            c: -1.0,
+            d: 0.0,
+            tx: 0.0,
+            ty: 0.0,
+        };
+        let placement =
+            quarter_turn * Transform::translate(-40.0, -20.0) * Transform::scale(80.0, 40.0);
+
+        renderer.begin_frame().unwrap();
+        renderer
+            .draw_image(&[255, 0, 0, 255, 0, 0, 255, 255], 2, 1, &placement, 0.5)
+            .unwrap();
+        renderer.end_frame().unwrap();
+
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("<image width=\"2\" height=\"1\""));
+        // Pixel x runs up the scene and pixel y runs right
+        assert!(svg.contains("transform=\"matrix(0 40 40 0 -20 -40)\""));
+        assert!(svg.contains("opacity=\"0.5\""));
+        assert!(svg.contains("href=\"data:image/png;base64,"));
+
+        assert!(renderer
+            .draw_image(&[0; 3], 1, 1, &Transform::identity(), 1.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_mismatched_group_pop_is_rejected() {
+        let mut renderer = SvgRenderer::new(800, 600);
+        renderer.begin_frame().unwrap();
+        renderer.push_group_opacity(0.5).unwrap();
+        renderer
+            .push_transform(&Transform::translate(1.0, 0.0))
+            .unwrap();
+
+        // The transform group is innermost, so an opacity pop must fail
+        assert!(renderer.pop_group_opacity().is_err());
+        renderer.pop_transform().unwrap();
+        assert!(renderer.pop_transform().is_err());
+        renderer.pop_group_opacity().unwrap();
+    }
+
+    #[test]
+    fn test_svg_capabilities() {
+        let caps = SvgRenderer::new(100, 100).capabilities();
+        assert!(caps.text);
+        assert!(caps.transparency);
+        assert!(caps.images);
+    }
+
+    #[test]
+    fn test_top_left_coordinate_system() {
+        let mut renderer =
+            SvgRenderer::new(800, 600).with_coordinate_system(CoordinateSystem::TopLeftYDown);
+        renderer.clear(Color::BLACK).unwrap();
+
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("viewBox=\"0 0 800 600\""));
+        assert!(!svg.contains("scale(1, -1)"));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\""));
+    }
+
+    #[test]
+    fn test_default_coordinate_system_is_centered() {
+        let renderer = SvgRenderer::new(800, 600);
+        assert_eq!(renderer.coordinate_system, CoordinateSystem::CenteredYUp);
+
+        let svg = renderer.to_svg_string();
+        assert!(svg.contains("viewBox=\"-400 -300 800 600\""));
+        assert!(svg.contains("<g transform=\"scale(1, -1)\">"));
+    }
+
+    #[test]
+    fn test_embed_font_adds_font_face() {
+        let mut renderer = SvgRenderer::new(100, 100).with_css_classes(true);
+        renderer.embed_font("Test Sans", b"fake ttf bytes");
+        renderer.clear(Color::BLACK).unwrap();
+        renderer
+            .draw_text("hi", Vector2D::ZERO, &TextStyle::default())
+            .unwrap();
+
+        let svg = renderer.to_svg_string();
+        assert_eq!(svg.matches("<style>").count(), 1);
+        assert!(svg.contains("font-family: \"Test Sans\";"));
+        assert!(svg.contains("src: url(data:font/ttf;base64,ZmFrZSB0dGYgYnl0ZXM=)"));
+        assert!(svg.find("@font-face") < svg.find("<g"));
+    }
+
+    #[test]
+    fn test_with_config_precision() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(1.23456, -0.5))
+            .line_to(Vector2D::new(2.0, 1.0 / 3.0));
+        let style = PathStyle::stroke(Color::RED, 1.0);
+
+        let mut default = SvgRenderer::new(100, 100);
+        default.draw_path(&path, &style).unwrap();
+        assert!(default
+            .to_svg_string()
+            .contains("d=\"M 1.23 -0.5 L 2 0.33\""));
+
+        let config = RenderConfig {
+            precision: 4,
+            ..RenderConfig::default()
+        };
+        let mut precise = SvgRenderer::with_config(100, 100, config);
+        precise.draw_path(&path, &style).unwrap();
+        assert!(precise
+            .to_svg_string()
+            .contains("d=\"M 1.2346 -0.5 L 2 0.3333\""));
+    }
 }
@@ -4,7 +4,12 @@
 
 use crate::renderer::{Path, PathCommand};
 
-/// Converts a path to an SVG path `d` attribute string.
+/// Converts a path to an SVG path `d` attribute string, keeping `precision`
+/// decimal places in each coordinate.
+///
+/// Only `M`, `L`, `Q`, `C` and `Z` are emitted. Paths have no arc command, so
+/// arcs read by [`Path::from_svg_d`] come back out as the cubic segments they
+/// were converted to.
 ///
 /// # Examples
 ///
@@ -19,7 +24,7 @@ use crate::renderer::{Path, PathCommand};
 ///
 /// // path_to_svg_d is used internally by the SVG backend
 /// ```
-pub fn path_to_svg_d(path: &Path) -> String {
+pub fn path_to_svg_d(path: &Path, precision: usize) -> String {
     let commands = path.commands();
     if commands.is_empty() {
         return String::new();
@@ -32,7 +37,7 @@ pub fn path_to_svg_d(path: &Path) -> String {
         if i > 0 {
             result.push(' ');
         }
-        result.push_str(&path_command_to_svg(cmd));
+        result.push_str(&path_command_to_svg(cmd, precision));
     }
 
     result
@@ -50,16 +55,24 @@ pub fn path_to_svg_d(path: &Path) -> String {
 /// let cmd = PathCommand::MoveTo(Vector2D::new(10.0, 20.0));
 /// // path_command_to_svg is internal, use path_to_svg_d instead
 /// ```
-pub fn path_command_to_svg(cmd: &PathCommand) -> String {
+pub fn path_command_to_svg(cmd: &PathCommand, precision: usize) -> String {
     match cmd {
-        PathCommand::MoveTo(p) => format!("M {} {}", format_coord(p.x), format_coord(p.y)),
-        PathCommand::LineTo(p) => format!("L {} {}", format_coord(p.x), format_coord(p.y)),
+        PathCommand::MoveTo(p) => format!(
+            "M {} {}",
+            format_coord(p.x, precision),
+            format_coord(p.y, precision)
+        ),
+        PathCommand::LineTo(p) => format!(
+            "L {} {}",
+            format_coord(p.x, precision),
+            format_coord(p.y, precision)
+        ),
         PathCommand::QuadraticTo { control, to } => format!(
             "Q {} {} {} {}",
-            format_coord(control.x),
-            format_coord(control.y),
-            format_coord(to.x),
-            format_coord(to.y)
+            format_coord(control.x, precision),
+            format_coord(control.y, precision),
+            format_coord(to.x, precision),
+            format_coord(to.y, precision)
         ),
         PathCommand::CubicTo {
             control1,
@@ -67,12 +80,12 @@ pub fn path_command_to_svg(cmd: &PathCommand) -> String {
             to,
         } => format!(
             "C {} {} {} {} {} {}",
-            format_coord(control1.x),
-            format_coord(control1.y),
-            format_coord(control2.x),
-            format_coord(control2.y),
-            format_coord(to.x),
-            format_coord(to.y)
+            format_coord(control1.x, precision),
+            format_coord(control1.y, precision),
+            format_coord(control2.x, precision),
+            format_coord(control2.y, precision),
+            format_coord(to.x, precision),
+            format_coord(to.y, precision)
         ),
         PathCommand::Close => "Z".to_string(),
     }
@@ -80,17 +93,21 @@ pub fn path_command_to_svg(cmd: &PathCommand) -> String {
 
 /// Formats a coordinate value for SVG output.
 ///
-/// Rounds to 2 decimal places to reduce file size while maintaining visual accuracy.
+/// Rounds to `precision` decimal places to reduce file size while
+/// maintaining visual accuracy, then drops trailing zeros. Halves round
+/// away from zero.
 #[inline]
-fn format_coord(value: f64) -> String {
-    // Round to 2 decimal places
-    let rounded = (value * 100.0).round() / 100.0;
+fn format_coord(value: f64, precision: usize) -> String {
+    let scale = 10f64.powi(precision as i32);
+    let rounded = (value * scale).round() / scale;
 
     // Remove trailing zeros and decimal point if integer
     if rounded.fract().abs() < f64::EPSILON {
         format!("{}", rounded as i32)
     } else {
-        format!("{:.2}", rounded).trim_end_matches('0').to_string()
+        format!("{:.*}", precision, rounded)
+            .trim_end_matches('0')
+            .to_string()
     }
 }
 
@@ -101,34 +118,42 @@ mod tests {
 
     #[test]
     fn test_format_coord_integer() {
-        assert_eq!(format_coord(10.0), "10");
-        assert_eq!(format_coord(-5.0), "-5");
-        assert_eq!(format_coord(0.0), "0");
+        assert_eq!(format_coord(10.0, 2), "10");
+        assert_eq!(format_coord(-5.0, 2), "-5");
+        assert_eq!(format_coord(0.0, 2), "0");
     }
 
     #[test]
     fn test_format_coord_decimal() {
-        assert_eq!(format_coord(10.5), "10.5");
-        assert_eq!(format_coord(3.15), "3.15");
-        assert_eq!(format_coord(-2.7), "-2.7");
+        assert_eq!(format_coord(10.5, 2), "10.5");
+        assert_eq!(format_coord(3.15, 2), "3.15");
+        assert_eq!(format_coord(-2.7, 2), "-2.7");
+    }
+
+    #[test]
+    fn test_format_coord_rounds_half_away_from_zero() {
+        assert_eq!(format_coord(0.125, 2), "0.13");
+        assert_eq!(format_coord(-0.125, 2), "-0.13");
+        assert_eq!(format_coord(0.0625, 3), "0.063");
+        assert_eq!(format_coord(2.5, 0), "3");
     }
 
     #[test]
     fn test_format_coord_removes_trailing_zeros() {
-        assert_eq!(format_coord(10.10), "10.1");
-        assert_eq!(format_coord(5.00), "5");
+        assert_eq!(format_coord(10.10, 2), "10.1");
+        assert_eq!(format_coord(5.00, 2), "5");
     }
 
     #[test]
     fn test_move_to_svg() {
         let cmd = PathCommand::MoveTo(Vector2D::new(10.0, 20.0));
-        assert_eq!(path_command_to_svg(&cmd), "M 10 20");
+        assert_eq!(path_command_to_svg(&cmd, 2), "M 10 20");
     }
 
     #[test]
     fn test_line_to_svg() {
         let cmd = PathCommand::LineTo(Vector2D::new(30.5, 40.25));
-        assert_eq!(path_command_to_svg(&cmd), "L 30.5 40.25");
+        assert_eq!(path_command_to_svg(&cmd, 2), "L 30.5 40.25");
     }
 
     #[test]
@@ -137,7 +162,7 @@ mod tests {
             control: Vector2D::new(10.0, 20.0),
             to: Vector2D::new(30.0, 40.0),
         };
-        assert_eq!(path_command_to_svg(&cmd), "Q 10 20 30 40");
+        assert_eq!(path_command_to_svg(&cmd, 2), "Q 10 20 30 40");
     }
 
     #[test]
@@ -147,19 +172,19 @@ mod tests {
             control2: Vector2D::new(3.0, 4.0),
             to: Vector2D::new(5.0, 6.0),
         };
-        assert_eq!(path_command_to_svg(&cmd), "C 1 2 3 4 5 6");
+        assert_eq!(path_command_to_svg(&cmd, 2), "C 1 2 3 4 5 6");
     }
 
     #[test]
     fn test_close_svg() {
         let cmd = PathCommand::Close;
-        assert_eq!(path_command_to_svg(&cmd), "Z");
+        assert_eq!(path_command_to_svg(&cmd, 2), "Z");
     }
 
     #[test]
     fn test_path_to_svg_d_empty() {
         let path = Path::new();
-        assert_eq!(path_to_svg_d(&path), "");
+        assert_eq!(path_to_svg_d(&path, 2), "");
     }
 
     #[test]
@@ -168,7 +193,7 @@ mod tests {
         path.move_to(Vector2D::new(0.0, 0.0))
             .line_to(Vector2D::new(10.0, 10.0));
 
-        let d = path_to_svg_d(&path);
+        let d = path_to_svg_d(&path, 2);
         assert_eq!(d, "M 0 0 L 10 10");
     }
 
@@ -180,7 +205,7 @@ mod tests {
             .line_to(Vector2D::new(0.5, 1.0))
             .close();
 
-        let d = path_to_svg_d(&path);
+        let d = path_to_svg_d(&path, 2);
         assert_eq!(d, "M 0 0 L 1 0 L 0.5 1 Z");
     }
 
@@ -193,7 +218,15 @@ mod tests {
             Vector2D::new(5.0, 0.0),
         );
 
-        let d = path_to_svg_d(&path);
+        let d = path_to_svg_d(&path, 2);
         assert_eq!(d, "M 0 0 C 1 2 3 4 5 0");
     }
+
+    #[test]
+    fn test_format_coord_precision() {
+        assert_eq!(format_coord(1.23456, 4), "1.2346");
+        assert_eq!(format_coord(1.23456, 0), "1");
+        assert_eq!(format_coord(120.0, 0), "120");
+        assert_eq!(format_coord(-0.001, 2), "0");
+    }
 }
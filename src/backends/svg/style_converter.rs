@@ -3,7 +3,9 @@
 //! This module converts manim-rs style types into SVG attribute key-value pairs.
 
 use crate::core::Color;
-use crate::renderer::{FontWeight, PathFillRule, PathStyle, TextAlignment, TextStyle};
+use crate::renderer::{
+    FontWeight, PathFillRule, PathStyle, TextAlignment, TextStyle, VerticalAlignment,
+};
 
 /// Converts a [`PathStyle`] to SVG attributes.
 ///
@@ -27,14 +29,10 @@ pub fn path_style_to_svg_attrs(style: &PathStyle) -> Vec<(&'static str, String)>
         attrs.push(("stroke", color_to_svg(stroke_color)));
         attrs.push(("stroke-width", format!("{}", style.stroke_width)));
 
-        // Apply opacity to stroke if needed
-        if style.opacity < 1.0 {
-            let stroke_opacity = stroke_color.a * style.opacity;
-            if stroke_opacity < 1.0 {
-                attrs.push(("stroke-opacity", format!("{:.3}", stroke_opacity)));
-            }
-        } else if stroke_color.a < 1.0 {
-            attrs.push(("stroke-opacity", format!("{:.3}", stroke_color.a)));
+        // Combined color alpha, stroke opacity and overall opacity
+        let stroke_opacity = stroke_color.a * style.stroke_opacity() * style.opacity;
+        if stroke_opacity < 1.0 {
+            attrs.push(("stroke-opacity", format!("{:.3}", stroke_opacity)));
         }
     } else {
         attrs.push(("stroke", "none".to_string()));
@@ -44,14 +42,10 @@ pub fn path_style_to_svg_attrs(style: &PathStyle) -> Vec<(&'static str, String)>
     if let Some(fill_color) = &style.fill_color {
         attrs.push(("fill", color_to_svg(fill_color)));
 
-        // Apply opacity to fill if needed
-        if style.opacity < 1.0 {
-            let fill_opacity = fill_color.a * style.opacity;
-            if fill_opacity < 1.0 {
-                attrs.push(("fill-opacity", format!("{:.3}", fill_opacity)));
-            }
-        } else if fill_color.a < 1.0 {
-            attrs.push(("fill-opacity", format!("{:.3}", fill_color.a)));
+        // Combined color alpha, fill opacity and overall opacity
+        let fill_opacity = fill_color.a * style.fill_opacity() * style.opacity;
+        if fill_opacity < 1.0 {
+            attrs.push(("fill-opacity", format!("{:.3}", fill_opacity)));
         }
 
         // Fill rule
@@ -71,7 +65,7 @@ pub fn path_style_to_svg_attrs(style: &PathStyle) -> Vec<(&'static str, String)>
 ///
 /// Returns a vector of attribute name-value pairs suitable for inclusion in an SVG text element.
 pub fn text_style_to_svg_attrs(style: &TextStyle) -> Vec<(&'static str, String)> {
-    let mut attrs = Vec::with_capacity(6);
+    let mut attrs = Vec::with_capacity(10);
 
     attrs.push(("fill", color_to_svg(&style.color)));
 
@@ -82,6 +76,16 @@ pub fn text_style_to_svg_attrs(style: &TextStyle) -> Vec<(&'static str, String)>
         }
     }
 
+    if let Some(stroke) = style.stroke_color {
+        if style.stroke_width > 0.0 {
+            attrs.push(("stroke", color_to_svg(&stroke)));
+            attrs.push(("stroke-width", format!("{}", style.stroke_width)));
+            attrs.push(("stroke-linejoin", "round".to_string()));
+            // Paint the outline first so it sits behind the glyph fill
+            attrs.push(("paint-order", "stroke".to_string()));
+        }
+    }
+
     attrs.push(("font-size", format!("{}", style.font_size)));
     attrs.push(("font-family", style.font_family.clone()));
 
@@ -92,12 +96,24 @@ pub fn text_style_to_svg_attrs(style: &TextStyle) -> Vec<(&'static str, String)>
     attrs.push(("font-weight", weight.to_string()));
 
     let anchor = match style.alignment {
-        TextAlignment::Left => "start",
+        // SVG text is a single line, so justified text starts at the anchor
+        TextAlignment::Left | TextAlignment::Justify => "start",
         TextAlignment::Center => "middle",
         TextAlignment::Right => "end",
     };
     attrs.push(("text-anchor", anchor.to_string()));
 
+    // The baseline is the SVG default, so it is left implicit
+    let baseline = match style.vertical_alignment {
+        VerticalAlignment::Top => Some("text-before-edge"),
+        VerticalAlignment::Middle => Some("middle"),
+        VerticalAlignment::Bottom => Some("text-after-edge"),
+        VerticalAlignment::Baseline => None,
+    };
+    if let Some(baseline) = baseline {
+        attrs.push(("dominant-baseline", baseline.to_string()));
+    }
+
     attrs
 }
 
@@ -106,6 +122,9 @@ pub fn text_style_to_svg_attrs(style: &TextStyle) -> Vec<(&'static str, String)>
 /// Returns a hex color string in the format `#RRGGBB`.
 /// Alpha channel is handled separately via opacity attributes.
 ///
+/// Channels are rounded to the nearest 8-bit value, exactly as
+/// [`Color::to_hex`] does.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -116,12 +135,7 @@ pub fn text_style_to_svg_attrs(style: &TextStyle) -> Vec<(&'static str, String)>
 /// // color_to_svg is used internally by the SVG backend
 /// ```
 pub fn color_to_svg(color: &Color) -> String {
-    format!(
-        "#{:02X}{:02X}{:02X}",
-        (color.r * 255.0) as u8,
-        (color.g * 255.0) as u8,
-        (color.b * 255.0) as u8
-    )
+    color.to_hex()
 }
 
 /// Escapes special XML characters in text content.
@@ -138,14 +152,75 @@ pub fn escape_xml(text: &str) -> String {
         .collect()
 }
 
+/// Builds an `@font-face` rule embedding a TrueType font as a data URL.
+///
+/// The family name is escaped for a CSS string, and `&` and `<` are escaped
+/// so the rule can sit directly inside an SVG `<style>` element.
+pub fn font_face_rule(family: &str, ttf_bytes: &[u8]) -> String {
+    let family: String = family
+        .chars()
+        .flat_map(|c| match c {
+            '\\' => vec!['\\', '\\'],
+            '"' => vec!['\\', '"'],
+            c => vec![c],
+        })
+        .collect();
+    let family = family.replace('&', "&amp;").replace('<', "&lt;");
+    format!(
+        "@font-face {{ font-family: \"{}\"; src: url(data:font/ttf;base64,{}) format(\"truetype\"); }}",
+        family,
+        base64_encode(ttf_bytes)
+    )
+}
+
+/// Encodes bytes as standard, padded base64.
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64_encode(&[0xFF, 0xFE]), "//4=");
+    }
+
+    #[test]
+    fn test_font_face_rule_escapes_family() {
+        let rule = font_face_rule("A \"B\" & <C>", &[0, 1, 2]);
+        assert!(rule.contains(r#"font-family: "A \"B\" &amp; &lt;C>";"#));
+        assert!(rule.contains("url(data:font/ttf;base64,AAEC)"));
+    }
+
     #[test]
     fn test_color_to_svg() {
         let color = Color::rgba(1.0, 0.5, 0.0, 1.0);
-        assert_eq!(color_to_svg(&color), "#FF7F00");
+        assert_eq!(color_to_svg(&color), "#FF8000");
 
         let black = Color::BLACK;
         assert_eq!(color_to_svg(&black), "#000000");
@@ -160,6 +235,15 @@ mod tests {
         assert_eq!(color_to_svg(&color), "#FF5733");
     }
 
+    #[test]
+    fn test_path_style_with_no_stroke_to_svg_attrs() {
+        let style = PathStyle::default().with_fill(Color::RED).with_no_stroke();
+        let attrs = path_style_to_svg_attrs(&style);
+
+        assert!(attrs.iter().any(|(k, v)| k == &"stroke" && v == "none"));
+        assert!(attrs.iter().any(|(k, v)| k == &"fill" && v == "#FF0000"));
+    }
+
     #[test]
     fn test_path_style_stroke_only() {
         let style = PathStyle::stroke(Color::from_hex("#FF0000").unwrap(), 2.5);
@@ -203,6 +287,33 @@ mod tests {
             .any(|(k, v)| k == &"stroke-opacity" && v.starts_with("0.5")));
     }
 
+    #[test]
+    fn test_path_style_fill_opacity_only() {
+        let style = PathStyle::default()
+            .with_stroke(Color::BLUE, 2.0)
+            .with_fill(Color::RED)
+            .with_fill_opacity(0.3)
+            .with_stroke_opacity(1.0);
+        let attrs = path_style_to_svg_attrs(&style);
+
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == &"fill-opacity" && v == "0.300"));
+        assert!(!attrs.iter().any(|(k, _)| k == &"stroke-opacity"));
+    }
+
+    #[test]
+    fn test_path_style_stroke_opacity_combines() {
+        let style = PathStyle::stroke(Color::BLUE, 2.0)
+            .with_stroke_opacity(0.5)
+            .with_opacity(0.5);
+        let attrs = path_style_to_svg_attrs(&style);
+
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == &"stroke-opacity" && v == "0.250"));
+    }
+
     #[test]
     fn test_path_style_with_fill_rule() {
         let style = PathStyle::fill(Color::RED).with_fill_rule(PathFillRule::EvenOdd);
@@ -233,6 +344,37 @@ mod tests {
         assert!(attrs
             .iter()
             .any(|(k, v)| k == &"text-anchor" && v == "middle"));
+        assert!(!attrs.iter().any(|(k, _)| k == &"stroke"));
+        assert!(!attrs.iter().any(|(k, _)| k == &"dominant-baseline"));
+    }
+
+    #[test]
+    fn test_text_style_vertical_alignment_to_svg_attrs() {
+        let style =
+            TextStyle::new(Color::WHITE, 48.0).with_vertical_alignment(VerticalAlignment::Middle);
+        let attrs = text_style_to_svg_attrs(&style);
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == &"dominant-baseline" && v == "middle"));
+
+        let style = TextStyle::new(Color::WHITE, 48.0).with_alignment(TextAlignment::Justify);
+        let attrs = text_style_to_svg_attrs(&style);
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == &"text-anchor" && v == "start"));
+    }
+
+    #[test]
+    fn test_text_style_outline_to_svg_attrs() {
+        let style = TextStyle::new(Color::WHITE, 48.0).with_stroke(Color::BLACK, 4.0);
+
+        let attrs = text_style_to_svg_attrs(&style);
+
+        assert!(attrs.iter().any(|(k, v)| k == &"stroke" && v == "#000000"));
+        assert!(attrs.iter().any(|(k, v)| k == &"stroke-width" && v == "4"));
+        assert!(attrs
+            .iter()
+            .any(|(k, v)| k == &"paint-order" && v == "stroke"));
     }
 
     #[test]
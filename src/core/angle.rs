@@ -19,8 +19,13 @@
 //! // Normalize angles
 //! let normalized = Degrees(450.0).normalized();
 //! assert_eq!(normalized.0, 90.0);
+//!
+//! // Compose angles with ordinary operators
+//! assert_eq!(Degrees(30.0) + Degrees(60.0), Degrees(90.0));
+//! assert_eq!(-Degrees(45.0) * 2.0, Degrees(-90.0));
 //! ```
 
+use crate::utils::inverse_interpolate;
 use std::f64::consts::PI;
 
 /// An angle measured in degrees.
@@ -96,6 +101,97 @@ impl Degrees {
         Self(angle)
     }
 
+    /// Linearly interpolates between two angles without wrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Degrees;
+    ///
+    /// assert_eq!(Degrees(0.0).lerp(Degrees(270.0), 0.5), Degrees(135.0));
+    /// ```
+    #[inline]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        Self(self.0 + (other.0 - self.0) * t)
+    }
+
+    /// Interpolates between two angles along the shorter arc.
+    ///
+    /// The result is normalized to [0, 360).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Degrees;
+    ///
+    /// let mid = Degrees(350.0).lerp_shortest(Degrees(10.0), 0.5);
+    /// assert!(mid.0.abs() < 1e-10);
+    /// ```
+    #[inline]
+    pub fn lerp_shortest(self, other: Self, t: f64) -> Self {
+        self.to_radians()
+            .lerp_shortest(other.to_radians(), t)
+            .to_degrees()
+    }
+
+    /// Returns where `value` falls between `start` and `end`.
+    ///
+    /// The result is 0.0 at `start` and 1.0 at `end`, and is not clamped.
+    /// Returns 0.0 when `start` and `end` are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Degrees;
+    ///
+    /// let t = Degrees::inverse_lerp(Degrees(0.0), Degrees(90.0), Degrees(45.0));
+    /// assert_eq!(t, 0.5);
+    /// ```
+    #[inline]
+    pub fn inverse_lerp(start: Self, end: Self, value: Self) -> f64 {
+        inverse_interpolate(start.0, end.0, value.0)
+    }
+
+    /// Returns `true` if the angle lies on the counter-clockwise sweep from
+    /// `start` to `end`, endpoints included.
+    ///
+    /// Angles are compared modulo a full turn, so a sweep may cross zero. A
+    /// sweep of a full turn or more, with `end - start >= 360`, contains
+    /// every angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Degrees;
+    ///
+    /// // The sweep from 350° to 30° crosses 0°
+    /// assert!(Degrees(10.0).is_between(Degrees(350.0), Degrees(30.0)));
+    /// assert!(!Degrees(180.0).is_between(Degrees(350.0), Degrees(30.0)));
+    /// ```
+    #[inline]
+    pub fn is_between(self, start: Self, end: Self) -> bool {
+        if end.0 - start.0 >= Self::FULL_CIRCLE.0 {
+            return true;
+        }
+        let sweep = (end - start).normalized();
+        let offset = (self - start).normalized();
+        offset.0 <= sweep.0
+    }
+
+    /// Restricts the angle to `[lo, hi]` without wrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Degrees;
+    ///
+    /// assert_eq!(Degrees(120.0).clamp(Degrees(0.0), Degrees(90.0)), Degrees(90.0));
+    /// ```
+    #[inline]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        Self(self.0.clamp(lo.0, hi.0))
+    }
+
     /// Returns the sine of the angle.
     #[inline]
     pub fn sin(self) -> f64 {
@@ -170,6 +266,102 @@ impl Radians {
         Self(angle)
     }
 
+    /// Linearly interpolates between two angles without wrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Radians;
+    /// use std::f64::consts::PI;
+    ///
+    /// assert_eq!(Radians(0.0).lerp(Radians(PI), 0.5), Radians(PI / 2.0));
+    /// ```
+    #[inline]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        Self(self.0 + (other.0 - self.0) * t)
+    }
+
+    /// Interpolates between two angles along the shorter arc.
+    ///
+    /// The difference between the angles is wrapped into [-π, π) before
+    /// interpolating, so rotations never take the long way around the
+    /// circle. The result is normalized to [0, 2π).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Radians;
+    /// use std::f64::consts::PI;
+    ///
+    /// // From just below 2π to just above 0 passes through 0, not π
+    /// let mid = Radians(2.0 * PI - 0.2).lerp_shortest(Radians(0.2), 0.5);
+    /// assert!(mid.0.abs() < 1e-10 || (mid.0 - 2.0 * PI).abs() < 1e-10);
+    /// ```
+    #[inline]
+    pub fn lerp_shortest(self, other: Self, t: f64) -> Self {
+        let delta = (other.0 - self.0 + PI).rem_euclid(2.0 * PI) - PI;
+        Self(self.0 + delta * t).normalized()
+    }
+
+    /// Returns where `value` falls between `start` and `end`.
+    ///
+    /// The result is 0.0 at `start` and 1.0 at `end`, and is not clamped.
+    /// Returns 0.0 when `start` and `end` are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Radians;
+    /// use std::f64::consts::PI;
+    ///
+    /// let t = Radians::inverse_lerp(Radians(0.0), Radians(PI), Radians(PI / 4.0));
+    /// assert_eq!(t, 0.25);
+    /// ```
+    #[inline]
+    pub fn inverse_lerp(start: Self, end: Self, value: Self) -> f64 {
+        inverse_interpolate(start.0, end.0, value.0)
+    }
+
+    /// Returns `true` if the angle lies on the counter-clockwise sweep from
+    /// `start` to `end`, endpoints included.
+    ///
+    /// Angles are compared modulo a full turn, so a sweep may cross zero. A
+    /// sweep of a full turn or more, with `end - start >= 2π`, contains
+    /// every angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Radians;
+    ///
+    /// // The sweep from 6.0 to 0.5 crosses zero
+    /// assert!(Radians(0.1).is_between(Radians(6.0), Radians(0.5)));
+    /// assert!(!Radians(3.0).is_between(Radians(6.0), Radians(0.5)));
+    /// ```
+    #[inline]
+    pub fn is_between(self, start: Self, end: Self) -> bool {
+        if end.0 - start.0 >= Self::FULL_CIRCLE.0 {
+            return true;
+        }
+        let sweep = (end - start).normalized();
+        let offset = (self - start).normalized();
+        offset.0 <= sweep.0
+    }
+
+    /// Restricts the angle to `[lo, hi]` without wrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Radians;
+    ///
+    /// assert_eq!(Radians(-1.0).clamp(Radians(0.0), Radians(1.0)), Radians(0.0));
+    /// ```
+    #[inline]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        Self(self.0.clamp(lo.0, hi.0))
+    }
+
     /// Returns the sine of the angle.
     #[inline]
     pub fn sin(self) -> f64 {
@@ -204,6 +396,74 @@ impl From<Radians> for Degrees {
     }
 }
 
+// Arithmetic is only defined between angles of the same unit, so mixing
+// degrees and radians requires an explicit conversion.
+macro_rules! impl_angle_ops {
+    ($angle:ident) => {
+        impl std::ops::Add for $angle {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+        }
+
+        impl std::ops::Sub for $angle {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+        }
+
+        impl std::ops::Mul<f64> for $angle {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, scalar: f64) -> Self {
+                Self(self.0 * scalar)
+            }
+        }
+
+        impl std::ops::Div<f64> for $angle {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, scalar: f64) -> Self {
+                Self(self.0 / scalar)
+            }
+        }
+
+        impl std::ops::Neg for $angle {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl std::ops::AddAssign for $angle {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+
+        impl std::ops::SubAssign for $angle {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                self.0 -= other.0;
+            }
+        }
+    };
+}
+
+impl_angle_ops!(Degrees);
+impl_angle_ops!(Radians);
+
 // Common angle constants
 impl Degrees {
     pub const ZERO: Self = Self(0.0);
@@ -359,4 +619,105 @@ mod tests {
         assert_relative_eq!(Radians::DOWN.0, 3.0 * PI / 2.0, epsilon = 1e-10);
         assert_relative_eq!(Radians::FULL_CIRCLE.0, 2.0 * PI, epsilon = 1e-10);
     }
+
+    #[test]
+    fn test_degrees_arithmetic() {
+        assert_eq!(Degrees(30.0) + Degrees(60.0), Degrees(90.0));
+        assert_eq!(Degrees(90.0) - Degrees(30.0), Degrees(60.0));
+        assert_eq!(Degrees(45.0) * 2.0, Degrees(90.0));
+        assert_eq!(Degrees(90.0) / 3.0, Degrees(30.0));
+        assert_eq!(-Degrees(45.0), Degrees(-45.0));
+    }
+
+    #[test]
+    fn test_radians_arithmetic() {
+        assert_relative_eq!((Radians(PI) * 2.0).0, 2.0 * PI);
+        assert_relative_eq!((Radians(PI) / 2.0).0, PI / 2.0);
+        assert_relative_eq!((Radians(PI) - Radians(PI / 2.0)).0, PI / 2.0);
+        assert_eq!(-Radians(PI), Radians(-PI));
+    }
+
+    #[test]
+    fn test_angle_assign_ops() {
+        let mut angle = Degrees(10.0);
+        angle += Degrees(20.0);
+        assert_eq!(angle, Degrees(30.0));
+        angle -= Degrees(5.0);
+        assert_eq!(angle, Degrees(25.0));
+
+        let mut rad = Radians::ZERO;
+        rad += Radians::UP;
+        assert_eq!(rad, Radians::UP);
+    }
+
+    #[test]
+    fn test_degrees_lerp_shortest_wraps() {
+        let mid = Degrees(350.0).lerp_shortest(Degrees(10.0), 0.5);
+        assert_relative_eq!(mid.0, 0.0, epsilon = 1e-10);
+
+        let quarter = Degrees(350.0).lerp_shortest(Degrees(10.0), 0.25);
+        assert_relative_eq!(quarter.0, 355.0, epsilon = 1e-10);
+
+        let reverse = Degrees(10.0).lerp_shortest(Degrees(350.0), 0.5);
+        assert_relative_eq!(reverse.0, 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_radians_lerp_shortest() {
+        let mid = Radians(0.0).lerp_shortest(Radians(PI / 2.0), 0.5);
+        assert_relative_eq!(mid.0, PI / 4.0, epsilon = 1e-10);
+
+        let end = Radians(0.1).lerp_shortest(Radians(2.0 * PI - 0.1), 1.0);
+        assert_relative_eq!(end.0, 2.0 * PI - 0.1, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_angle_lerp_plain() {
+        assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 0.5), Degrees(180.0));
+    }
+
+    #[test]
+    fn test_angle_inverse_lerp() {
+        assert_eq!(
+            Degrees::inverse_lerp(Degrees(10.0), Degrees(30.0), Degrees(15.0)),
+            0.25
+        );
+        assert_eq!(
+            Radians::inverse_lerp(Radians(1.0), Radians(1.0), Radians(2.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_radians_is_between() {
+        // Plain sweep
+        assert!(Radians(1.0).is_between(Radians(0.0), Radians(PI)));
+        assert!(Radians(0.0).is_between(Radians(0.0), Radians(PI)));
+        assert!(Radians(PI).is_between(Radians(0.0), Radians(PI)));
+        assert!(!Radians(4.0).is_between(Radians(0.0), Radians(PI)));
+        assert!(!Radians(-0.1).is_between(Radians(0.0), Radians(PI)));
+
+        // Sweep across the 2π wrap
+        assert!(Radians(0.1).is_between(Radians(6.0), Radians(0.5)));
+        assert!(Radians(6.2).is_between(Radians(6.0), Radians(0.5)));
+        assert!(!Radians(3.0).is_between(Radians(6.0), Radians(0.5)));
+
+        // Angles outside [0, 2π) and full turns
+        assert!(Radians(1.0 + 4.0 * PI).is_between(Radians(0.0), Radians(PI)));
+        assert!(Radians(4.0).is_between(Radians(0.0), Radians(2.0 * PI)));
+    }
+
+    #[test]
+    fn test_degrees_is_between_and_clamp() {
+        assert!(Degrees(10.0).is_between(Degrees(350.0), Degrees(30.0)));
+        assert!(!Degrees(40.0).is_between(Degrees(350.0), Degrees(30.0)));
+        assert_eq!(
+            Degrees(-5.0).clamp(Degrees(0.0), Degrees(90.0)),
+            Degrees(0.0)
+        );
+        assert_eq!(
+            Degrees(45.0).clamp(Degrees(0.0), Degrees(90.0)),
+            Degrees(45.0)
+        );
+    }
 }
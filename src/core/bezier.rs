@@ -171,10 +171,10 @@ impl QuadraticBezier {
         self.p2
     }
 
-    /// Computes an axis-aligned bounding box for the curve.
+    /// Computes the tight axis-aligned bounding box of the curve.
     ///
-    /// This is a conservative bounding box that may be larger than the
-    /// actual bounding box of the curve.
+    /// The box covers the endpoints and any interior extrema, so it hugs the
+    /// curve rather than its control polygon.
     ///
     /// # Examples
     ///
@@ -190,35 +190,21 @@ impl QuadraticBezier {
     /// let bbox = curve.bounding_box();
     /// assert!(bbox.contains_point(curve.start()));
     /// assert!(bbox.contains_point(curve.end()));
+    /// // The peak is at y = 1, below the control point
+    /// assert_eq!(bbox.max().y, 1.0);
     /// ```
     pub fn bounding_box(&self) -> BoundingBox {
-        // For quadratic Bézier curves, we need to check all control points
-        // and potentially evaluate the curve at the point where the derivative is zero
-        let mut points = vec![self.p0, self.p1, self.p2];
-
-        // Check if the curve has local extrema
-        // For quadratic Bézier: B'(t) = 2(1-t)(P1-P0) + 2t(P2-P1) = 0
-        // Solving for t: 2(1-t)(P1-P0) = -2t(P2-P1)
-        // (1-t)(P1-P0) = -t(P2-P1)
-        // P1-P0 + t(P2-P1) = t(P1-P0)
-        // P1-P0 = t(P2-P1 + P1-P0)
-        // t = (P1-P0) / (P2-P1 + P1-P0) = (P1-P0) / (P2-P0)
-
-        // Check for extrema in x direction
-        let dx = self.p2.x - self.p0.x;
-        if dx != 0.0 {
-            let t = (self.p1.x - self.p0.x) / dx;
-            if (0.0..=1.0).contains(&t) {
-                points.push(self.evaluate(t));
-            }
-        }
-
-        // Check for extrema in y direction
-        let dy = self.p2.y - self.p0.y;
-        if dy != 0.0 {
-            let t = (self.p1.y - self.p0.y) / dy;
-            if (0.0..=1.0).contains(&t) {
-                points.push(self.evaluate(t));
+        let mut points = vec![self.p0, self.p2];
+
+        // B'(t) = 2[(1-t)(P1-P0) + t(P2-P1)] = 0  =>  t = (P0-P1) / (P0-2P1+P2)
+        let denom = self.p0 - self.p1 * 2.0 + self.p2;
+        let numer = self.p0 - self.p1;
+        for (n, d) in [(numer.x, denom.x), (numer.y, denom.y)] {
+            if d.abs() > 1e-12 {
+                let t = n / d;
+                if t > 0.0 && t < 1.0 {
+                    points.push(self.evaluate(t));
+                }
             }
         }
 
@@ -288,6 +274,36 @@ impl QuadraticBezier {
 
         length
     }
+
+    /// Returns the same curve as a cubic Bézier (degree elevation).
+    ///
+    /// The cubic's inner control points lie two thirds of the way from each
+    /// endpoint towards the quadratic control point, so both curves trace
+    /// exactly the same shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{QuadraticBezier, Vector2D};
+    ///
+    /// let quad = QuadraticBezier::new(
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(3.0, 3.0),
+    ///     Vector2D::new(6.0, 0.0),
+    /// );
+    ///
+    /// let cubic = quad.to_cubic();
+    /// assert_eq!(cubic.p1, Vector2D::new(2.0, 2.0));
+    /// assert_eq!(cubic.p2, Vector2D::new(4.0, 2.0));
+    /// ```
+    pub fn to_cubic(&self) -> CubicBezier {
+        CubicBezier::new(
+            self.p0,
+            self.p0.lerp(self.p1, 2.0 / 3.0),
+            self.p2.lerp(self.p1, 2.0 / 3.0),
+            self.p2,
+        )
+    }
 }
 
 impl CubicBezier {
@@ -362,54 +378,56 @@ impl CubicBezier {
         self.p3
     }
 
-    /// Computes an axis-aligned bounding box for the curve.
+    /// Computes the tight axis-aligned bounding box of the curve.
+    ///
+    /// The box covers the endpoints and any interior extrema, so it hugs the
+    /// curve rather than its control polygon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{CubicBezier, Vector2D};
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(0.0, 2.0),
+    ///     Vector2D::new(2.0, 2.0),
+    ///     Vector2D::new(2.0, 0.0),
+    /// );
     ///
-    /// This is a conservative bounding box that may be larger than the
-    /// actual bounding box of the curve.
+    /// // The peak is at y = 1.5, below the control points
+    /// assert_eq!(curve.bounding_box().max().y, 1.5);
+    /// ```
     pub fn bounding_box(&self) -> BoundingBox {
-        // For cubic Bézier curves, we need to check all control points
-        // and potentially evaluate at points where derivatives are zero
-        let mut points = vec![self.p0, self.p3, self.p1, self.p2];
-
-        // For cubic curves, B'(t) = 3(1-t)²(P1-P0) + 6(1-t)t(P2-P1) + 3t²(P3-P2) = 0
-        // This is a quadratic equation: at² + bt + c = 0
-        // where a = 3(P0 - 2P1 + P2), b = 2(P1 - P0), c = P0 - P1 (wait, let me recalculate)
-
-        // Actually, let's use the standard formula:
-        // B'(t) = -3P0(1-t)² + 3P1(1-t)² - 6P1(1-t)t + 6P2(1-t)t - 3P2 t² + 3P3 t²
-        // Simplified: B'(t) = 3(1-t)²(P1-P0) + 6(1-t)t(P2-P1) + 3t²(P3-P2)
-
-        // Set B'(t) = 0 and solve for t. This gives a quadratic equation:
-        // A t² + B t + C = 0 where:
-        let a_coeff = (self.p0 - self.p1 * 2.0 + self.p2) * 3.0; // 3(P0 - 2P1 + P2)
-        let b_coeff = (self.p1 - self.p0) * 6.0; // 6(P1 - P0)
-        let c_coeff = (self.p2 - self.p1) * 3.0; // 3(P2 - P1)
-
-        // For each dimension, solve quadratic equation
-        for dim in 0..2 {
-            let a_val = if dim == 0 { a_coeff.x } else { a_coeff.y };
-            let b_val = if dim == 0 { b_coeff.x } else { b_coeff.y };
-            let c_val = if dim == 0 { c_coeff.x } else { c_coeff.y };
-
-            if a_val.abs() > 1e-10 {
-                // Quadratic formula: t = [-b ± sqrt(b² - 4ac)] / 2a
-                let discriminant = b_val * b_val - 4.0 * a_val * c_val;
+        let mut points = vec![self.p0, self.p3];
+
+        // B'(t)/3 = (1-t)²D0 + 2(1-t)t D1 + t²D2 with Di = P(i+1) - Pi,
+        // which expands to a t² + b t + c with the coefficients below.
+        let d0 = self.p1 - self.p0;
+        let d1 = self.p2 - self.p1;
+        let d2 = self.p3 - self.p2;
+        let a_coeff = d0 - d1 * 2.0 + d2;
+        let b_coeff = (d1 - d0) * 2.0;
+        let c_coeff = d0;
+
+        for (a, b, c) in [
+            (a_coeff.x, b_coeff.x, c_coeff.x),
+            (a_coeff.y, b_coeff.y, c_coeff.y),
+        ] {
+            let mut roots = [f64::NAN; 2];
+            if a.abs() > 1e-12 {
+                let discriminant = b * b - 4.0 * a * c;
                 if discriminant >= 0.0 {
                     let sqrt_d = discriminant.sqrt();
-                    let t1 = (-b_val - sqrt_d) / (2.0 * a_val);
-                    let t2 = (-b_val + sqrt_d) / (2.0 * a_val);
-
-                    if (0.0..=1.0).contains(&t1) {
-                        points.push(self.evaluate(t1));
-                    }
-                    if (0.0..=1.0).contains(&t2) {
-                        points.push(self.evaluate(t2));
-                    }
+                    roots = [(-b - sqrt_d) / (2.0 * a), (-b + sqrt_d) / (2.0 * a)];
                 }
-            } else if b_val.abs() > 1e-10 {
+            } else if b.abs() > 1e-12 {
                 // Linear case
-                let t = -c_val / b_val;
-                if (0.0..=1.0).contains(&t) {
+                roots[0] = -c / b;
+            }
+
+            for t in roots {
+                if t > 0.0 && t < 1.0 {
                     points.push(self.evaluate(t));
                 }
             }
@@ -615,13 +633,11 @@ mod tests {
 
         let bbox = bezier.bounding_box();
 
-        // Should contain all control points and extrema
-        assert!(bbox.contains_point(Vector2D::new(0.0, 0.0)));
-        assert!(bbox.contains_point(Vector2D::new(2.0, 2.0)));
-        assert!(bbox.contains_point(Vector2D::new(4.0, 0.0)));
-
-        // The curve reaches y=1 at its peak
-        assert!(bbox.contains_point(Vector2D::new(2.0, 1.0)));
+        // Tight around the endpoints and the peak at y=1, excluding the control point
+        assert_eq!(bbox.min(), Vector2D::new(0.0, 0.0));
+        assert_relative_eq!(bbox.max().x, 4.0);
+        assert_relative_eq!(bbox.max().y, 1.0);
+        assert!(!bbox.contains_point(Vector2D::new(2.0, 2.0)));
     }
 
     #[test]
@@ -635,11 +651,29 @@ mod tests {
 
         let bbox = bezier.bounding_box();
 
-        // Should contain all control points
-        assert!(bbox.contains_point(Vector2D::new(0.0, 0.0)));
-        assert!(bbox.contains_point(Vector2D::new(1.0, 2.0)));
-        assert!(bbox.contains_point(Vector2D::new(3.0, 2.0)));
-        assert!(bbox.contains_point(Vector2D::new(4.0, 0.0)));
+        // Tight around the endpoints and the peak at y=1.5
+        assert_eq!(bbox.min(), Vector2D::new(0.0, 0.0));
+        assert_relative_eq!(bbox.max().x, 4.0);
+        assert_relative_eq!(bbox.max().y, 1.5);
+    }
+
+    #[test]
+    fn test_cubic_bezier_bounding_box_horizontal_extrema() {
+        // S-shaped curve overshooting its endpoints in x
+        let bezier = CubicBezier::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(3.0, 1.0),
+            Vector2D::new(-2.0, 2.0),
+            Vector2D::new(1.0, 3.0),
+        );
+
+        let bbox = bezier.bounding_box();
+        let samples = (0..=1000).map(|i| bezier.evaluate(i as f64 / 1000.0));
+        let sampled = BoundingBox::from_points(samples).unwrap();
+
+        assert_relative_eq!(bbox.min().x, sampled.min().x, epsilon = 1e-4);
+        assert_relative_eq!(bbox.max().x, sampled.max().x, epsilon = 1e-4);
+        assert!(bbox.max().x < 3.0);
     }
 
     #[test]
@@ -779,4 +813,21 @@ mod tests {
         assert_eq!(second.start(), bezier.end());
         assert_eq!(second.end(), bezier.end());
     }
+
+    #[test]
+    fn test_quadratic_to_cubic_traces_same_curve() {
+        let quad = QuadraticBezier::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 2.0),
+            Vector2D::new(3.0, -1.0),
+        );
+        let cubic = quad.to_cubic();
+
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let (a, b) = (quad.evaluate(t), cubic.evaluate(t));
+            assert_relative_eq!(a.x, b.x, epsilon = 1e-12);
+            assert_relative_eq!(a.y, b.y, epsilon = 1e-12);
+        }
+    }
 }
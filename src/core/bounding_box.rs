@@ -21,7 +21,7 @@
 //! assert!(bbox.contains_point(Vector2D::new(0.0, 1.0)));
 //! ```
 
-use crate::core::Vector2D;
+use crate::core::{Transform, Vector2D};
 use std::fmt;
 
 /// An axis-aligned bounding box in 2D space.
@@ -82,9 +82,12 @@ impl BoundingBox {
     ///
     /// * `points` - Iterator of points to bound
     ///
+    /// Points with an infinite or NaN component are skipped, so a single
+    /// degenerate point cannot poison the bounds of the rest.
+    ///
     /// # Returns
     ///
-    /// `None` if the points iterator is empty.
+    /// `None` if the iterator yields no finite points.
     ///
     /// # Examples
     ///
@@ -95,6 +98,7 @@ impl BoundingBox {
     ///     Vector2D::new(0.0, 0.0),
     ///     Vector2D::new(2.0, 3.0),
     ///     Vector2D::new(-1.0, 1.0),
+    ///     Vector2D::new(f64::NAN, 5.0),
     /// ];
     ///
     /// let bbox = BoundingBox::from_points(points).unwrap();
@@ -106,7 +110,7 @@ impl BoundingBox {
     where
         I: IntoIterator<Item = Vector2D>,
     {
-        let mut iter = points.into_iter();
+        let mut iter = points.into_iter().filter(|p| p.is_finite());
         let first = iter.next()?;
 
         let mut min = first;
@@ -120,6 +124,36 @@ impl BoundingBox {
         Some(Self::new(min, max))
     }
 
+    /// Creates the smallest bounding box containing all given boxes.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the boxes iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Vector2D};
+    ///
+    /// let boxes = vec![
+    ///     BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 1.0)),
+    ///     BoundingBox::new(Vector2D::new(3.0, -2.0), Vector2D::new(4.0, 0.0)),
+    /// ];
+    ///
+    /// let bbox = BoundingBox::from_bounding_boxes(boxes).unwrap();
+    /// assert_eq!(bbox.min(), Vector2D::new(0.0, -2.0));
+    /// assert_eq!(bbox.max(), Vector2D::new(4.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn from_bounding_boxes<I>(boxes: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = BoundingBox>,
+    {
+        let mut iter = boxes.into_iter();
+        let first = iter.next()?;
+        Some(first.union_all(iter))
+    }
+
     /// Creates an empty bounding box centered at the origin with zero size.
     ///
     /// # Examples
@@ -206,6 +240,59 @@ impl BoundingBox {
         2.0 * (self.width() + self.height())
     }
 
+    /// Returns the ratio of width to height.
+    ///
+    /// A box with zero height has an infinite ratio, unless its width is
+    /// zero too, in which case the ratio is 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Vector2D};
+    ///
+    /// let bbox = BoundingBox::new(Vector2D::ZERO, Vector2D::new(4.0, 2.0));
+    /// assert_eq!(bbox.aspect_ratio(), 2.0);
+    /// ```
+    #[inline]
+    pub fn aspect_ratio(&self) -> f64 {
+        match (self.width(), self.height()) {
+            (w, h) if h > 0.0 => w / h,
+            (w, _) if w > 0.0 => f64::INFINITY,
+            _ => 1.0,
+        }
+    }
+
+    /// Returns the length of the diagonal from `min` to `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Vector2D};
+    ///
+    /// let bbox = BoundingBox::new(Vector2D::ZERO, Vector2D::new(3.0, 4.0));
+    /// assert_eq!(bbox.diagonal(), 5.0);
+    /// ```
+    #[inline]
+    pub fn diagonal(&self) -> f64 {
+        (self.max - self.min).magnitude()
+    }
+
+    /// Checks if width and height differ by at most `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Vector2D};
+    ///
+    /// let bbox = BoundingBox::new(Vector2D::ZERO, Vector2D::new(2.0, 2.05));
+    /// assert!(bbox.is_square(0.1));
+    /// assert!(!bbox.is_square(0.01));
+    /// ```
+    #[inline]
+    pub fn is_square(&self, epsilon: f64) -> bool {
+        (self.width() - self.height()).abs() <= epsilon
+    }
+
     /// Checks if the bounding box is empty (has zero area).
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -269,6 +356,59 @@ impl BoundingBox {
             && other.max.y <= self.max.y
     }
 
+    /// Checks if another bounding box lies inside this one, allowing it to
+    /// stick out by up to `margin` on each side.
+    ///
+    /// A margin of 0.0 behaves like [`contains_bbox`](BoundingBox::contains_bbox).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Vector2D};
+    ///
+    /// let frame = BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(4.0, 4.0));
+    /// let label = BoundingBox::new(Vector2D::new(3.0, 3.0), Vector2D::new(4.1, 4.0));
+    ///
+    /// assert!(!frame.contains_bbox(&label));
+    /// assert!(frame.contains_bbox_within(&label, 0.2));
+    /// ```
+    #[inline]
+    pub fn contains_bbox_within(&self, other: &BoundingBox, margin: f64) -> bool {
+        other.min.x >= self.min.x - margin
+            && other.max.x <= self.max.x + margin
+            && other.min.y >= self.min.y - margin
+            && other.max.y <= self.max.y + margin
+    }
+
+    /// Linearly interpolates the corners of two bounding boxes.
+    ///
+    /// `t = 0.0` yields `self` and `t = 1.0` yields `other`, which makes it
+    /// suitable for animating a camera frame from one region to another.
+    /// Values of `t` outside [0.0, 1.0] extrapolate, with the corners
+    /// swapped where needed to keep the box valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Vector2D};
+    ///
+    /// let a = BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 2.0));
+    /// let b = BoundingBox::new(Vector2D::new(2.0, 4.0), Vector2D::new(6.0, 8.0));
+    ///
+    /// let mid = a.lerp(&b, 0.5);
+    /// assert_eq!(mid.min(), Vector2D::new(1.0, 2.0));
+    /// assert_eq!(mid.max(), Vector2D::new(4.0, 5.0));
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &BoundingBox, t: f64) -> BoundingBox {
+        let a = self.min.lerp(other.min, t);
+        let b = self.max.lerp(other.max, t);
+        Self {
+            min: Vector2D::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Vector2D::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
     /// Checks if this bounding box intersects with another.
     ///
     /// # Arguments
@@ -374,6 +514,30 @@ impl BoundingBox {
         )
     }
 
+    /// Returns the union of this bounding box with every box in `others`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Vector2D};
+    ///
+    /// let bbox = BoundingBox::zero().union_all(vec![
+    ///     BoundingBox::new(Vector2D::new(1.0, 1.0), Vector2D::new(2.0, 2.0)),
+    ///     BoundingBox::new(Vector2D::new(-3.0, 0.0), Vector2D::new(-2.0, 1.0)),
+    /// ]);
+    /// assert_eq!(bbox.min(), Vector2D::new(-3.0, 0.0));
+    /// assert_eq!(bbox.max(), Vector2D::new(2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn union_all<I>(&self, others: I) -> BoundingBox
+    where
+        I: IntoIterator<Item = BoundingBox>,
+    {
+        others
+            .into_iter()
+            .fold(*self, |bbox, other| bbox.union(&other))
+    }
+
     /// Expands the bounding box to include a point.
     ///
     /// # Arguments
@@ -478,6 +642,36 @@ impl BoundingBox {
 
         BoundingBox::new(center - half_size, center + half_size)
     }
+
+    /// Applies a transformation, returning the bounding box of the result.
+    ///
+    /// All four corners are transformed and re-enclosed, so rotations yield
+    /// the axis-aligned box around the rotated rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Transform, Vector2D};
+    ///
+    /// let bbox = BoundingBox::new(
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(2.0, 1.0)
+    /// );
+    ///
+    /// let moved = bbox.transform(&Transform::translate(1.0, 1.0));
+    /// assert_eq!(moved.min(), Vector2D::new(1.0, 1.0));
+    /// assert_eq!(moved.max(), Vector2D::new(3.0, 2.0));
+    /// ```
+    pub fn transform(&self, transform: &Transform) -> BoundingBox {
+        let corners = [
+            self.min,
+            Vector2D::new(self.max.x, self.min.y),
+            self.max,
+            Vector2D::new(self.min.x, self.max.y),
+        ];
+        // Four corners always yield a box
+        BoundingBox::from_points(corners.map(|c| transform.apply(c))).unwrap_or(*self)
+    }
 }
 
 impl Default for BoundingBox {
@@ -544,6 +738,23 @@ mod tests {
         assert_eq!(bbox.max(), Vector2D::new(1.0, 2.0));
     }
 
+    #[test]
+    fn test_from_points_skips_non_finite() {
+        let points = vec![
+            Vector2D::new(f64::NAN, 0.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(f64::INFINITY, -5.0),
+            Vector2D::new(-2.0, 3.0),
+        ];
+
+        let bbox = BoundingBox::from_points(points).unwrap();
+        assert!(bbox.min().is_finite() && bbox.max().is_finite());
+        assert_eq!(bbox.min(), Vector2D::new(-2.0, 1.0));
+        assert_eq!(bbox.max(), Vector2D::new(1.0, 3.0));
+
+        assert!(BoundingBox::from_points(vec![Vector2D::new(f64::NAN, f64::NAN)]).is_none());
+    }
+
     #[test]
     fn test_zero() {
         let bbox = BoundingBox::zero();
@@ -682,4 +893,93 @@ mod tests {
         let bbox = BoundingBox::default();
         assert_eq!(bbox, BoundingBox::zero());
     }
+
+    #[test]
+    fn test_transform_scale_about_center() {
+        let bbox = BoundingBox::new(Vector2D::new(1.0, 1.0), Vector2D::new(3.0, 2.0));
+        let center = bbox.center();
+        let scaled = bbox.transform(&Transform::scale_about(2.0, 2.0, center));
+
+        assert_eq!(scaled.center(), center);
+        assert_eq!(scaled.size(), Vector2D::new(4.0, 2.0));
+    }
+
+    #[test]
+    fn test_transform_rotation() {
+        let bbox = BoundingBox::new(Vector2D::new(-1.0, -1.0), Vector2D::new(1.0, 1.0));
+        let rotated = bbox.transform(&Transform::rotate(std::f64::consts::PI / 4.0));
+
+        let expected = 2.0_f64.sqrt();
+        assert!((rotated.max().x - expected).abs() < 1e-10);
+        assert!((rotated.min().y + expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_from_bounding_boxes() {
+        let boxes = [
+            BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 1.0)),
+            BoundingBox::new(Vector2D::new(5.0, 2.0), Vector2D::new(6.0, 3.0)),
+            BoundingBox::new(Vector2D::new(-4.0, -1.0), Vector2D::new(-3.0, 0.5)),
+        ];
+
+        let bbox = BoundingBox::from_bounding_boxes(boxes).unwrap();
+        assert_eq!(bbox.min(), Vector2D::new(-4.0, -1.0));
+        assert_eq!(bbox.max(), Vector2D::new(6.0, 3.0));
+    }
+
+    #[test]
+    fn test_from_bounding_boxes_empty() {
+        assert!(BoundingBox::from_bounding_boxes(std::iter::empty()).is_none());
+    }
+
+    #[test]
+    fn test_union_all() {
+        let bbox = BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 1.0));
+        assert_eq!(bbox.union_all(std::iter::empty()), bbox);
+
+        let union = bbox.union_all([BoundingBox::new(
+            Vector2D::new(2.0, 2.0),
+            Vector2D::new(3.0, 3.0),
+        )]);
+        assert_eq!(union.max(), Vector2D::new(3.0, 3.0));
+    }
+
+    #[test]
+    fn test_lerp_averages_corners() {
+        let a = BoundingBox::new(Vector2D::new(-2.0, 0.0), Vector2D::new(0.0, 1.0));
+        let b = BoundingBox::new(Vector2D::new(2.0, 3.0), Vector2D::new(6.0, 4.0));
+
+        let mid = a.lerp(&b, 0.5);
+        assert_eq!(mid.min(), Vector2D::new(0.0, 1.5));
+        assert_eq!(mid.max(), Vector2D::new(3.0, 2.5));
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+    }
+
+    #[test]
+    fn test_contains_bbox_within_margin() {
+        let outer = BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 1.0));
+        let poking_out = BoundingBox::new(Vector2D::new(-0.05, 0.5), Vector2D::new(0.5, 1.05));
+
+        assert!(!outer.contains_bbox(&poking_out));
+        assert!(!outer.contains_bbox_within(&poking_out, 0.01));
+        assert!(outer.contains_bbox_within(&poking_out, 0.1));
+    }
+
+    #[test]
+    fn test_aspect_ratio_diagonal_and_is_square() {
+        let wide = BoundingBox::new(Vector2D::ZERO, Vector2D::new(4.0, 2.0));
+        assert_eq!(wide.aspect_ratio(), 2.0);
+        assert!(!wide.is_square(1e-6));
+
+        let right_triangle = BoundingBox::new(Vector2D::new(1.0, 1.0), Vector2D::new(4.0, 5.0));
+        assert_eq!(right_triangle.diagonal(), 5.0);
+
+        let nearly = BoundingBox::new(Vector2D::ZERO, Vector2D::new(1.0, 1.0 + 1e-9));
+        assert!(nearly.is_square(1e-6));
+
+        let flat = BoundingBox::new(Vector2D::ZERO, Vector2D::new(3.0, 0.0));
+        assert_eq!(flat.aspect_ratio(), f64::INFINITY);
+        assert_eq!(BoundingBox::zero().aspect_ratio(), 1.0);
+    }
 }
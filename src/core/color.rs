@@ -1,3 +1,49 @@
+/// Blend modes for compositing one color over another.
+///
+/// The formulas follow the W3C Compositing and Blending specification, where
+/// the source is the color being drawn and the backdrop is the color already
+/// present underneath it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// The source replaces the backdrop (plain alpha compositing).
+    #[default]
+    Normal,
+
+    /// Multiplies source and backdrop, always producing a darker color.
+    Multiply,
+
+    /// Inverse of multiply, always producing a lighter color.
+    Screen,
+
+    /// Multiplies or screens depending on the backdrop, preserving highlights
+    /// and shadows.
+    Overlay,
+
+    /// Adds source and backdrop, clamped to 1.0 (linear dodge).
+    Add,
+}
+
+impl BlendMode {
+    /// Blends a single channel of source `s` over backdrop `b`.
+    #[inline]
+    fn blend_channel(self, s: f64, b: f64) -> f64 {
+        match self {
+            Self::Normal => s,
+            Self::Multiply => s * b,
+            Self::Screen => s + b - s * b,
+            Self::Overlay => {
+                if b <= 0.5 {
+                    2.0 * s * b
+                } else {
+                    let b2 = 2.0 * b - 1.0;
+                    s + b2 - s * b2
+                }
+            }
+            Self::Add => (s + b).min(1.0),
+        }
+    }
+}
+
 /// An RGBA color representation.
 ///
 /// Colors are stored as normalized floating-point values (0.0 to 1.0)
@@ -81,8 +127,72 @@ impl Color {
         Some(Self::rgb(r, g, b))
     }
 
+    /// Creates a color from a packed `0xRRGGBBAA` integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    ///
+    /// let red = Color::from_u32_rgba(0xFF0000FF);
+    /// assert_eq!(red, Color::rgb(255, 0, 0));
+    /// ```
+    #[inline]
+    pub fn from_u32_rgba(value: u32) -> Self {
+        let [r, g, b, a] = value.to_be_bytes();
+        Self::rgba(
+            r as f64 / 255.0,
+            g as f64 / 255.0,
+            b as f64 / 255.0,
+            a as f64 / 255.0,
+        )
+    }
+
+    /// Creates an opaque color from a packed `0xRRGGBB` integer.
+    ///
+    /// The top byte is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    ///
+    /// let blue = Color::from_u32_rgb(0x0000FF);
+    /// assert_eq!(blue, Color::rgb(0, 0, 255));
+    /// ```
+    #[inline]
+    pub fn from_u32_rgb(value: u32) -> Self {
+        let [_, r, g, b] = value.to_be_bytes();
+        Self::rgb(r, g, b)
+    }
+
+    /// Packs the color into a `0xRRGGBBAA` integer.
+    ///
+    /// Channels are rounded to the nearest 8-bit value, as in
+    /// [`Color::to_hex_rgba`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    ///
+    /// let red = Color::rgba(1.0, 0.0, 0.0, 0.5);
+    /// assert_eq!(red.to_u32_rgba(), 0xFF000080);
+    /// ```
+    #[inline]
+    pub fn to_u32_rgba(&self) -> u32 {
+        u32::from_be_bytes([
+            channel_to_u8(self.r),
+            channel_to_u8(self.g),
+            channel_to_u8(self.b),
+            channel_to_u8(self.a),
+        ])
+    }
+
     /// Converts the color to a hex string (e.g., "#FF0000").
     ///
+    /// Channels are rounded to the nearest 8-bit value and alpha is ignored.
+    ///
     /// # Examples
     ///
     /// ```
@@ -94,12 +204,26 @@ impl Color {
     pub fn to_hex(&self) -> String {
         format!(
             "#{:02X}{:02X}{:02X}",
-            (self.r * 255.0) as u8,
-            (self.g * 255.0) as u8,
-            (self.b * 255.0) as u8
+            channel_to_u8(self.r),
+            channel_to_u8(self.g),
+            channel_to_u8(self.b)
         )
     }
 
+    /// Converts the color to a hex string including alpha (e.g., "#FF000080").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    ///
+    /// let red = Color::rgba(1.0, 0.0, 0.0, 0.5);
+    /// assert_eq!(red.to_hex_rgba(), "#FF000080");
+    /// ```
+    pub fn to_hex_rgba(&self) -> String {
+        format!("{}{:02X}", self.to_hex(), channel_to_u8(self.a))
+    }
+
     /// Linearly interpolates between this color and another.
     ///
     /// # Arguments
@@ -142,6 +266,89 @@ impl Color {
         self
     }
 
+    /// Composites this color over a backdrop using the given blend mode.
+    ///
+    /// The blend function is applied to the color channels, then the result
+    /// is composited over the backdrop with the "source-over" operator, so
+    /// partially transparent colors only blend in proportion to their alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BlendMode, Color};
+    ///
+    /// let backdrop = Color::rgb(51, 102, 204);
+    ///
+    /// // Multiplying by white leaves the backdrop unchanged
+    /// let result = Color::WHITE.blend(backdrop, BlendMode::Multiply);
+    /// assert!((result.r - backdrop.r).abs() < 1e-10);
+    /// assert!((result.b - backdrop.b).abs() < 1e-10);
+    /// ```
+    pub fn blend(self, backdrop: Self, mode: BlendMode) -> Self {
+        let alpha = self.a + backdrop.a * (1.0 - self.a);
+        if alpha <= 0.0 {
+            return Self::TRANSPARENT;
+        }
+
+        let channel = |s: f64, b: f64| {
+            // Where the backdrop is transparent the source shows unblended
+            let mixed = (1.0 - backdrop.a) * s + backdrop.a * mode.blend_channel(s, b);
+            (self.a * mixed + backdrop.a * b * (1.0 - self.a)) / alpha
+        };
+
+        Self::rgba(
+            channel(self.r, backdrop.r),
+            channel(self.g, backdrop.g),
+            channel(self.b, backdrop.b),
+            alpha,
+        )
+    }
+
+    /// Returns the WCAG relative luminance of the color.
+    ///
+    /// Each sRGB channel is linearized before weighting, giving 0.0 for
+    /// black and 1.0 for white. Alpha is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    ///
+    /// assert_eq!(Color::BLACK.relative_luminance(), 0.0);
+    /// assert!((Color::WHITE.relative_luminance() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn relative_luminance(&self) -> f64 {
+        let linear = |c: f64| {
+            let c = c.clamp(0.0, 1.0);
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// Returns the WCAG contrast ratio between two colors.
+    ///
+    /// The ratio ranges from 1.0 for identical luminance to 21.0 for black
+    /// against white and does not depend on argument order. WCAG asks for at
+    /// least 4.5 for body text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    ///
+    /// let ratio = Color::BLACK.contrast_ratio(&Color::WHITE);
+    /// assert!((ratio - 21.0).abs() < 1e-9);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
     // Common colors
     pub const WHITE: Self = Self::rgba(1.0, 1.0, 1.0, 1.0);
     pub const BLACK: Self = Self::rgba(0.0, 0.0, 0.0, 1.0);
@@ -154,6 +361,32 @@ impl Color {
     pub const TRANSPARENT: Self = Self::rgba(0.0, 0.0, 0.0, 0.0);
 }
 
+/// Returns black or white, whichever contrasts more with `background`.
+///
+/// Useful for picking a readable label color over an arbitrary fill.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::{best_text_color, Color};
+///
+/// assert_eq!(best_text_color(Color::WHITE), Color::BLACK);
+/// assert_eq!(best_text_color(Color::rgb(0, 0, 128)), Color::WHITE);
+/// ```
+pub fn best_text_color(background: Color) -> Color {
+    if Color::BLACK.contrast_ratio(&background) >= Color::WHITE.contrast_ratio(&background) {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
+/// Converts a [0, 1] channel to the nearest 8-bit value.
+#[inline]
+fn channel_to_u8(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,6 +420,20 @@ mod tests {
 
         let purple = Color::rgb(128, 0, 128);
         assert_eq!(purple.to_hex(), "#800080");
+        assert_eq!(Color::RED.to_hex(), "#FF0000");
+    }
+
+    #[test]
+    fn test_to_hex_rgba() {
+        assert_eq!(Color::rgba(1.0, 0.0, 0.0, 0.5).to_hex_rgba(), "#FF000080");
+        assert_eq!(Color::BLUE.to_hex_rgba(), "#0000FFFF");
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        for hex in ["#FF5733", "#010203", "#7F7F7F"] {
+            assert_eq!(Color::from_hex(hex).unwrap().to_hex(), hex);
+        }
     }
 
     #[test]
@@ -214,4 +461,104 @@ mod tests {
         assert_eq!(Color::BLACK.r, 0.0);
         assert_eq!(Color::TRANSPARENT.a, 0.0);
     }
+
+    #[test]
+    fn test_blend_multiply_white_is_identity() {
+        let backdrop = Color::rgb(51, 102, 204);
+        let result = Color::WHITE.blend(backdrop, BlendMode::Multiply);
+
+        assert!((result.r - backdrop.r).abs() < 1e-10);
+        assert!((result.g - backdrop.g).abs() < 1e-10);
+        assert!((result.b - backdrop.b).abs() < 1e-10);
+        assert_eq!(result.a, 1.0);
+    }
+
+    #[test]
+    fn test_blend_screen_black_is_identity() {
+        let backdrop = Color::rgb(51, 102, 204);
+        let result = Color::BLACK.blend(backdrop, BlendMode::Screen);
+
+        assert!((result.r - backdrop.r).abs() < 1e-10);
+        assert!((result.g - backdrop.g).abs() < 1e-10);
+        assert!((result.b - backdrop.b).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_blend_normal_alpha_mix() {
+        let source = Color::RED.with_alpha(0.25);
+        let result = source.blend(Color::BLUE, BlendMode::Normal);
+
+        assert!((result.r - 0.25).abs() < 1e-10);
+        assert!(result.g.abs() < 1e-10);
+        assert!((result.b - 0.75).abs() < 1e-10);
+        assert_eq!(result.a, 1.0);
+    }
+
+    #[test]
+    fn test_blend_add_clamps() {
+        let source = Color::rgba(0.75, 0.5, 0.0, 1.0);
+        let backdrop = Color::rgba(0.5, 0.25, 0.0, 1.0);
+        let result = source.blend(backdrop, BlendMode::Add);
+
+        assert!((result.r - 1.0).abs() < 1e-10);
+        assert!((result.g - 0.75).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_blend_overlay() {
+        let gray = Color::rgba(0.5, 0.5, 0.5, 1.0);
+        let dark = Color::rgba(0.25, 0.25, 0.25, 1.0);
+        let light = Color::rgba(0.75, 0.75, 0.75, 1.0);
+
+        // Dark backdrop multiplies, light backdrop screens
+        assert!((gray.blend(dark, BlendMode::Overlay).r - 0.25).abs() < 1e-10);
+        assert!((gray.blend(light, BlendMode::Overlay).r - 0.75).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_blend_over_transparent() {
+        let result = Color::RED.blend(Color::TRANSPARENT, BlendMode::Multiply);
+        assert_eq!(result, Color::RED);
+
+        let nothing = Color::TRANSPARENT.blend(Color::TRANSPARENT, BlendMode::Normal);
+        assert_eq!(nothing.a, 0.0);
+    }
+
+    #[test]
+    fn test_u32_conversions() {
+        let red = Color::from_u32_rgba(0xFF0000FF);
+        assert_eq!(red, Color::rgb(255, 0, 0));
+        assert_eq!(red.a, 1.0);
+        assert_eq!(red.to_u32_rgba(), 0xFF0000FF);
+
+        let teal = Color::from_u32_rgba(0x12_34_56_78);
+        assert_eq!(teal.to_u32_rgba(), 0x12_34_56_78);
+        assert_eq!(teal.to_hex_rgba(), "#12345678");
+
+        let opaque = Color::from_u32_rgb(0xAB_12_34_56);
+        assert_eq!(opaque.to_u32_rgba(), 0x12_34_56_FF);
+    }
+
+    #[test]
+    fn test_contrast_ratio_extremes() {
+        assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 1e-9);
+        assert!((Color::WHITE.contrast_ratio(&Color::BLACK) - 21.0).abs() < 1e-9);
+        assert_eq!(Color::RED.contrast_ratio(&Color::RED), 1.0);
+    }
+
+    #[test]
+    fn test_relative_luminance_weights_green_most() {
+        assert!(Color::GREEN.relative_luminance() > Color::RED.relative_luminance());
+        assert!(Color::RED.relative_luminance() > Color::BLUE.relative_luminance());
+        // Mid gray is darker than half once linearized
+        assert!(Color::rgb(128, 128, 128).relative_luminance() < 0.25);
+    }
+
+    #[test]
+    fn test_best_text_color() {
+        assert_eq!(best_text_color(Color::WHITE), Color::BLACK);
+        assert_eq!(best_text_color(Color::BLACK), Color::WHITE);
+        assert_eq!(best_text_color(Color::YELLOW), Color::BLACK);
+        assert_eq!(best_text_color(Color::BLUE), Color::WHITE);
+    }
 }
@@ -19,10 +19,63 @@ pub enum Error {
     #[error("Invalid configuration: {0}")]
     Config(String),
 
+    /// Invalid argument passed to a constructor or method
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     /// Generic error
     #[error("{0}")]
     Other(String),
 }
 
+/// Errors are equal when they are the same variant with the same message.
+///
+/// [`std::io::Error`] has no equality of its own, so two [`Error::Io`] values
+/// are compared by [`std::io::ErrorKind`] only.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::Error;
+///
+/// assert_eq!(Error::Render("bad".into()), Error::Render("bad".into()));
+/// assert_ne!(Error::Render("bad".into()), Error::Other("bad".into()));
+/// ```
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Error::Render(a), Error::Render(b))
+            | (Error::Animation(a), Error::Animation(b))
+            | (Error::Config(a), Error::Config(b))
+            | (Error::InvalidArgument(a), Error::InvalidArgument(b))
+            | (Error::Other(a), Error::Other(b)) => a == b,
+            (Error::Io(a), Error::Io(b)) => a.kind() == b.kind(),
+            _ => false,
+        }
+    }
+}
+
 /// Result type for manim-rs operations.
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_error_equality() {
+        assert_eq!(Error::Render("x".into()), Error::Render("x".into()));
+        assert_ne!(Error::Render("x".into()), Error::Render("y".into()));
+        assert_ne!(Error::Render("x".into()), Error::Config("x".into()));
+    }
+
+    #[test]
+    fn test_io_errors_compare_by_kind() {
+        let a = Error::from(io::Error::new(io::ErrorKind::NotFound, "a.svg"));
+        let b = Error::from(io::Error::new(io::ErrorKind::NotFound, "b.svg"));
+        let denied = Error::from(io::Error::from(io::ErrorKind::PermissionDenied));
+        assert_eq!(a, b);
+        assert_ne!(a, denied);
+    }
+}
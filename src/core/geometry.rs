@@ -0,0 +1,248 @@
+//! Computational geometry helpers.
+//!
+//! Small building blocks for geometric constructions: line and segment
+//! intersections and point-to-line distances. Higher-level operations such as
+//! polygon offsetting and hit-testing are built on top of these.
+//!
+//! # Examples
+//!
+//! ```
+//! use manim_rs::core::geometry::line_intersection;
+//! use manim_rs::core::Vector2D;
+//!
+//! // The x-axis and the vertical line x = 2
+//! let hit = line_intersection(
+//!     Vector2D::ZERO,
+//!     Vector2D::RIGHT,
+//!     Vector2D::new(2.0, 5.0),
+//!     Vector2D::UP,
+//! );
+//! assert_eq!(hit, Some(Vector2D::new(2.0, 0.0)));
+//! ```
+
+use crate::core::Vector2D;
+
+/// Tolerance below which direction vectors are considered parallel.
+const PARALLEL_EPSILON: f64 = 1e-12;
+
+/// Intersects two infinite lines given in parametric form.
+///
+/// The lines are `p1 + t * d1` and `p2 + s * d2`. Returns `None` if the lines
+/// are parallel (including coincident) or a direction is zero.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::geometry::line_intersection;
+/// use manim_rs::core::Vector2D;
+///
+/// let parallel = line_intersection(
+///     Vector2D::ZERO,
+///     Vector2D::RIGHT,
+///     Vector2D::new(0.0, 1.0),
+///     Vector2D::RIGHT,
+/// );
+/// assert_eq!(parallel, None);
+/// ```
+#[inline]
+pub fn line_intersection(
+    p1: Vector2D,
+    d1: Vector2D,
+    p2: Vector2D,
+    d2: Vector2D,
+) -> Option<Vector2D> {
+    let denom = d1.cross(d2);
+    if denom.abs() < PARALLEL_EPSILON {
+        return None;
+    }
+    let t = (p2 - p1).cross(d2) / denom;
+    Some(p1 + d1 * t)
+}
+
+/// Intersects the segments `a1..a2` and `b1..b2`.
+///
+/// Endpoints are included, so segments that merely touch intersect. Returns
+/// `None` if the segments do not cross or are parallel; overlapping collinear
+/// segments also return `None` since they share no single point.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::geometry::segment_intersection;
+/// use manim_rs::core::Vector2D;
+///
+/// let hit = segment_intersection(
+///     Vector2D::new(-1.0, 0.0),
+///     Vector2D::new(1.0, 0.0),
+///     Vector2D::new(0.0, -1.0),
+///     Vector2D::new(0.0, 1.0),
+/// );
+/// assert_eq!(hit, Some(Vector2D::new(0.0, 0.0)));
+///
+/// let miss = segment_intersection(
+///     Vector2D::new(-1.0, 0.0),
+///     Vector2D::new(1.0, 0.0),
+///     Vector2D::new(0.0, 1.0),
+///     Vector2D::new(0.0, 2.0),
+/// );
+/// assert_eq!(miss, None);
+/// ```
+pub fn segment_intersection(
+    a1: Vector2D,
+    a2: Vector2D,
+    b1: Vector2D,
+    b2: Vector2D,
+) -> Option<Vector2D> {
+    let da = a2 - a1;
+    let db = b2 - b1;
+    let denom = da.cross(db);
+    if denom.abs() < PARALLEL_EPSILON {
+        return None;
+    }
+
+    let offset = b1 - a1;
+    let t = offset.cross(db) / denom;
+    let u = offset.cross(da) / denom;
+
+    let range = -PARALLEL_EPSILON..=1.0 + PARALLEL_EPSILON;
+    if range.contains(&t) && range.contains(&u) {
+        Some(a1 + da * t)
+    } else {
+        None
+    }
+}
+
+/// Returns the distance from `p` to the infinite line through `a` and `b`.
+///
+/// If `a` and `b` coincide, the distance to that point is returned.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::geometry::point_line_distance;
+/// use manim_rs::core::Vector2D;
+///
+/// let d = point_line_distance(
+///     Vector2D::new(3.0, 4.0),
+///     Vector2D::ZERO,
+///     Vector2D::new(1.0, 0.0),
+/// );
+/// assert!((d - 4.0).abs() < 1e-10);
+/// ```
+#[inline]
+pub fn point_line_distance(p: Vector2D, a: Vector2D, b: Vector2D) -> f64 {
+    let direction = b - a;
+    let length = direction.magnitude();
+    if length == 0.0 {
+        return (p - a).magnitude();
+    }
+    direction.cross(p - a).abs() / length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_line_intersection_perpendicular() {
+        let hit = line_intersection(
+            Vector2D::new(0.0, 1.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(3.0, -4.0),
+            Vector2D::new(0.0, 2.0),
+        )
+        .unwrap();
+
+        assert_relative_eq!(hit.x, 3.0);
+        assert_relative_eq!(hit.y, 1.0);
+    }
+
+    #[test]
+    fn test_line_intersection_parallel() {
+        let result = line_intersection(
+            Vector2D::ZERO,
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(2.0, 2.0),
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_line_intersection_beyond_segments() {
+        // Infinite lines meet even where the direction vectors don't reach
+        let hit = line_intersection(
+            Vector2D::ZERO,
+            Vector2D::new(0.1, 0.0),
+            Vector2D::new(5.0, 5.0),
+            Vector2D::new(0.0, 0.1),
+        )
+        .unwrap();
+
+        assert_relative_eq!(hit.x, 5.0);
+        assert_relative_eq!(hit.y, 0.0);
+    }
+
+    #[test]
+    fn test_segment_intersection_crossing() {
+        let hit = segment_intersection(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(2.0, 2.0),
+            Vector2D::new(0.0, 2.0),
+            Vector2D::new(2.0, 0.0),
+        )
+        .unwrap();
+
+        assert_relative_eq!(hit.x, 1.0);
+        assert_relative_eq!(hit.y, 1.0);
+    }
+
+    #[test]
+    fn test_segment_intersection_touching_endpoint() {
+        let hit = segment_intersection(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+        );
+        assert_eq!(hit, Some(Vector2D::new(1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_segment_intersection_misses() {
+        let miss = segment_intersection(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(2.0, -1.0),
+            Vector2D::new(2.0, 1.0),
+        );
+        assert!(miss.is_none());
+
+        let parallel = segment_intersection(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(0.0, 1.0),
+            Vector2D::new(1.0, 1.0),
+        );
+        assert!(parallel.is_none());
+    }
+
+    #[test]
+    fn test_point_line_distance() {
+        let a = Vector2D::new(0.0, 0.0);
+        let b = Vector2D::new(1.0, 1.0);
+
+        assert_relative_eq!(
+            point_line_distance(Vector2D::new(1.0, 0.0), a, b),
+            std::f64::consts::FRAC_1_SQRT_2
+        );
+        assert_relative_eq!(point_line_distance(Vector2D::new(5.0, 5.0), a, b), 0.0);
+    }
+
+    #[test]
+    fn test_point_line_distance_degenerate() {
+        let a = Vector2D::new(1.0, 1.0);
+        assert_relative_eq!(point_line_distance(Vector2D::new(4.0, 5.0), a, a), 5.0);
+    }
+}
@@ -15,6 +15,7 @@ use super::Vector2D;
 /// let combined = translation * rotation;
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform {
     // Column-major 3x3 matrix for 2D affine transformations
     // [m11 m12 m13]   [a  c  tx]
@@ -119,6 +120,240 @@ impl Transform {
         }
     }
 
+    /// Creates a uniform scaling transformation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    ///
+    /// let t = Transform::scale_uniform(3.0);
+    /// assert_eq!(t.apply(Vector2D::new(1.0, 2.0)), Vector2D::new(3.0, 6.0));
+    /// ```
+    #[inline]
+    pub const fn scale_uniform(s: f64) -> Self {
+        Self::scale(s, s)
+    }
+
+    /// Creates a scaling transformation about a pivot point.
+    ///
+    /// The pivot stays fixed while distances from it are scaled, which is
+    /// equivalent to `translate(center) * scale(sx, sy) * translate(-center)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    ///
+    /// let center = Vector2D::new(1.0, 1.0);
+    /// let t = Transform::scale_about(2.0, 2.0, center);
+    ///
+    /// assert_eq!(t.apply(center), center);
+    /// assert_eq!(t.apply(Vector2D::new(2.0, 1.0)), Vector2D::new(3.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn scale_about(sx: f64, sy: f64, center: Vector2D) -> Self {
+        Self {
+            a: sx,
+            b: 0.0,
+            c: 0.0,
+            d: sy,
+            tx: center.x * (1.0 - sx),
+            ty: center.y * (1.0 - sy),
+        }
+    }
+
+    /// Creates a reflection across a line through the origin.
+    ///
+    /// The line runs along `axis`, which does not need to be normalized. A
+    /// zero axis has no direction and yields the identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    ///
+    /// // Mirror across the y-axis
+    /// let t = Transform::reflect(Vector2D::new(0.0, 1.0));
+    /// assert_eq!(t.apply(Vector2D::new(2.0, 3.0)), Vector2D::new(-2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn reflect(axis: Vector2D) -> Self {
+        let length_sq = axis.x * axis.x + axis.y * axis.y;
+        if length_sq == 0.0 {
+            return Self::identity();
+        }
+        let cos2 = (axis.x * axis.x - axis.y * axis.y) / length_sq;
+        let sin2 = 2.0 * axis.x * axis.y / length_sq;
+        Self {
+            a: cos2,
+            b: sin2,
+            c: sin2,
+            d: -cos2,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// Creates a reflection across the line through `point` along `axis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    ///
+    /// let t = Transform::reflect_about(Vector2D::new(0.0, 1.0), Vector2D::new(1.0, 0.0));
+    /// assert_eq!(t.apply(Vector2D::new(3.0, 5.0)), Vector2D::new(-1.0, 5.0));
+    /// ```
+    #[inline]
+    pub fn reflect_about(axis: Vector2D, point: Vector2D) -> Self {
+        Self::translate(point.x, point.y)
+            * Self::reflect(axis)
+            * Self::translate(-point.x, -point.y)
+    }
+
+    /// Creates the rotation, uniform scale and translation that maps
+    /// `src_a` onto `dst_a` and `src_b` onto `dst_b`.
+    ///
+    /// Returns `None` when the source points coincide, since no rotation or
+    /// scale is then determined. Coinciding destination points are allowed
+    /// and collapse everything onto `dst_a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    ///
+    /// let t = Transform::try_from_two_point_map(
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(1.0, 0.0),
+    ///     Vector2D::new(2.0, 2.0),
+    ///     Vector2D::new(2.0, 4.0),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(t.apply(Vector2D::new(0.5, 0.0)), Vector2D::new(2.0, 3.0));
+    ///
+    /// let p = Vector2D::new(1.0, 1.0);
+    /// assert!(Transform::try_from_two_point_map(p, p, Vector2D::ZERO, p).is_none());
+    /// ```
+    pub fn try_from_two_point_map(
+        src_a: Vector2D,
+        src_b: Vector2D,
+        dst_a: Vector2D,
+        dst_b: Vector2D,
+    ) -> Option<Self> {
+        let s = src_b - src_a;
+        let t = dst_b - dst_a;
+        let length_sq = s.x * s.x + s.y * s.y;
+        if length_sq == 0.0 {
+            return None;
+        }
+        // As complex numbers the linear part is the quotient t / s
+        let cos = (t.x * s.x + t.y * s.y) / length_sq;
+        let sin = (t.y * s.x - t.x * s.y) / length_sq;
+        let mut transform = Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            tx: 0.0,
+            ty: 0.0,
+        };
+        let offset = dst_a - transform.apply(src_a);
+        transform.tx = offset.x;
+        transform.ty = offset.y;
+        Some(transform)
+    }
+
+    /// Returns the transform as a 3x3 homogeneous matrix.
+    ///
+    /// The matrix is indexed `[row][column]` and includes the implicit
+    /// bottom row `[0, 0, 1]`, so the translation is in the last column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Transform;
+    ///
+    /// let m = Transform::translate(5.0, 3.0).to_homogeneous();
+    /// assert_eq!(m, [[1.0, 0.0, 5.0], [0.0, 1.0, 3.0], [0.0, 0.0, 1.0]]);
+    /// ```
+    #[inline]
+    pub fn to_homogeneous(&self) -> [[f64; 3]; 3] {
+        [
+            [self.a, self.c, self.tx],
+            [self.b, self.d, self.ty],
+            [0.0, 0.0, 1.0],
+        ]
+    }
+
+    /// Creates a transform from a 3x3 homogeneous matrix indexed
+    /// `[row][column]`, as returned by
+    /// [`to_homogeneous`](Transform::to_homogeneous).
+    ///
+    /// Returns `None` if the bottom row is not `[0, 0, 1]`, since such a
+    /// matrix is a projective transform with no affine equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Transform;
+    ///
+    /// let t = Transform::rotate(1.0) * Transform::translate(2.0, 0.0);
+    /// assert_eq!(Transform::from_homogeneous(t.to_homogeneous()), Some(t));
+    ///
+    /// let projective = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.5, 0.0, 1.0]];
+    /// assert!(Transform::from_homogeneous(projective).is_none());
+    /// ```
+    pub fn from_homogeneous(m: [[f64; 3]; 3]) -> Option<Self> {
+        if m[2] != [0.0, 0.0, 1.0] {
+            return None;
+        }
+        Some(Self {
+            a: m[0][0],
+            b: m[1][0],
+            c: m[0][1],
+            d: m[1][1],
+            tx: m[0][2],
+            ty: m[1][2],
+        })
+    }
+
+    /// Creates the rotation, uniform scale and translation that maps
+    /// `src_a` onto `dst_a` and `src_b` onto `dst_b`.
+    ///
+    /// This is the usual way to fit a mobject between two anchor points.
+    /// When the source points coincide the result is the translation taking
+    /// `src_a` to `dst_a`; use
+    /// [`try_from_two_point_map`](Transform::try_from_two_point_map) to
+    /// detect that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    ///
+    /// let t = Transform::from_two_point_map(
+    ///     Vector2D::new(-1.0, 0.0),
+    ///     Vector2D::new(1.0, 0.0),
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(0.0, 4.0),
+    /// );
+    /// let mid = t.apply(Vector2D::ZERO);
+    /// assert!((mid - Vector2D::new(0.0, 2.0)).magnitude() < 1e-10);
+    /// ```
+    pub fn from_two_point_map(
+        src_a: Vector2D,
+        src_b: Vector2D,
+        dst_a: Vector2D,
+        dst_b: Vector2D,
+    ) -> Self {
+        Self::try_from_two_point_map(src_a, src_b, dst_a, dst_b).unwrap_or_else(|| {
+            let offset = dst_a - src_a;
+            Self::translate(offset.x, offset.y)
+        })
+    }
+
     /// Applies the transformation to a vector.
     ///
     /// # Examples
@@ -138,6 +373,164 @@ impl Transform {
             self.b * v.x + self.d * v.y + self.ty,
         )
     }
+
+    /// Returns the determinant of the linear part.
+    ///
+    /// Its absolute value is the factor by which areas are scaled; it is
+    /// negative when the transform mirrors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Transform;
+    ///
+    /// assert_eq!(Transform::scale(2.0, 3.0).determinant(), 6.0);
+    /// assert_eq!(Transform::scale(-1.0, 1.0).determinant(), -1.0);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Returns the transform that undoes this one, or `None` if it is
+    /// singular, i.e. collapses the plane onto a line or a point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    ///
+    /// let t = Transform::translate(1.0, 2.0) * Transform::rotate(0.5) * Transform::scale(2.0, 4.0);
+    /// let inverse = t.inverse().unwrap();
+    /// assert!((inverse * t).is_identity(1e-12));
+    ///
+    /// assert!(Transform::scale(0.0, 1.0).inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Transform> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let a = self.d / det;
+        let b = -self.b / det;
+        let c = -self.c / det;
+        let d = self.a / det;
+        Some(Self {
+            a,
+            b,
+            c,
+            d,
+            tx: -(a * self.tx + c * self.ty),
+            ty: -(b * self.tx + d * self.ty),
+        })
+    }
+
+    /// Returns `true` if every matrix entry is within `epsilon` of the
+    /// corresponding entry of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Transform;
+    /// use std::f64::consts::PI;
+    ///
+    /// let quarter = Transform::rotate(PI / 4.0);
+    /// let half = Transform::rotate(PI / 2.0);
+    /// assert!((quarter * quarter).approx_eq(&half, 1e-10));
+    /// assert!(!quarter.approx_eq(&half, 1e-10));
+    /// ```
+    pub fn approx_eq(&self, other: &Transform, epsilon: f64) -> bool {
+        (self.a - other.a).abs() <= epsilon
+            && (self.b - other.b).abs() <= epsilon
+            && (self.c - other.c).abs() <= epsilon
+            && (self.d - other.d).abs() <= epsilon
+            && (self.tx - other.tx).abs() <= epsilon
+            && (self.ty - other.ty).abs() <= epsilon
+    }
+
+    /// Returns `true` if the transformation is the identity, up to
+    /// `epsilon` in each matrix entry.
+    ///
+    /// An `epsilon` of zero tests for the exact identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Transform;
+    ///
+    /// assert!(Transform::translate(0.0, 0.0).is_identity(0.0));
+    /// assert!(!Transform::translate(1e-6, 0.0).is_identity(1e-9));
+    /// ```
+    pub fn is_identity(&self, epsilon: f64) -> bool {
+        self.approx_eq(&Transform::identity(), epsilon)
+    }
+
+    /// Interpolates between two transformations.
+    ///
+    /// Both matrices are decomposed into translation, rotation, scale, and
+    /// shear components, which are interpolated independently and recomposed.
+    /// Rotation follows the shortest angular path, so interpolating from
+    /// `+170°` to `-170°` sweeps through `180°` rather than through zero.
+    ///
+    /// `t = 0.0` returns `self` and `t = 1.0` returns `other` (up to floating
+    /// point error).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    /// use std::f64::consts::PI;
+    ///
+    /// let start = Transform::identity();
+    /// let end = Transform::rotate(PI / 2.0);
+    /// let halfway = start.lerp(&end, 0.5);
+    ///
+    /// let v = halfway.apply(Vector2D::new(1.0, 0.0));
+    /// assert!((v.x - (PI / 4.0).cos()).abs() < 1e-10);
+    /// assert!((v.y - (PI / 4.0).sin()).abs() < 1e-10);
+    /// ```
+    pub fn lerp(&self, other: &Transform, t: f64) -> Transform {
+        let (angle0, sx0, sy0, shear0) = self.decompose();
+        let (angle1, sx1, sy1, shear1) = other.decompose();
+
+        // Shortest signed angular difference in (-PI, PI]
+        let mut delta = (angle1 - angle0) % std::f64::consts::TAU;
+        if delta > std::f64::consts::PI {
+            delta -= std::f64::consts::TAU;
+        } else if delta <= -std::f64::consts::PI {
+            delta += std::f64::consts::TAU;
+        }
+
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        let angle = angle0 + delta * t;
+        let sx = lerp(sx0, sx1);
+        let sy = lerp(sy0, sy1);
+        let shear = lerp(shear0, shear1);
+
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            a: cos * sx,
+            b: sin * sx,
+            c: cos * shear - sin * sy,
+            d: sin * shear + cos * sy,
+            tx: lerp(self.tx, other.tx),
+            ty: lerp(self.ty, other.ty),
+        }
+    }
+
+    /// Decomposes the linear part as `rotate(angle) * [[sx, shear], [0, sy]]`.
+    ///
+    /// Returns `(angle, sx, sy, shear)`. Reflections end up as a negative `sy`.
+    fn decompose(&self) -> (f64, f64, f64, f64) {
+        let sx = self.a.hypot(self.b);
+        if sx == 0.0 {
+            return (0.0, 0.0, self.d, self.c);
+        }
+        let angle = self.b.atan2(self.a);
+        let shear = (self.a * self.c + self.b * self.d) / sx;
+        let sy = (self.a * self.d - self.b * self.c) / sx;
+        (angle, sx, sy, shear)
+    }
 }
 
 // Matrix multiplication for combining transformations
@@ -205,4 +598,237 @@ mod tests {
         assert!((result.x - 5.0).abs() < 1e-10);
         assert!((result.y - 1.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_scale_uniform() {
+        assert_eq!(Transform::scale_uniform(2.5), Transform::scale(2.5, 2.5));
+    }
+
+    #[test]
+    fn test_scale_about_keeps_center_fixed() {
+        let center = Vector2D::new(3.0, -2.0);
+        let t = Transform::scale_about(2.0, 2.0, center);
+
+        assert_eq!(t.apply(center), center);
+
+        let p = Vector2D::new(4.0, 0.0);
+        let scaled = t.apply(p);
+        assert!(((scaled - center).magnitude() - 2.0 * (p - center).magnitude()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_scale_about_matches_composition() {
+        let center = Vector2D::new(1.5, 0.5);
+        let expected = Transform::translate(center.x, center.y)
+            * Transform::scale(2.0, 3.0)
+            * Transform::translate(-center.x, -center.y);
+        let t = Transform::scale_about(2.0, 3.0, center);
+
+        let v = Vector2D::new(-2.0, 7.0);
+        assert!((t.apply(v) - expected.apply(v)).magnitude() < 1e-10);
+    }
+
+    #[test]
+    fn test_lerp_rotation_halfway() {
+        let start = Transform::identity();
+        let end = Transform::rotate(std::f64::consts::PI / 2.0);
+        let mid = start.lerp(&end, 0.5);
+        let expected = Transform::rotate(std::f64::consts::PI / 4.0);
+
+        assert!((mid.a - expected.a).abs() < 1e-10);
+        assert!((mid.b - expected.b).abs() < 1e-10);
+        assert!((mid.c - expected.c).abs() < 1e-10);
+        assert!((mid.d - expected.d).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_lerp_translation_linear() {
+        let start = Transform::translate(0.0, 2.0);
+        let end = Transform::translate(10.0, -2.0);
+
+        for &t in &[0.0, 0.25, 0.5, 1.0] {
+            let result = start.lerp(&end, t);
+            assert!((result.tx - 10.0 * t).abs() < 1e-10);
+            assert!((result.ty - (2.0 - 4.0 * t)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let start = Transform::translate(1.0, 2.0) * Transform::rotate(0.3);
+        let end =
+            Transform::translate(-3.0, 4.0) * Transform::rotate(2.0) * Transform::scale(2.0, 0.5);
+
+        let at_start = start.lerp(&end, 0.0);
+        let at_end = start.lerp(&end, 1.0);
+
+        for (x, y) in [
+            (at_start.a, start.a),
+            (at_start.b, start.b),
+            (at_start.c, start.c),
+            (at_start.d, start.d),
+            (at_end.a, end.a),
+            (at_end.b, end.b),
+            (at_end.c, end.c),
+            (at_end.d, end.d),
+            (at_end.tx, end.tx),
+            (at_end.ty, end.ty),
+        ] {
+            assert!((x - y).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_lerp_shortest_rotation() {
+        let deg = std::f64::consts::PI / 180.0;
+        let start = Transform::rotate(170.0 * deg);
+        let end = Transform::rotate(-170.0 * deg);
+        let mid = start.lerp(&end, 0.5);
+
+        // Halfway along the short path is 180 degrees, not 0
+        let v = mid.apply(Vector2D::new(1.0, 0.0));
+        assert!((v.x + 1.0).abs() < 1e-10);
+        assert!(v.y.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_reflect_diagonal() {
+        let t = Transform::reflect(Vector2D::new(1.0, 1.0));
+        let p = t.apply(Vector2D::new(2.0, 0.0));
+        assert!((p.x - 0.0).abs() < 1e-10);
+        assert!((p.y - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_reflect_zero_axis_is_identity() {
+        assert_eq!(Transform::reflect(Vector2D::ZERO), Transform::identity());
+    }
+
+    #[test]
+    fn test_reflect_about_point() {
+        let t = Transform::reflect_about(Vector2D::new(1.0, 0.0), Vector2D::new(0.0, 2.0));
+        assert_eq!(t.apply(Vector2D::new(1.0, 3.0)), Vector2D::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_two_point_map_sends_sources_to_targets() {
+        let cases = [
+            (
+                Vector2D::new(1.0, 2.0),
+                Vector2D::new(3.0, -1.0),
+                Vector2D::new(-4.0, 0.5),
+                Vector2D::new(2.0, 7.0),
+            ),
+            (
+                Vector2D::new(0.0, 0.0),
+                Vector2D::new(0.0, 1.0),
+                Vector2D::new(5.0, 5.0),
+                Vector2D::new(5.0, 5.0),
+            ),
+        ];
+        for (src_a, src_b, dst_a, dst_b) in cases {
+            let t = Transform::from_two_point_map(src_a, src_b, dst_a, dst_b);
+            assert!((t.apply(src_a) - dst_a).magnitude() < 1e-10);
+            assert!((t.apply(src_b) - dst_b).magnitude() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_two_point_map_is_similarity() {
+        let t = Transform::from_two_point_map(
+            Vector2D::ZERO,
+            Vector2D::new(1.0, 0.0),
+            Vector2D::ZERO,
+            Vector2D::new(0.0, 2.0),
+        );
+        // A quarter turn with scale 2 keeps right angles
+        let image = t.apply(Vector2D::new(0.0, 1.0));
+        assert!((image - Vector2D::new(-2.0, 0.0)).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_two_point_map_degenerate_source() {
+        let p = Vector2D::new(1.0, 1.0);
+        let q = Vector2D::new(3.0, 0.0);
+        assert!(Transform::try_from_two_point_map(p, p, q, Vector2D::ZERO).is_none());
+        assert_eq!(
+            Transform::from_two_point_map(p, p, q, Vector2D::ZERO).apply(p),
+            q
+        );
+    }
+
+    #[test]
+    fn test_is_identity() {
+        assert!(Transform::identity().is_identity(0.0));
+        assert!(Transform::translate(0.0, 0.0).is_identity(1e-12));
+        assert!(Transform::scale(1.0, 1.0).is_identity(0.0));
+        assert!(!Transform::translate(1e-6, 0.0).is_identity(1e-9));
+        assert!(Transform::translate(1e-12, 0.0).is_identity(1e-9));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let t =
+            Transform::rotate(1.2) * Transform::translate(3.0, -1.0) * Transform::scale(-2.0, 0.5);
+        let inverse = t.inverse().unwrap();
+        assert!((t * inverse).is_identity(1e-12));
+        assert!((inverse * t).is_identity(1e-12));
+
+        let p = Vector2D::new(0.3, 7.0);
+        let back = inverse.apply(t.apply(p));
+        assert!((back - p).magnitude() < 1e-12);
+
+        assert!(Transform::scale(0.0, 0.0).inverse().is_none());
+        assert!(Transform::scale(f64::INFINITY, 1.0).inverse().is_none());
+    }
+
+    #[test]
+    fn test_approx_eq_round_trips() {
+        let angle = 0.7;
+        let round_trip = Transform::rotate(angle) * Transform::rotate(-angle);
+        assert!(round_trip.is_identity(1e-12));
+
+        let there = Transform::translate(3.0, -2.0) * Transform::scale(2.0, 4.0);
+        let back = Transform::scale(0.5, 0.25) * Transform::translate(-3.0, 2.0);
+        assert!((back * there).approx_eq(&Transform::identity(), 1e-12));
+        assert!(!there.approx_eq(&Transform::identity(), 1e-12));
+    }
+
+    #[test]
+    fn test_to_homogeneous_translation_column() {
+        let m = Transform::translate(4.0, -2.0).to_homogeneous();
+        assert_eq!(m[0][2], 4.0);
+        assert_eq!(m[1][2], -2.0);
+        assert_eq!(m[2], [0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_homogeneous_round_trip() {
+        let t =
+            Transform::translate(1.0, 2.0) * Transform::rotate(0.3) * Transform::scale(2.0, 0.5);
+        let m = t.to_homogeneous();
+        assert_eq!(Transform::from_homogeneous(m), Some(t));
+
+        // The homogeneous product matches Transform multiplication
+        let u = Transform::rotate(-1.2);
+        let (mt, mu) = (t.to_homogeneous(), u.to_homogeneous());
+        let mut product = [[0.0; 3]; 3];
+        for (i, row) in product.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = (0..3).map(|k| mt[i][k] * mu[k][j]).sum();
+            }
+        }
+        let composed = Transform::from_homogeneous(product).unwrap();
+        assert!(composed.approx_eq(&(t * u), 1e-12));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let t =
+            Transform::translate(1.0, 2.0) * Transform::rotate(0.3) * Transform::scale(2.0, 0.5);
+        let json = serde_json::to_string(&t).unwrap();
+        let back: Transform = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, t);
+    }
 }
@@ -1,3 +1,5 @@
+use super::Radians;
+
 /// A 2D vector in Euclidean space.
 ///
 /// This type is optimized for performance with inline operations
@@ -154,6 +156,61 @@ impl Vector2D {
         Self::new(self.x.max(other.x), self.y.max(other.y))
     }
 
+    /// Rounds each component to the nearest multiple of `spacing`.
+    ///
+    /// Returns the vector unchanged if `spacing` is not a positive finite
+    /// number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    ///
+    /// let snapped = Vector2D::new(1.3, 2.7).snap_to_grid(0.5);
+    /// assert_eq!(snapped, Vector2D::new(1.5, 2.5));
+    /// ```
+    #[inline]
+    pub fn snap_to_grid(self, spacing: f64) -> Self {
+        if !(spacing.is_finite() && spacing > 0.0) {
+            return self;
+        }
+        Self::new(
+            (self.x / spacing).round() * spacing,
+            (self.y / spacing).round() * spacing,
+        )
+    }
+
+    /// Returns `true` if both components are finite (neither infinite nor NaN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    ///
+    /// assert!(Vector2D::new(1.0, 2.0).is_finite());
+    /// assert!(!Vector2D::new(f64::INFINITY, 0.0).is_finite());
+    /// assert!(!Vector2D::new(0.0, f64::NAN).is_finite());
+    /// ```
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns `true` if either component is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    ///
+    /// assert!(Vector2D::new(f64::NAN, 1.0).is_nan());
+    /// assert!(!Vector2D::new(f64::INFINITY, 1.0).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
     /// Returns a normalized (unit length) version of the vector.
     ///
     /// Returns `None` if the vector has zero length.
@@ -236,6 +293,94 @@ impl Vector2D {
             self.y + (other.y - self.y) * t,
         )
     }
+
+    /// Rotates the vector counter-clockwise about the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Radians, Vector2D};
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let v = Vector2D::RIGHT.rotate(Radians(FRAC_PI_2));
+    /// assert!((v - Vector2D::UP).magnitude() < 1e-10);
+    /// ```
+    #[inline]
+    pub fn rotate(self, angle: Radians) -> Self {
+        let (sin, cos) = angle.0.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// Rotates the vector counter-clockwise about `pivot`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Radians, Vector2D};
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let v = Vector2D::new(2.0, 1.0).rotate_about(Vector2D::new(1.0, 1.0), Radians(FRAC_PI_2));
+    /// assert!((v - Vector2D::new(1.0, 2.0)).magnitude() < 1e-10);
+    /// ```
+    #[inline]
+    pub fn rotate_about(self, pivot: Self, angle: Radians) -> Self {
+        (self - pivot).rotate(angle) + pivot
+    }
+
+    /// Returns the signed angle from this vector to `other`.
+    ///
+    /// The result lies in (-π, π] and is positive when `other` is
+    /// counter-clockwise from `self`. Returns zero if either vector is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// assert_eq!(Vector2D::RIGHT.angle_to(Vector2D::UP).0, FRAC_PI_2);
+    /// assert_eq!(Vector2D::UP.angle_to(Vector2D::RIGHT).0, -FRAC_PI_2);
+    /// ```
+    #[inline]
+    pub fn angle_to(self, other: Self) -> Radians {
+        Radians(self.cross(other).atan2(self.dot(other)))
+    }
+
+    /// Creates a vector from polar coordinates: a length `r` and an angle
+    /// `theta` measured counter-clockwise from the positive x-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Radians, Vector2D};
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let v = Vector2D::from_polar(2.0, Radians(FRAC_PI_2));
+    /// assert!((v - Vector2D::new(0.0, 2.0)).magnitude() < 1e-10);
+    /// ```
+    #[inline]
+    pub fn from_polar(r: f64, theta: Radians) -> Self {
+        let (sin, cos) = theta.0.sin_cos();
+        Self::new(r * cos, r * sin)
+    }
+
+    /// Returns the polar coordinates `(r, theta)` of the vector.
+    ///
+    /// The angle lies in (-π, π]; the zero vector gives `(0.0, 0.0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    ///
+    /// let (r, theta) = Vector2D::new(0.0, -3.0).to_polar();
+    /// assert_eq!(r, 3.0);
+    /// assert_eq!(theta.0, -std::f64::consts::FRAC_PI_2);
+    /// ```
+    #[inline]
+    pub fn to_polar(self) -> (f64, Radians) {
+        (self.magnitude(), Radians(self.y.atan2(self.x)))
+    }
 }
 
 // Operator overloads
@@ -293,6 +438,8 @@ impl std::fmt::Display for Vector2D {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_relative_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
 
     #[test]
     fn test_vector_creation() {
@@ -366,4 +513,66 @@ mod tests {
         let mid = v1.lerp(v2, 0.5);
         assert_eq!(mid, Vector2D::new(5.0, 5.0));
     }
+
+    #[test]
+    fn test_rotate_about() {
+        let v = Vector2D::new(2.0, 1.0).rotate_about(Vector2D::new(1.0, 1.0), Radians(FRAC_PI_2));
+        assert_relative_eq!(v.x, 1.0, epsilon = 1e-10);
+        assert_relative_eq!(v.y, 2.0, epsilon = 1e-10);
+
+        let pivot = Vector2D::new(3.0, -2.0);
+        assert_eq!(pivot.rotate_about(pivot, Radians(1.0)), pivot);
+    }
+
+    #[test]
+    fn test_angle_to() {
+        assert_relative_eq!(Vector2D::RIGHT.angle_to(Vector2D::UP).0, FRAC_PI_2);
+        assert_relative_eq!(Vector2D::RIGHT.angle_to(Vector2D::DOWN).0, -FRAC_PI_2);
+        assert_relative_eq!(Vector2D::RIGHT.angle_to(Vector2D::LEFT).0, PI);
+        assert_eq!(Vector2D::ZERO.angle_to(Vector2D::UP).0, 0.0);
+    }
+
+    #[test]
+    fn test_is_finite_and_is_nan() {
+        assert!(Vector2D::new(-3.0, 4.0).is_finite());
+        assert!(!Vector2D::new(f64::NEG_INFINITY, 4.0).is_finite());
+        assert!(!Vector2D::new(f64::NAN, 4.0).is_finite());
+
+        assert!(Vector2D::new(1.0, f64::NAN).is_nan());
+        assert!(!Vector2D::new(1.0, f64::INFINITY).is_nan());
+        assert!(!Vector2D::ZERO.is_nan());
+    }
+
+    #[test]
+    fn test_polar_conversions() {
+        let v = Vector2D::from_polar(2.0, Radians(FRAC_PI_2));
+        assert_relative_eq!(v.x, 0.0, epsilon = 1e-10);
+        assert_relative_eq!(v.y, 2.0);
+
+        let (r, theta) = Vector2D::new(3.0, 4.0).to_polar();
+        assert_relative_eq!(r, 5.0);
+        assert_relative_eq!(theta.0, 4.0_f64.atan2(3.0));
+
+        // Round trip
+        let back = Vector2D::from_polar(r, theta);
+        assert_relative_eq!(back.x, 3.0, epsilon = 1e-10);
+        assert_relative_eq!(back.y, 4.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        assert_eq!(
+            Vector2D::new(1.3, 2.7).snap_to_grid(0.5),
+            Vector2D::new(1.5, 2.5)
+        );
+        assert_eq!(
+            Vector2D::new(-1.3, 0.2).snap_to_grid(1.0),
+            Vector2D::new(-1.0, 0.0)
+        );
+
+        let v = Vector2D::new(1.3, 2.7);
+        assert_eq!(v.snap_to_grid(0.0), v);
+        assert_eq!(v.snap_to_grid(-1.0), v);
+        assert_eq!(v.snap_to_grid(f64::NAN), v);
+    }
 }
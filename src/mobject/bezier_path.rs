@@ -4,7 +4,7 @@
 
 use crate::core::{BoundingBox, Color, CubicBezier, Result, Transform, Vector2D};
 use crate::mobject::{Mobject, VMobject};
-use crate::renderer::{Path, Renderer};
+use crate::renderer::{Path, PathStyle, Renderer};
 
 /// A mobject for arbitrary Bézier curve paths.
 ///
@@ -103,10 +103,25 @@ impl BezierPath {
         self
     }
 
+    /// Sets the stroke color, and the fill color if one is set.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.vmobject.set_color(color);
+        self
+    }
+
     /// Returns a mutable reference to the underlying VMobject.
     pub fn vmobject_mut(&mut self) -> &mut VMobject {
         &mut self.vmobject
     }
+
+    /// Returns a transformed copy, leaving `self` unchanged.
+    ///
+    /// Styling is preserved on the copy.
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        let mut copy = self.clone();
+        copy.apply_transform(transform);
+        copy
+    }
 }
 
 impl Mobject for BezierPath {
@@ -118,6 +133,14 @@ impl Mobject for BezierPath {
         self.vmobject.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.vmobject.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.vmobject.apply_default_style(style);
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.vmobject.apply_transform(transform);
     }
@@ -138,6 +161,14 @@ impl Mobject for BezierPath {
         self.vmobject.set_opacity(opacity);
     }
 
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.vmobject)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.vmobject)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
@@ -185,4 +216,23 @@ mod tests {
         bezier.set_stroke(Color::RED, 3.0);
         assert_eq!(bezier.vmobject.stroke_color(), Some(Color::RED));
     }
+
+    #[test]
+    fn test_bezier_path_transformed() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .line_to(Vector2D::new(1.0, 1.0));
+        let mut bezier = BezierPath::from_path(path.clone());
+        bezier.set_stroke(Color::RED, 3.0);
+
+        let moved = bezier.transformed(&Transform::translate(2.0, 0.0));
+
+        assert_eq!(bezier.vmobject.path(), &path);
+        assert_eq!(
+            moved.vmobject.path(),
+            &path.transformed(&Transform::translate(2.0, 0.0))
+        );
+        assert_eq!(moved.vmobject.stroke_color(), Some(Color::RED));
+        assert_eq!(moved.position(), Vector2D::new(2.0, 0.0));
+    }
 }
@@ -4,9 +4,9 @@
 
 use std::f64::consts::PI;
 
-use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
+use crate::core::{BoundingBox, Color, Radians, Result, Transform, Vector2D};
 use crate::mobject::{Mobject, VMobject};
-use crate::renderer::{Path, Renderer};
+use crate::renderer::{Path, PathCursor, PathStyle, Renderer};
 
 /// Magic number for approximating a circle/arc with cubic Bézier curves.
 /// (Currently unused - arc uses a different approximation method)
@@ -33,7 +33,7 @@ const BEZIER_MAGIC: f64 = 0.551_915_024_493_510_6;
 ///     .end_angle(PI)
 ///     .build();
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Arc {
     vmobject: VMobject,
     radius: f64,
@@ -64,17 +64,97 @@ impl Arc {
         }
     }
 
+    /// Creates the arc of the circle through three points.
+    ///
+    /// The arc starts at `a`, passes through `b` and ends at `c`, centered on
+    /// the circumcenter of the three points. Arcs are stored counterclockwise,
+    /// so when `a → b → c` turns clockwise the start and end angles are
+    /// swapped; the traced curve is the same.
+    ///
+    /// Collinear points have no finite circumcircle, so they give the limit
+    /// of such arcs instead: a straight segment from `a` to `c` with an
+    /// infinite radius and zero sweep, both angles pointing away from `a` to
+    /// the right of the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Arc;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let arc = Arc::from_three_points(
+    ///     Vector2D::new(3.0, 0.0),
+    ///     Vector2D::new(1.0, 2.0),
+    ///     Vector2D::new(-1.0, 0.0),
+    /// );
+    ///
+    /// assert!((arc.radius() - 2.0).abs() < 1e-10);
+    /// assert!((arc.position() - Vector2D::new(1.0, 0.0)).magnitude() < 1e-10);
+    ///
+    /// let on_a_line = Arc::from_three_points(
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(1.0, 1.0),
+    ///     Vector2D::new(2.0, 2.0),
+    /// );
+    /// assert_eq!(on_a_line.radius(), f64::INFINITY);
+    /// assert_eq!(on_a_line.angle(), 0.0);
+    /// ```
+    pub fn from_three_points(a: Vector2D, b: Vector2D, c: Vector2D) -> Self {
+        let ab = b - a;
+        let ac = c - a;
+        let det = 2.0 * ab.cross(ac);
+
+        if det.abs() < 1e-12 {
+            let normal_angle = ac.y.atan2(ac.x) - PI / 2.0;
+            let mut path = Path::new();
+            path.move_to(a).line_to(c);
+            return Self {
+                vmobject: VMobject::new(path),
+                radius: f64::INFINITY,
+                start_angle: normal_angle,
+                end_angle: normal_angle,
+            };
+        }
+
+        // Circumcenter relative to `a`
+        let ab_sq = ab.dot(ab);
+        let ac_sq = ac.dot(ac);
+        let offset = Vector2D::new(
+            (ac.y * ab_sq - ab.y * ac_sq) / det,
+            (ab.x * ac_sq - ac.x * ab_sq) / det,
+        );
+        let center = a + offset;
+        let radius = offset.magnitude();
+
+        let angle_of = |p: Vector2D| (p.y - center.y).atan2(p.x - center.x);
+        let (from, to) = if det > 0.0 { (a, c) } else { (c, a) };
+        let start = angle_of(from);
+        let span = (angle_of(to) - start).rem_euclid(2.0 * PI);
+
+        let mut arc = Self::new(radius, start, start + span);
+        arc.set_position(center);
+        arc
+    }
+
     /// Returns a builder for constructing an arc.
     pub fn builder() -> ArcBuilder {
         ArcBuilder::new()
     }
 
     /// Returns the radius of the arc.
+    ///
+    /// Transforms scale it by the square root of their area scaling, which
+    /// is exact for uniform scaling. An arc stretched unevenly is elliptical,
+    /// and this is the radius of the circle with the same area.
     pub fn radius(&self) -> f64 {
         self.radius
     }
 
     /// Returns the start angle of the arc in radians.
+    ///
+    /// Both angles follow transforms, so they keep describing the drawn
+    /// curve. A mirror reverses the arc, so its start and end swap.
     pub fn start_angle(&self) -> f64 {
         self.start_angle
     }
@@ -84,23 +164,93 @@ impl Arc {
         self.end_angle
     }
 
+    /// Returns `true` if the direction `angle`, in radians, points into the
+    /// arc's sweep.
+    ///
+    /// The sweep runs counter-clockwise from the start angle to the end
+    /// angle, as the arc is drawn, and may cross zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use manim_rs::mobject::geometry::Arc;
+    ///
+    /// let arc = Arc::new(1.0, 3.0 * PI / 2.0, PI / 2.0); // Right half
+    /// assert!(arc.contains_angle(0.0));
+    /// assert!(!arc.contains_angle(PI));
+    /// ```
+    pub fn contains_angle(&self, angle: f64) -> bool {
+        Radians(angle).is_between(Radians(self.start_angle), Radians(self.end_angle))
+    }
+
     /// Returns the angular extent of the arc in radians.
     pub fn angle(&self) -> f64 {
         self.end_angle - self.start_angle
     }
 
+    /// Sets the radius, keeping the center and angles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use manim_rs::mobject::geometry::Arc;
+    ///
+    /// let mut arc = Arc::new(1.0, 0.0, PI / 2.0);
+    /// arc.set_radius(3.0);
+    /// assert_eq!(arc.radius(), 3.0);
+    /// ```
+    pub fn set_radius(&mut self, radius: f64) {
+        self.radius = radius;
+        self.rebuild_path();
+    }
+
+    /// Sets the start and end angles in radians, keeping the center and radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use manim_rs::mobject::geometry::Arc;
+    ///
+    /// let mut arc = Arc::new(1.0, 0.0, PI / 2.0);
+    /// arc.set_angles(0.0, PI);
+    /// assert_eq!(arc.angle(), PI);
+    /// ```
+    pub fn set_angles(&mut self, start_angle: f64, end_angle: f64) {
+        self.start_angle = start_angle;
+        self.end_angle = end_angle;
+        self.rebuild_path();
+    }
+
+    /// Regenerates the path from the current radius and angles, keeping the
+    /// arc centered at its current position.
+    fn rebuild_path(&mut self) {
+        let mut path = Self::create_arc_path(self.radius, self.start_angle, self.end_angle);
+        let position = self.vmobject.position();
+        path.apply_transform(&Transform::translate(position.x, position.y));
+        *self.vmobject.path_mut() = path;
+    }
+
     /// Sets the stroke color and width.
     pub fn set_stroke(&mut self, color: Color, width: f64) -> &mut Self {
         self.vmobject.set_stroke(color, width);
         self
     }
 
+    /// Sets the stroke color, and the fill color if one is set.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.vmobject.set_color(color);
+        self
+    }
+
     /// Creates an arc path using cubic Bézier curves.
     ///
-    /// The arc is approximated by dividing it into segments, each handled by a cubic Bézier.
-    /// For angles up to π/2, one Bézier curve is sufficient. For larger angles, multiple curves are used.
+    /// The arc always runs counter-clockwise from `start_angle`, with the
+    /// sweep normalized to [0, 2π). [`PathCursor::arc`] splits it into
+    /// segments of at most a quarter turn.
     fn create_arc_path(radius: f64, start_angle: f64, end_angle: f64) -> Path {
-        let mut path = Path::new();
         let mut angle = end_angle - start_angle;
 
         // Normalize angle to [0, 2π)
@@ -111,57 +261,14 @@ impl Arc {
             angle -= 2.0 * PI;
         }
 
-        // Starting point
-        let start_x = radius * start_angle.cos();
-        let start_y = radius * start_angle.sin();
-        path.move_to(Vector2D::new(start_x, start_y));
-
-        // Divide arc into segments of at most π/2 each
-        let num_segments = ((angle / (PI / 2.0)).ceil() as usize).max(1);
-        let segment_angle = angle / num_segments as f64;
-
-        for i in 0..num_segments {
-            let seg_start = start_angle + i as f64 * segment_angle;
-            let seg_end = seg_start + segment_angle;
-
-            // Calculate control points for this segment
-            let (cp1, cp2, end) =
-                Self::bezier_arc_segment(radius, seg_start, seg_end, segment_angle);
-
-            path.cubic_to(cp1, cp2, end);
-        }
-
-        path
-    }
-
-    /// Calculates control points for a single arc segment using Bézier approximation.
-    fn bezier_arc_segment(
-        radius: f64,
-        start: f64,
-        end: f64,
-        angle: f64,
-    ) -> (Vector2D, Vector2D, Vector2D) {
-        // Calculate the control point offset
-        let alpha = angle.sin() * (((1.0 + angle.cos()).sqrt() - 1.0) / 3.0).sqrt();
-
-        let cos_start = start.cos();
-        let sin_start = start.sin();
-        let cos_end = end.cos();
-        let sin_end = end.sin();
-
-        let cp1 = Vector2D::new(
-            radius * (cos_start - sin_start * alpha),
-            radius * (sin_start + cos_start * alpha),
-        );
-
-        let cp2 = Vector2D::new(
-            radius * (cos_end + sin_end * alpha),
-            radius * (sin_end - cos_end * alpha),
+        let mut cursor = PathCursor::new();
+        cursor.arc(
+            Vector2D::ZERO,
+            radius,
+            Radians(start_angle),
+            Radians(start_angle + angle),
         );
-
-        let end_point = Vector2D::new(radius * cos_end, radius * sin_end);
-
-        (cp1, cp2, end_point)
+        cursor.into_path()
     }
 }
 
@@ -174,8 +281,58 @@ impl Mobject for Arc {
         self.vmobject.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.vmobject.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.vmobject.apply_default_style(style);
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.vmobject.apply_transform(transform);
+        let (a, b, c, d) = (transform.a, transform.b, transform.c, transform.d);
+        if (a, b, c, d) == (1.0, 0.0, 0.0, 1.0) {
+            // Translations move the center, which the vmobject tracks
+            return;
+        }
+
+        if self.radius.is_infinite() {
+            // The degenerate arc is a segment: recompute its normal
+            let points = self.vmobject.path().points();
+            if let (Some(first), Some(last)) = (points.first(), points.last()) {
+                let along = *last - *first;
+                let normal = along.y.atan2(along.x) - PI / 2.0;
+                self.start_angle = normal;
+                self.end_angle = normal;
+            }
+            return;
+        }
+
+        // Follow each angle through the linear part, staying within half a
+        // turn of the old value so small transforms give small changes
+        let turn = |angle: f64| {
+            let (sin, cos) = angle.sin_cos();
+            let direction = Vector2D::new(a * cos + c * sin, b * cos + d * sin);
+            angle + (direction.y.atan2(direction.x) - angle + PI).rem_euclid(2.0 * PI) - PI
+        };
+        let sweep = self.end_angle - self.start_angle;
+        // A mirror reverses the drawing direction, so the ends trade places
+        let (from, to) = if transform.determinant() < 0.0 {
+            (self.end_angle, self.start_angle)
+        } else {
+            (self.start_angle, self.end_angle)
+        };
+        let start = turn(from);
+        let span = if sweep.rem_euclid(2.0 * PI) == 0.0 {
+            sweep.abs()
+        } else {
+            (turn(to) - start).rem_euclid(2.0 * PI)
+        };
+
+        self.radius *= transform.determinant().abs().sqrt();
+        self.start_angle = start;
+        self.end_angle = start + span;
     }
 
     fn position(&self) -> Vector2D {
@@ -194,6 +351,14 @@ impl Mobject for Arc {
         self.vmobject.set_opacity(opacity);
     }
 
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.vmobject)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.vmobject)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
@@ -336,9 +501,149 @@ mod tests {
         assert_relative_eq!(arc.angle(), PI / 2.0);
     }
 
+    #[test]
+    fn test_arc_segments_follow_circle() {
+        let arc = Arc::new(2.0, 0.0, 3.0 * PI / 2.0);
+        let mut from = Vector2D::new(2.0, 0.0);
+        let mut segments = 0;
+        for command in arc.vmobject.path().commands() {
+            if let crate::renderer::PathCommand::CubicTo {
+                control1,
+                control2,
+                to,
+            } = *command
+            {
+                let curve = crate::core::CubicBezier::new(from, control1, control2, to);
+                assert_relative_eq!(curve.evaluate(0.5).magnitude(), 2.0, epsilon = 1e-3);
+                from = to;
+                segments += 1;
+            }
+        }
+        assert_eq!(segments, 3);
+        assert_relative_eq!(from.y, -2.0, epsilon = 1e-10);
+    }
+
     #[test]
     fn test_arc_three_quarters() {
         let arc = Arc::new(1.0, 0.0, 3.0 * PI / 2.0);
         assert_relative_eq!(arc.angle(), 3.0 * PI / 2.0);
     }
+
+    #[test]
+    fn test_arc_from_three_points_unit_circle() {
+        let point = |angle: f64| Vector2D::new(angle.cos(), angle.sin());
+        let arc = Arc::from_three_points(point(0.2), point(1.0), point(2.5));
+
+        assert_relative_eq!(arc.radius(), 1.0, epsilon = 1e-10);
+        assert_relative_eq!(arc.position().magnitude(), 0.0, epsilon = 1e-10);
+        assert_relative_eq!(arc.start_angle(), 0.2, epsilon = 1e-10);
+        assert_relative_eq!(arc.angle(), 2.3, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_arc_from_three_points_clockwise() {
+        let point = |angle: f64| Vector2D::new(angle.cos(), angle.sin());
+        // Clockwise through the top: stored counterclockwise from c to a
+        let arc = Arc::from_three_points(point(2.5), point(1.0), point(0.2));
+
+        assert_relative_eq!(arc.start_angle(), 0.2, epsilon = 1e-10);
+        assert_relative_eq!(arc.angle(), 2.3, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_arc_from_three_points_collinear() {
+        let arc = Arc::from_three_points(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(2.0, 2.0),
+        );
+        assert_eq!(arc.radius(), f64::INFINITY);
+        assert_eq!(arc.angle(), 0.0);
+        assert_relative_eq!(arc.start_angle(), -PI / 4.0);
+        assert_eq!(
+            arc.get_points(),
+            vec![Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 2.0)]
+        );
+
+        // Nearly collinear points still give a finite circle
+        let arc = Arc::from_three_points(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 1e-3),
+            Vector2D::new(2.0, 0.0),
+        );
+        assert!(arc.radius().is_finite());
+    }
+
+    #[test]
+    fn test_arc_set_angles_and_radius() {
+        let mut arc = Arc::new(1.0, 0.0, PI / 2.0);
+        arc.set_position(Vector2D::new(5.0, 0.0));
+
+        arc.set_angles(0.0, PI);
+        assert_relative_eq!(arc.angle(), PI);
+
+        arc.set_radius(2.0);
+        assert_eq!(arc.radius(), 2.0);
+        let bbox = arc.vmobject.path().bounding_box();
+        assert_relative_eq!(bbox.min().x, 3.0, epsilon = 1e-10);
+        assert_relative_eq!(bbox.max().x, 7.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_contains_angle_wraps_through_zero() {
+        let arc = Arc::new(1.0, 3.0 * PI / 2.0, PI / 2.0);
+        assert!(arc.contains_angle(0.0));
+        assert!(arc.contains_angle(-PI / 4.0));
+        assert!(arc.contains_angle(PI / 2.0));
+        assert!(!arc.contains_angle(PI));
+
+        let upper = Arc::new(1.0, 0.0, PI);
+        assert!(upper.contains_angle(PI / 2.0));
+        assert!(!upper.contains_angle(3.0 * PI / 2.0));
+    }
+
+    #[test]
+    fn test_arc_angles_follow_transforms() {
+        // Quarter arc in the first quadrant, centered at the origin
+        let mut arc = Arc::new(1.0, 0.0, PI / 2.0);
+        arc.apply_transform(&Transform::rotate(PI / 2.0));
+        assert_relative_eq!(arc.start_angle(), PI / 2.0, epsilon = 1e-12);
+        assert_relative_eq!(arc.end_angle(), PI, epsilon = 1e-12);
+        assert!(arc.contains_angle(3.0 * PI / 4.0));
+        assert!(!arc.contains_angle(PI / 4.0));
+
+        arc.apply_transform(&Transform::scale(2.0, 2.0));
+        assert_relative_eq!(arc.radius(), 2.0, epsilon = 1e-12);
+
+        // Translations leave the angles exactly as they were
+        arc.apply_transform(&Transform::translate(3.0, 1.0));
+        assert_eq!(arc.position(), Vector2D::new(3.0, 1.0));
+        assert_eq!(arc.end_angle(), PI);
+    }
+
+    #[test]
+    fn test_arc_mirror_swaps_ends() {
+        let mut arc = Arc::new(1.0, 0.0, PI / 2.0);
+        arc.apply_transform(&Transform::scale(-1.0, 1.0));
+
+        // The mirrored arc covers the second quadrant, drawn from its top
+        assert_relative_eq!(arc.start_angle(), PI / 2.0, epsilon = 1e-12);
+        assert_relative_eq!(arc.end_angle(), PI, epsilon = 1e-12);
+        assert!(arc.contains_angle(3.0 * PI / 4.0));
+        assert!(!arc.contains_angle(PI / 4.0));
+        assert_relative_eq!(arc.radius(), 1.0);
+    }
+
+    #[test]
+    fn test_degenerate_arc_follows_transforms() {
+        let mut arc = Arc::from_three_points(
+            Vector2D::ZERO,
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(2.0, 0.0),
+        );
+        arc.apply_transform(&Transform::rotate(PI / 2.0));
+        assert_eq!(arc.radius(), f64::INFINITY);
+        assert_relative_eq!(arc.start_angle(), 0.0, epsilon = 1e-12);
+        assert_eq!(arc.angle(), 0.0);
+    }
 }
@@ -4,9 +4,9 @@
 
 use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
 use crate::mobject::{Mobject, MobjectGroup};
-use crate::renderer::Renderer;
+use crate::renderer::{PathStyle, Renderer};
 
-use super::{Line, Polygon};
+use super::{LabelPos, Line, Polygon};
 
 /// An arrow mobject.
 ///
@@ -88,6 +88,27 @@ impl Arrow {
         self.end
     }
 
+    /// Returns the anchor point for a label at `pos` along the arrow.
+    ///
+    /// [`LabelPos::End`] is the tip of the arrowhead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::{Arrow, LabelPos};
+    ///
+    /// let arrow = Arrow::new(Vector2D::ZERO, Vector2D::new(0.0, 2.0));
+    /// // The left-hand side of an upward arrow
+    /// assert_eq!(
+    ///     arrow.label_position(LabelPos::AboveMiddle(0.3)),
+    ///     Vector2D::new(-0.3, 1.0)
+    /// );
+    /// ```
+    pub fn label_position(&self, pos: LabelPos) -> Vector2D {
+        pos.resolve(self.start, self.end)
+    }
+
     /// Returns the length of the arrow tip.
     pub fn tip_length(&self) -> f64 {
         self.tip_length
@@ -134,8 +155,9 @@ impl Arrow {
             tip_base_center - perpendicular * (tip_width / 2.0),
         ];
 
+        // Filled without counting as styled, so a theme can recolor it
         let mut tip = Polygon::new(tip_vertices);
-        tip.set_fill(Color::WHITE); // Default fill
+        tip.apply_default_style(&PathStyle::default().with_fill(Color::WHITE));
 
         group.add(Box::new(line));
         group.add(Box::new(tip));
@@ -151,6 +173,23 @@ impl Mobject for Arrow {
         self.group.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.group.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        // The line comes first and the tip, if any, second; the tip is
+        // filled with the line's stroke color
+        let mut tip_style = style.clone();
+        tip_style.fill_color = style.stroke_color;
+        if let Some((line, rest)) = self.group.mobjects_mut().split_first_mut() {
+            line.apply_default_style(style);
+            for tip in rest {
+                tip.apply_default_style(&tip_style);
+            }
+        }
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.group.apply_transform(transform);
         self.start = transform.apply(self.start);
@@ -162,7 +201,10 @@ impl Mobject for Arrow {
     }
 
     fn set_position(&mut self, pos: Vector2D) {
+        let delta = pos - self.group.position();
         self.group.set_position(pos);
+        self.start = self.start + delta;
+        self.end = self.end + delta;
     }
 
     fn opacity(&self) -> f64 {
@@ -173,6 +215,14 @@ impl Mobject for Arrow {
         self.group.set_opacity(opacity);
     }
 
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.group)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.group)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
@@ -333,4 +383,76 @@ mod tests {
         let arrow = Arrow::new(Vector2D::ZERO, Vector2D::new(0.1, 0.0));
         assert_eq!(arrow.start(), Vector2D::ZERO);
     }
+
+    #[test]
+    fn test_arrow_label_above_middle_is_perpendicular() {
+        let arrow = Arrow::new(Vector2D::new(1.0, 1.0), Vector2D::new(4.0, 5.0));
+        let middle = arrow.label_position(LabelPos::Middle);
+        let label = arrow.label_position(LabelPos::AboveMiddle(0.2));
+
+        let offset = label - middle;
+        assert!((offset.magnitude() - 0.2).abs() < 1e-10);
+        assert!(offset.dot(arrow.end() - arrow.start()).abs() < 1e-10);
+        // Left of the direction of travel
+        assert!((arrow.end() - arrow.start()).cross(offset) > 0.0);
+    }
+
+    #[test]
+    fn test_arrow_label_end_and_start() {
+        let mut arrow = Arrow::new(Vector2D::ZERO, Vector2D::new(2.0, 0.0));
+        arrow.apply_transform(&Transform::translate(1.0, 1.0));
+        assert_eq!(arrow.label_position(LabelPos::End), arrow.end());
+        assert_eq!(arrow.label_position(LabelPos::End), Vector2D::new(3.0, 1.0));
+        assert_eq!(
+            arrow.label_position(LabelPos::Start),
+            Vector2D::new(1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_arrow_label_follows_set_position() {
+        let mut arrow = Arrow::new(Vector2D::ZERO, Vector2D::new(2.0, 0.0));
+        let delta = Vector2D::new(3.0, -1.0);
+        arrow.set_position(arrow.position() + delta);
+
+        assert_eq!(arrow.start(), delta);
+        assert_eq!(arrow.end(), Vector2D::new(5.0, -1.0));
+        assert_eq!(
+            arrow.label_position(LabelPos::Middle),
+            Vector2D::new(4.0, -1.0)
+        );
+    }
+
+    fn drawn_styles(arrow: &Arrow) -> Vec<PathStyle> {
+        use crate::backends::{DrawCommand, RecordingRenderer};
+
+        let mut renderer = RecordingRenderer::new(100, 100);
+        arrow.render(&mut renderer).unwrap();
+        renderer
+            .commands()
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Path { style, .. } => Some(style.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_arrow_applies_default_style() {
+        let theme = PathStyle::stroke(Color::BLACK, 3.0);
+
+        let mut plain = Arrow::new(Vector2D::ZERO, Vector2D::new(2.0, 0.0));
+        plain.apply_default_style(&theme);
+        let styles = drawn_styles(&plain);
+        assert_eq!(styles.len(), 2);
+        assert_eq!(styles[0].stroke_color, Some(Color::BLACK));
+        assert_eq!(styles[1].fill_color, Some(Color::BLACK));
+
+        let mut styled = Arrow::builder().stroke_color(Color::RED).build();
+        styled.apply_default_style(&theme);
+        let styles = drawn_styles(&styled);
+        assert_eq!(styles[0].stroke_color, Some(Color::RED));
+        assert_eq!(styles[1].fill_color, Some(Color::RED));
+    }
 }
@@ -2,9 +2,9 @@
 //!
 //! Implements a circle using 4 cubic Bézier curves for accurate approximation.
 
-use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
+use crate::core::{BoundingBox, Color, Radians, Result, Transform, Vector2D};
 use crate::mobject::{Mobject, VMobject};
-use crate::renderer::{Path, Renderer};
+use crate::renderer::{Path, PathStyle, Renderer};
 
 /// Magic number for approximating a circle with cubic Bézier curves.
 ///
@@ -36,7 +36,7 @@ const BEZIER_CIRCLE_MAGIC: f64 = 0.551_915_024_493_510_6;
 ///     .fill_color(Color::from_hex("#87CEEB").unwrap())
 ///     .build();
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Circle {
     vmobject: VMobject,
     radius: f64,
@@ -84,6 +84,10 @@ impl Circle {
 
     /// Returns the radius of the circle.
     ///
+    /// Transforms scale the radius along with the geometry. A non-uniform
+    /// scale turns the circle into an ellipse; the radius is then that of
+    /// the circle with the same area.
+    ///
     /// # Examples
     ///
     /// ```
@@ -96,6 +100,115 @@ impl Circle {
         self.radius
     }
 
+    /// Returns the point on the circumference at `angle`.
+    ///
+    /// Angles are measured counter-clockwise from the positive x-axis around
+    /// the circle's current position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Radians, Vector2D};
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut circle = Circle::new(2.0);
+    /// circle.set_position(Vector2D::new(1.0, 1.0));
+    /// let top = circle.point_at_angle(Radians(std::f64::consts::FRAC_PI_2));
+    /// assert!((top - Vector2D::new(1.0, 3.0)).magnitude() < 1e-10);
+    /// ```
+    pub fn point_at_angle(&self, angle: Radians) -> Vector2D {
+        self.position() + Vector2D::new(self.radius, 0.0).rotate(angle)
+    }
+
+    /// Returns the unit tangent at `angle`, pointing counter-clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Radians, Vector2D};
+    /// use manim_rs::mobject::geometry::Circle;
+    ///
+    /// let circle = Circle::new(2.0);
+    /// let tangent = circle.tangent_at_angle(Radians(0.0));
+    /// assert!((tangent - Vector2D::UP).magnitude() < 1e-10);
+    /// ```
+    pub fn tangent_at_angle(&self, angle: Radians) -> Vector2D {
+        let (sin, cos) = angle.0.sin_cos();
+        Vector2D::new(-sin, cos)
+    }
+
+    /// Returns `true` if `point` lies inside the circle or on its boundary.
+    ///
+    /// The test is exact and measured from the circle's current position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut circle = Circle::new(1.0);
+    /// circle.set_position(Vector2D::new(5.0, 0.0));
+    /// assert!(circle.contains_point(Vector2D::new(5.5, 0.0)));
+    /// assert!(!circle.contains_point(Vector2D::ZERO));
+    /// ```
+    pub fn contains_point(&self, point: Vector2D) -> bool {
+        (point - self.position()).magnitude_squared() <= self.radius * self.radius
+    }
+
+    /// Returns the points where the segment from `a` to `b` crosses the
+    /// circle.
+    ///
+    /// The result holds 0, 1 (tangent), or 2 points, ordered from `a`
+    /// towards `b`. Only crossings within the segment are returned, so a
+    /// segment that ends inside the circle yields a single point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Circle;
+    ///
+    /// let circle = Circle::new(1.0);
+    /// let points = circle.intersect_line(Vector2D::new(-2.0, 0.0), Vector2D::new(2.0, 0.0));
+    /// assert_eq!(points, vec![Vector2D::new(-1.0, 0.0), Vector2D::new(1.0, 0.0)]);
+    /// ```
+    pub fn intersect_line(&self, a: Vector2D, b: Vector2D) -> Vec<Vector2D> {
+        self.crossings(a, b, 1.0)
+    }
+
+    /// Returns the points where the ray from `origin` through `through`
+    /// crosses the circle.
+    ///
+    /// Unlike [`Circle::intersect_line`], crossings beyond `through` are
+    /// kept; only those behind `origin` are dropped. The result is ordered
+    /// from `origin` outwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Circle;
+    ///
+    /// let circle = Circle::new(1.0);
+    /// let points = circle.intersect_ray(Vector2D::new(-3.0, 0.0), Vector2D::new(-2.0, 0.0));
+    /// assert_eq!(points, vec![Vector2D::new(-1.0, 0.0), Vector2D::new(1.0, 0.0)]);
+    /// ```
+    pub fn intersect_ray(&self, origin: Vector2D, through: Vector2D) -> Vec<Vector2D> {
+        self.crossings(origin, through, f64::INFINITY)
+    }
+
+    fn crossings(&self, a: Vector2D, b: Vector2D, max_t: f64) -> Vec<Vector2D> {
+        let center = self.position();
+        let to_unit = |p: Vector2D| (p - center) / self.radius;
+        unit_circle_line_params(to_unit(a), to_unit(b), max_t)
+            .into_iter()
+            .map(|t| a.lerp(b, t))
+            .collect()
+    }
+
     /// Sets the radius of the circle.
     ///
     /// This regenerates the underlying path.
@@ -147,6 +260,24 @@ impl Circle {
         self
     }
 
+    /// Sets the stroke color, and the fill color if the circle is filled.
+    ///
+    /// The stroke width is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::geometry::Circle;
+    ///
+    /// let mut circle = Circle::new(2.0);
+    /// circle.set_fill(Color::RED).set_color(Color::BLUE);
+    /// ```
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.vmobject.set_color(color);
+        self
+    }
+
     /// Creates a path representing a circle using 4 cubic Bézier curves.
     ///
     /// This is the standard technique for representing circles in vector graphics.
@@ -200,8 +331,17 @@ impl Mobject for Circle {
         self.vmobject.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.vmobject.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.vmobject.apply_default_style(style);
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.vmobject.apply_transform(transform);
+        self.radius *= transform.determinant().abs().sqrt();
     }
 
     fn position(&self) -> Vector2D {
@@ -220,11 +360,63 @@ impl Mobject for Circle {
         self.vmobject.set_opacity(opacity);
     }
 
+    fn describe(&self) -> String {
+        format!(
+            "Circle(radius: {}, position: {}, opacity: {})",
+            self.radius,
+            self.position(),
+            self.opacity()
+        )
+    }
+
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.vmobject)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.vmobject)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
 }
 
+/// Returns the parameters `t` in `[0, max_t]` at which the line `a + t (b - a)`
+/// crosses the unit circle, in ascending order.
+///
+/// A `max_t` of 1 limits the result to the segment from `a` to `b`, and
+/// infinity to the ray from `a` through `b`. Shapes that are affine images of
+/// the unit circle map their points into unit space first; affine maps
+/// preserve `t`, so the parameters apply to the original line unchanged.
+pub(super) fn unit_circle_line_params(a: Vector2D, b: Vector2D, max_t: f64) -> Vec<f64> {
+    let d = b - a;
+    // |a + t d|^2 = 1, as qa t^2 + qb t + qc = 0
+    let qa = d.dot(d);
+    if qa == 0.0 || !qa.is_finite() {
+        return Vec::new();
+    }
+    let qb = 2.0 * a.dot(d);
+    let qc = a.dot(a) - 1.0;
+    let discriminant = qb * qb - 4.0 * qa * qc;
+    // Tangent lines land on zero only up to rounding, so compare relative
+    // to the terms being subtracted
+    let tolerance = 1e-12 * (qb * qb).max((4.0 * qa * qc).abs()).max(1.0);
+
+    let roots = if discriminant < -tolerance {
+        Vec::new()
+    } else if discriminant <= tolerance {
+        vec![-qb / (2.0 * qa)]
+    } else {
+        let root = discriminant.sqrt();
+        vec![(-qb - root) / (2.0 * qa), (-qb + root) / (2.0 * qa)]
+    };
+    roots
+        .into_iter()
+        .filter(|t| (0.0..=max_t).contains(t))
+        .collect()
+}
+
 /// Builder for constructing circles with custom properties.
 ///
 /// # Examples
@@ -506,6 +698,19 @@ mod tests {
         assert_eq!(cloned.vmobject.fill_color(), Some(Color::BLUE));
     }
 
+    #[test]
+    fn test_circle_clone_keeps_concrete_api() {
+        let circle = Circle::new(2.0);
+        let mut cloned = circle.clone();
+        cloned.set_radius(3.0);
+
+        assert_eq!(cloned.radius(), 3.0);
+        assert_eq!(circle.radius(), 2.0);
+
+        let boxed = cloned.into_boxed();
+        assert_eq!(boxed.position(), Vector2D::ZERO);
+    }
+
     #[test]
     fn test_circle_builder_default() {
         let circle = Circle::builder().build();
@@ -585,4 +790,225 @@ mod tests {
 
         assert_eq!(circle.position(), Vector2D::new(3.0, 4.0));
     }
+
+    #[test]
+    fn test_circle_set_color() {
+        let mut circle = Circle::new(2.0);
+        circle.set_stroke(Color::WHITE, 3.0).set_fill(Color::RED);
+        circle.set_color(Color::BLUE);
+
+        assert_eq!(circle.vmobject.stroke_color(), Some(Color::BLUE));
+        assert_eq!(circle.vmobject.fill_color(), Some(Color::BLUE));
+        assert_eq!(circle.vmobject.stroke_width(), 3.0);
+    }
+
+    #[test]
+    fn test_circle_contains_point() {
+        let mut circle = Circle::new(2.0);
+        assert!(circle.contains_point(Vector2D::ZERO));
+        assert!(circle.contains_point(Vector2D::new(1.99, 0.0)));
+        assert!(circle.contains_point(Vector2D::new(0.0, -2.0)));
+        assert!(!circle.contains_point(Vector2D::new(2.01, 0.0)));
+        assert!(!circle.contains_point(Vector2D::new(1.5, 1.5)));
+
+        circle.set_position(Vector2D::new(10.0, 10.0));
+        assert!(circle.contains_point(Vector2D::new(11.0, 10.0)));
+        assert!(!circle.contains_point(Vector2D::ZERO));
+    }
+
+    #[test]
+    fn test_circle_describe() {
+        let description = Circle::new(2.0).describe();
+        assert!(description.starts_with("Circle("));
+        assert!(description.contains("radius: 2"));
+    }
+
+    #[test]
+    fn test_circle_point_and_tangent_at_angle() {
+        let mut circle = Circle::new(3.0);
+        circle.set_position(Vector2D::new(-2.0, 5.0));
+
+        let point = circle.point_at_angle(Radians(0.0));
+        assert_relative_eq!(point.x, 1.0);
+        assert_relative_eq!(point.y, 5.0);
+
+        let tangent = circle.tangent_at_angle(Radians(0.0));
+        assert_relative_eq!(tangent.x, 0.0);
+        assert_relative_eq!(tangent.y.abs(), 1.0);
+
+        // Tangents are unit length and perpendicular to the radius
+        let angle = Radians(2.0);
+        let radius = circle.point_at_angle(angle) - circle.position();
+        let tangent = circle.tangent_at_angle(angle);
+        assert_relative_eq!(radius.magnitude(), 3.0, epsilon = 1e-10);
+        assert_relative_eq!(tangent.magnitude(), 1.0, epsilon = 1e-10);
+        assert_relative_eq!(radius.dot(tangent), 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_circle_intersect_line_through_center() {
+        let circle = Circle::new(1.0);
+        let points = circle.intersect_line(Vector2D::new(-3.0, 0.0), Vector2D::new(3.0, 0.0));
+
+        assert_eq!(points.len(), 2);
+        assert_relative_eq!(points[0].x, -1.0);
+        assert_relative_eq!(points[0].y, 0.0);
+        assert_relative_eq!(points[1].x, 1.0);
+        assert_relative_eq!(points[1].y, 0.0);
+    }
+
+    #[test]
+    fn test_circle_intersect_line_misses() {
+        let circle = Circle::new(1.0);
+        let above = circle.intersect_line(Vector2D::new(-3.0, 2.0), Vector2D::new(3.0, 2.0));
+        assert!(above.is_empty());
+
+        // The infinite line would cross, but the segment stops short
+        let short = circle.intersect_line(Vector2D::new(-3.0, 0.0), Vector2D::new(-2.0, 0.0));
+        assert!(short.is_empty());
+    }
+
+    #[test]
+    fn test_circle_intersect_line_tangent_and_partial() {
+        let mut circle = Circle::new(2.0);
+        circle.set_position(Vector2D::new(1.0, 1.0));
+
+        let tangent = circle.intersect_line(Vector2D::new(-5.0, 3.0), Vector2D::new(5.0, 3.0));
+        assert_eq!(tangent.len(), 1);
+        assert_relative_eq!(tangent[0].x, 1.0, epsilon = 1e-9);
+        assert_relative_eq!(tangent[0].y, 3.0);
+
+        // Starts inside, so only the exit point lies on the segment
+        let exit = circle.intersect_line(Vector2D::new(1.0, 1.0), Vector2D::new(10.0, 1.0));
+        assert_eq!(exit.len(), 1);
+        assert_relative_eq!(exit[0].x, 3.0);
+    }
+
+    #[test]
+    fn test_circle_bounding_box_is_rotation_invariant() {
+        let mut circle = Circle::builder().radius(2.0).no_stroke().build();
+        let before = circle.bounding_box();
+
+        circle.apply_transform(&Transform::rotate(std::f64::consts::FRAC_PI_4));
+        let after = circle.bounding_box();
+
+        // The Bézier approximation deviates from a true circle by < 0.03%
+        assert_relative_eq!(after.width(), 4.0, epsilon = 2e-3);
+        assert_relative_eq!(after.height(), 4.0, epsilon = 2e-3);
+        assert_relative_eq!(after.width(), before.width(), epsilon = 2e-3);
+        assert_relative_eq!(after.center().x, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_circle_match_style_from_square() {
+        use crate::mobject::geometry::Square;
+
+        let square = Square::builder()
+            .stroke_color(Color::RED)
+            .stroke_width(5.0)
+            .fill_color(Color::BLUE)
+            .build();
+        let mut circle = Circle::new(1.0);
+        let points = circle.get_points();
+
+        circle.match_style(square.vmobject().unwrap());
+        assert_eq!(circle.vmobject().unwrap().stroke_color(), Some(Color::RED));
+        assert_eq!(circle.vmobject().unwrap().stroke_width(), 5.0);
+        assert_eq!(circle.vmobject().unwrap().fill_color(), Some(Color::BLUE));
+        assert_eq!(circle.get_points(), points);
+    }
+
+    #[test]
+    fn test_circle_equality() {
+        let mut a = Circle::new(2.0);
+        let mut b = Circle::new(2.0);
+        a.set_stroke(Color::BLUE, 3.0);
+        b.set_stroke(Color::BLUE, 3.0);
+        assert_eq!(a, b);
+
+        // Computing cached bounds does not affect equality
+        let _ = a.bounding_box();
+        assert_eq!(a, b);
+
+        assert_ne!(a, Circle::new(3.0));
+        b.set_fill(Color::RED);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_transform_updates_radius() {
+        let mut circle = Circle::new(1.0);
+        circle.apply_transform(&Transform::scale(3.0, 3.0));
+        assert_relative_eq!(circle.radius(), 3.0, epsilon = 1e-12);
+        assert!(circle.contains_point(Vector2D::new(2.0, 0.0)));
+        assert!(!circle.contains_point(Vector2D::new(3.5, 0.0)));
+
+        // Rotations and translations keep the radius
+        circle.apply_transform(&(Transform::translate(1.0, 0.0) * Transform::rotate(0.7)));
+        assert_relative_eq!(circle.radius(), 3.0, epsilon = 1e-12);
+        assert!(circle.contains_point(Vector2D::new(3.5, 0.0)));
+    }
+
+    #[test]
+    fn test_point_at_angle_after_scale() {
+        let mut circle = Circle::new(1.0);
+        circle.apply_transform(&Transform::scale(3.0, 3.0));
+        let point = circle.point_at_angle(Radians(0.0));
+        assert_relative_eq!(point.x, 3.0, epsilon = 1e-12);
+        assert_relative_eq!(point.y, 0.0, epsilon = 1e-12);
+
+        // The returned point lies on the transformed path
+        let top = circle.point_at_angle(Radians(std::f64::consts::FRAC_PI_2));
+        assert_relative_eq!(
+            top.y,
+            circle.vmobject().unwrap().path().bounding_box().max().y,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_intersect_line_after_scale() {
+        let mut circle = Circle::new(1.0);
+        circle.apply_transform(&Transform::scale(2.0, 2.0));
+        let points = circle.intersect_line(Vector2D::new(-3.0, 0.0), Vector2D::new(3.0, 0.0));
+        assert_eq!(points.len(), 2);
+        assert_relative_eq!(points[0].x, -2.0, epsilon = 1e-12);
+        assert_relative_eq!(points[1].x, 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_builder_defaults_do_not_count_as_styled() {
+        let theme = PathStyle::stroke(Color::BLACK, 3.0);
+
+        let mut built = Circle::builder().radius(1.0).build();
+        assert_eq!(built, Circle::new(1.0));
+        built.apply_default_style(&theme);
+        assert_eq!(built.vmobject().unwrap().stroke_color(), Some(Color::BLACK));
+
+        let mut red = Circle::builder()
+            .radius(1.0)
+            .stroke_color(Color::RED)
+            .build();
+        red.apply_default_style(&theme);
+        assert_eq!(red.vmobject().unwrap().stroke_color(), Some(Color::RED));
+    }
+
+    #[test]
+    fn test_circle_intersect_ray() {
+        let circle = Circle::new(1.0);
+        // The segment stops short of the circle; the ray does not
+        let (origin, through) = (Vector2D::new(0.0, -3.0), Vector2D::new(0.0, -2.0));
+        assert!(circle.intersect_line(origin, through).is_empty());
+        assert_eq!(
+            circle.intersect_ray(origin, through),
+            vec![Vector2D::new(0.0, -1.0), Vector2D::new(0.0, 1.0)]
+        );
+
+        // Crossings behind the origin are dropped
+        let points = circle.intersect_ray(Vector2D::ZERO, Vector2D::new(2.0, 0.0));
+        assert_eq!(points, vec![Vector2D::new(1.0, 0.0)]);
+        assert!(circle
+            .intersect_ray(Vector2D::new(2.0, 0.0), Vector2D::new(3.0, 0.0))
+            .is_empty());
+    }
 }
@@ -4,7 +4,9 @@
 
 use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
 use crate::mobject::{Mobject, VMobject};
-use crate::renderer::{Path, Renderer};
+use crate::renderer::{Path, PathStyle, Renderer};
+
+use super::circle::unit_circle_line_params;
 
 /// Magic number for approximating a circle/ellipse with cubic Bézier curves.
 const BEZIER_MAGIC: f64 = 0.551_915_024_493_510_6;
@@ -27,11 +29,12 @@ const BEZIER_MAGIC: f64 = 0.551_915_024_493_510_6;
 ///     .fill_color(Color::BLUE)
 ///     .build();
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Ellipse {
     vmobject: VMobject,
     width: f64,
     height: f64,
+    rotation: f64,
 }
 
 impl Ellipse {
@@ -50,9 +53,71 @@ impl Ellipse {
             vmobject: VMobject::new(path),
             width,
             height,
+            rotation: 0.0,
         }
     }
 
+    /// Creates an ellipse from its two foci and the constant sum of distances
+    /// from any point on the ellipse to both foci.
+    ///
+    /// The major axis runs through both foci, so the ellipse is rotated to
+    /// match their orientation and centered at their midpoint. If
+    /// `sum_distance` is smaller than the distance between the foci, no such
+    /// ellipse exists and the result degenerates to a flat ellipse (height 0)
+    /// spanning the foci.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Ellipse;
+    ///
+    /// let ellipse = Ellipse::from_foci(
+    ///     Vector2D::new(-3.0, 0.0),
+    ///     Vector2D::new(3.0, 0.0),
+    ///     10.0,
+    /// );
+    /// assert!((ellipse.width() - 10.0).abs() < 1e-10);
+    /// assert!((ellipse.height() - 8.0).abs() < 1e-10);
+    /// ```
+    pub fn from_foci(focus1: Vector2D, focus2: Vector2D, sum_distance: f64) -> Self {
+        let axis = focus2 - focus1;
+        let focal_distance = axis.magnitude();
+        let major = sum_distance.max(focal_distance) / 2.0;
+        let half_focal = focal_distance / 2.0;
+        let minor = (major * major - half_focal * half_focal).max(0.0).sqrt();
+
+        let mut ellipse = Self::new(2.0 * major, 2.0 * minor);
+        ellipse.rotation = if focal_distance > 0.0 {
+            axis.y.atan2(axis.x)
+        } else {
+            0.0
+        };
+        ellipse.rebuild_path();
+        ellipse.set_position((focus1 + focus2) / 2.0);
+        ellipse
+    }
+
+    /// Creates an ellipse inscribed in a bounding box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{BoundingBox, Vector2D};
+    /// use manim_rs::mobject::Mobject;
+    /// use manim_rs::mobject::geometry::Ellipse;
+    ///
+    /// let bbox = BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(4.0, 2.0));
+    /// let ellipse = Ellipse::from_bounding_box(bbox);
+    /// assert_eq!(ellipse.width(), 4.0);
+    /// assert_eq!(ellipse.position(), Vector2D::new(2.0, 1.0));
+    /// ```
+    pub fn from_bounding_box(bbox: BoundingBox) -> Self {
+        let mut ellipse = Self::new(bbox.width(), bbox.height());
+        ellipse.set_position(bbox.center());
+        ellipse
+    }
+
     /// Returns a builder for constructing an ellipse.
     pub fn builder() -> EllipseBuilder {
         EllipseBuilder::new()
@@ -68,11 +133,97 @@ impl Ellipse {
         self.height
     }
 
+    /// Returns the points where the segment from `a` to `b` crosses the
+    /// ellipse.
+    ///
+    /// The result holds 0, 1 (tangent), or 2 points, ordered from `a`
+    /// towards `b`, and only crossings within the segment are returned. The
+    /// ellipse is taken to be centered at its position and oriented by
+    /// [`Ellipse::rotation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Ellipse;
+    ///
+    /// let ellipse = Ellipse::new(4.0, 2.0);
+    /// let points = ellipse.intersect_line(Vector2D::new(0.0, -3.0), Vector2D::new(0.0, 3.0));
+    /// assert_eq!(points, vec![Vector2D::new(0.0, -1.0), Vector2D::new(0.0, 1.0)]);
+    /// ```
+    pub fn intersect_line(&self, a: Vector2D, b: Vector2D) -> Vec<Vector2D> {
+        self.crossings(a, b, 1.0)
+    }
+
+    /// Returns the points where the ray from `origin` through `through`
+    /// crosses the ellipse.
+    ///
+    /// Unlike [`Ellipse::intersect_line`], crossings beyond `through` are
+    /// kept; only those behind `origin` are dropped. The result is ordered
+    /// from `origin` outwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Ellipse;
+    ///
+    /// let ellipse = Ellipse::new(4.0, 2.0);
+    /// let points = ellipse.intersect_ray(Vector2D::ZERO, Vector2D::new(0.5, 0.0));
+    /// assert_eq!(points, vec![Vector2D::new(2.0, 0.0)]);
+    /// ```
+    pub fn intersect_ray(&self, origin: Vector2D, through: Vector2D) -> Vec<Vector2D> {
+        self.crossings(origin, through, f64::INFINITY)
+    }
+
+    fn crossings(&self, a: Vector2D, b: Vector2D, max_t: f64) -> Vec<Vector2D> {
+        let (half_width, half_height) = (self.width / 2.0, self.height / 2.0);
+        if half_width <= 0.0 || half_height <= 0.0 {
+            return Vec::new();
+        }
+        let center = self.position();
+        let (sin, cos) = self.rotation.sin_cos();
+        let to_unit = |p: Vector2D| {
+            let local = p - center;
+            // Undo the rotation, then squash the axes onto the unit circle
+            Vector2D::new(
+                (local.x * cos + local.y * sin) / half_width,
+                (local.y * cos - local.x * sin) / half_height,
+            )
+        };
+        unit_circle_line_params(to_unit(a), to_unit(b), max_t)
+            .into_iter()
+            .map(|t| a.lerp(b, t))
+            .collect()
+    }
+
+    /// Returns the rotation of the width axis in radians.
+    ///
+    /// This starts as the orientation the ellipse was constructed with (see
+    /// [`Ellipse::from_foci`]) and follows later transforms. Any affine
+    /// transform maps an ellipse to an ellipse, so width, height and
+    /// rotation always describe the transformed shape.
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+
     /// Sets the width and height of the ellipse.
+    ///
+    /// The ellipse keeps its rotation and position.
     pub fn set_size(&mut self, width: f64, height: f64) {
         self.width = width;
         self.height = height;
-        let path = Self::create_ellipse_path(width, height);
+        self.rebuild_path();
+    }
+
+    /// Regenerates the path from the current size and rotation, keeping the
+    /// ellipse centered at its current position.
+    fn rebuild_path(&mut self) {
+        let mut path = Self::create_ellipse_path(self.width, self.height);
+        let position = self.vmobject.position();
+        let transform =
+            Transform::translate(position.x, position.y) * Transform::rotate(self.rotation);
+        path.apply_transform(&transform);
         *self.vmobject.path_mut() = path;
     }
 
@@ -82,6 +233,12 @@ impl Ellipse {
         self
     }
 
+    /// Sets the stroke color, and the fill color if one is set.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.vmobject.set_color(color);
+        self
+    }
+
     /// Sets the fill color.
     pub fn set_fill(&mut self, color: Color) -> &mut Self {
         self.vmobject.set_fill(color);
@@ -141,8 +298,21 @@ impl Mobject for Ellipse {
         self.vmobject.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.vmobject.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.vmobject.apply_default_style(style);
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.vmobject.apply_transform(transform);
+        let (width, height, rotation) =
+            transformed_axes(transform, self.width, self.height, self.rotation);
+        self.width = width;
+        self.height = height;
+        self.rotation = rotation;
     }
 
     fn position(&self) -> Vector2D {
@@ -161,6 +331,14 @@ impl Mobject for Ellipse {
         self.vmobject.set_opacity(opacity);
     }
 
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.vmobject)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.vmobject)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
@@ -260,6 +438,53 @@ impl Default for EllipseBuilder {
     }
 }
 
+/// Returns the width, height and rotation of an ellipse after `transform`.
+///
+/// The linear part of the transform times the ellipse's own rotation and
+/// scale is decomposed into rotation × scale × rotation; the leftmost
+/// rotation and the scale give the new axes. Of the two new axes, the one
+/// closest to the image of the old width axis stays the width axis.
+fn transformed_axes(
+    transform: &Transform,
+    width: f64,
+    height: f64,
+    rotation: f64,
+) -> (f64, f64, f64) {
+    let (sin, cos) = rotation.sin_cos();
+    let (hw, hh) = (width / 2.0, height / 2.0);
+    // Columns are the images of the width and height semi-axes
+    let (p, r) = (
+        (transform.a * cos + transform.c * sin) * hw,
+        (transform.b * cos + transform.d * sin) * hw,
+    );
+    let (q, s) = (
+        (transform.c * cos - transform.a * sin) * hh,
+        (transform.d * cos - transform.b * sin) * hh,
+    );
+
+    let (e, f) = ((p + s) / 2.0, (p - s) / 2.0);
+    let (g, h) = ((r + q) / 2.0, (r - q) / 2.0);
+    let (big, small) = (e.hypot(h), f.hypot(g));
+    let theta = (g.atan2(f) + h.atan2(e)) / 2.0;
+    let major = Vector2D::new(theta.cos(), theta.sin());
+    let minor = Vector2D::new(-major.y, major.x);
+
+    let width_axis = Vector2D::new(p, r);
+    let (axis, new_width, new_height) =
+        if width_axis.dot(major).abs() >= width_axis.dot(minor).abs() {
+            (major, big + small, (big - small).abs())
+        } else {
+            (minor, (big - small).abs(), big + small)
+        };
+    // Point the axis the same way as the old width axis
+    let axis = if width_axis.dot(axis) < 0.0 {
+        -axis
+    } else {
+        axis
+    };
+    (2.0 * new_width, 2.0 * new_height, axis.y.atan2(axis.x))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,4 +521,161 @@ mod tests {
         assert_eq!(ellipse.width(), 6.0);
         assert_eq!(ellipse.height(), 4.0);
     }
+
+    #[test]
+    fn test_ellipse_from_foci_sum_of_distances() {
+        let f1 = Vector2D::new(-3.0, 0.0);
+        let f2 = Vector2D::new(3.0, 0.0);
+        let ellipse = Ellipse::from_foci(f1, f2, 10.0);
+
+        assert!((ellipse.width() - 10.0).abs() < 1e-10);
+        assert!((ellipse.height() - 8.0).abs() < 1e-10);
+
+        // Every on-curve anchor satisfies |P - F1| + |P - F2| = sum
+        for command in ellipse.vmobject.path().commands() {
+            let point = match command {
+                crate::renderer::PathCommand::MoveTo(p) => *p,
+                crate::renderer::PathCommand::CubicTo { to, .. } => *to,
+                _ => continue,
+            };
+            let sum = (point - f1).magnitude() + (point - f2).magnitude();
+            assert!((sum - 10.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_ellipse_from_foci_rotated() {
+        let ellipse = Ellipse::from_foci(Vector2D::new(1.0, -3.0), Vector2D::new(1.0, 3.0), 10.0);
+
+        assert!((ellipse.rotation() - std::f64::consts::FRAC_PI_2).abs() < 1e-10);
+        assert_eq!(ellipse.position(), Vector2D::new(1.0, 0.0));
+
+        // Major axis is vertical: taller than wide
+        let bbox = ellipse.vmobject.path().bounding_box();
+        assert!((bbox.height() - 10.0).abs() < 1e-9);
+        assert!((bbox.width() - 8.0).abs() < 1e-9);
+        assert!((bbox.center().x - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ellipse_from_foci_degenerate() {
+        let ellipse = Ellipse::from_foci(Vector2D::new(0.0, 0.0), Vector2D::new(4.0, 0.0), 1.0);
+        assert_eq!(ellipse.width(), 4.0);
+        assert_eq!(ellipse.height(), 0.0);
+
+        let coincident = Ellipse::from_foci(Vector2D::ZERO, Vector2D::ZERO, 2.0);
+        assert_eq!(coincident.width(), 2.0);
+        assert_eq!(coincident.height(), 2.0);
+    }
+
+    #[test]
+    fn test_ellipse_from_bounding_box() {
+        let bbox = BoundingBox::new(Vector2D::new(-1.0, 2.0), Vector2D::new(5.0, 4.0));
+        let ellipse = Ellipse::from_bounding_box(bbox);
+
+        assert_eq!(ellipse.width(), 6.0);
+        assert_eq!(ellipse.height(), 2.0);
+        assert_eq!(ellipse.position(), Vector2D::new(2.0, 3.0));
+
+        let path_bbox = ellipse.vmobject.path().bounding_box();
+        assert!((path_bbox.min().x - bbox.min().x).abs() < 1e-10);
+        assert!((path_bbox.max().y - bbox.max().y).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_ellipse_set_size_keeps_position() {
+        let mut ellipse = Ellipse::new(2.0, 1.0);
+        ellipse.set_position(Vector2D::new(3.0, 3.0));
+        ellipse.set_size(4.0, 2.0);
+
+        let bbox = ellipse.vmobject.path().bounding_box();
+        assert!((bbox.center().x - 3.0).abs() < 1e-10);
+        assert!((bbox.center().y - 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_ellipse_intersect_line() {
+        let ellipse = Ellipse::new(4.0, 2.0);
+        let points = ellipse.intersect_line(Vector2D::new(-5.0, 0.0), Vector2D::new(5.0, 0.0));
+        assert_eq!(points.len(), 2);
+        assert!((points[0] - Vector2D::new(-2.0, 0.0)).magnitude() < 1e-10);
+        assert!((points[1] - Vector2D::new(2.0, 0.0)).magnitude() < 1e-10);
+
+        let miss = ellipse.intersect_line(Vector2D::new(-5.0, 1.5), Vector2D::new(5.0, 1.5));
+        assert!(miss.is_empty());
+    }
+
+    #[test]
+    fn test_ellipse_intersect_line_rotated_and_moved() {
+        // Major axis along y, centered at (1, 1)
+        let ellipse = Ellipse::from_foci(Vector2D::new(1.0, -2.0), Vector2D::new(1.0, 4.0), 10.0);
+        let points = ellipse.intersect_line(Vector2D::new(1.0, -10.0), Vector2D::new(1.0, 10.0));
+
+        assert_eq!(points.len(), 2);
+        assert!((points[0] - Vector2D::new(1.0, -4.0)).magnitude() < 1e-10);
+        assert!((points[1] - Vector2D::new(1.0, 6.0)).magnitude() < 1e-10);
+    }
+
+    #[test]
+    fn test_transform_tracks_axes() {
+        let mut ellipse = Ellipse::new(4.0, 2.0);
+        ellipse.apply_transform(&Transform::rotate(std::f64::consts::FRAC_PI_2));
+        assert!((ellipse.width() - 4.0).abs() < 1e-9);
+        assert!((ellipse.height() - 2.0).abs() < 1e-9);
+        assert!((ellipse.rotation() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+
+        // The width axis now lies along y
+        let hits = ellipse.intersect_line(Vector2D::new(-3.0, 0.0), Vector2D::new(3.0, 0.0));
+        assert_eq!(hits.len(), 2);
+        assert!((hits[0] - Vector2D::new(-1.0, 0.0)).magnitude() < 1e-9);
+        assert!((hits[1] - Vector2D::new(1.0, 0.0)).magnitude() < 1e-9);
+        let hits = ellipse.intersect_line(Vector2D::new(0.0, -3.0), Vector2D::new(0.0, 3.0));
+        assert!((hits[1] - Vector2D::new(0.0, 2.0)).magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn test_transform_scale_and_shear() {
+        let mut ellipse = Ellipse::new(2.0, 2.0);
+        ellipse.apply_transform(&Transform::scale(3.0, 1.0));
+        assert!((ellipse.width() - 6.0).abs() < 1e-9);
+        assert!((ellipse.height() - 2.0).abs() < 1e-9);
+        assert!(ellipse.rotation().abs() < 1e-9);
+
+        // After a shear, every path anchor still lies on the tracked ellipse
+        let shear = Transform {
+            a: 1.0,
+            b: 0.0,
+            c: 0.8,
+            d: 1.0,
+            tx: 1.0,
+            ty: -2.0,
+        };
+        ellipse.apply_transform(&shear);
+        let center = ellipse.position();
+        let (sin, cos) = ellipse.rotation().sin_cos();
+        for point in ellipse
+            .vmobject()
+            .unwrap()
+            .path()
+            .points()
+            .iter()
+            .step_by(3)
+        {
+            let local = *point - center;
+            let u = (local.x * cos + local.y * sin) / (ellipse.width() / 2.0);
+            let v = (local.y * cos - local.x * sin) / (ellipse.height() / 2.0);
+            assert!((u * u + v * v - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_ellipse_intersect_ray() {
+        let ellipse = Ellipse::new(4.0, 2.0);
+        let (origin, through) = (Vector2D::new(-5.0, 0.0), Vector2D::new(-4.0, 0.0));
+        assert!(ellipse.intersect_line(origin, through).is_empty());
+        assert_eq!(
+            ellipse.intersect_ray(origin, through),
+            vec![Vector2D::new(-2.0, 0.0), Vector2D::new(2.0, 0.0)]
+        );
+    }
 }
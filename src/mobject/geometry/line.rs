@@ -3,8 +3,46 @@
 //! Provides line segments with various styling options.
 
 use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
-use crate::mobject::{Mobject, VMobject};
-use crate::renderer::{Path, Renderer};
+use crate::mobject::{Mobject, MobjectGroup, VMobject};
+use crate::renderer::{Path, PathStyle, Renderer};
+
+use super::Arrow;
+
+/// Where along a segment to place a label.
+///
+/// Used by [`Line::label_position`] and [`Arrow::label_position`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LabelPos {
+    /// The start point
+    Start,
+    /// The midpoint
+    Middle,
+    /// The end point
+    End,
+    /// The midpoint, moved this distance along the left-hand normal of the
+    /// start-to-end direction. For a segment pointing right, this is above
+    /// it; negative distances move below.
+    AboveMiddle(f64),
+}
+
+impl LabelPos {
+    /// Resolves the position on the segment from `start` to `end`.
+    pub(crate) fn resolve(self, start: Vector2D, end: Vector2D) -> Vector2D {
+        let middle = start.lerp(end, 0.5);
+        match self {
+            LabelPos::Start => start,
+            LabelPos::Middle => middle,
+            LabelPos::End => end,
+            LabelPos::AboveMiddle(offset) => {
+                // Degenerate segments have no direction; treat them as pointing right
+                let normal = Vector2D::new(start.y - end.y, end.x - start.x)
+                    .normalize()
+                    .unwrap_or(Vector2D::new(0.0, 1.0));
+                middle + normal * offset
+            }
+        }
+    }
+}
 
 /// A line segment mobject.
 ///
@@ -22,7 +60,7 @@ use crate::renderer::{Path, Renderer};
 ///     .stroke_color(Color::BLUE)
 ///     .build();
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Line {
     vmobject: VMobject,
     start: Vector2D,
@@ -87,6 +125,25 @@ impl Line {
         (self.end - self.start).magnitude()
     }
 
+    /// Returns the anchor point for a label at `pos` along the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::{LabelPos, Line};
+    ///
+    /// let line = Line::new(Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 0.0));
+    /// assert_eq!(line.label_position(LabelPos::Middle), Vector2D::new(1.0, 0.0));
+    /// assert_eq!(
+    ///     line.label_position(LabelPos::AboveMiddle(0.5)),
+    ///     Vector2D::new(1.0, 0.5)
+    /// );
+    /// ```
+    pub fn label_position(&self, pos: LabelPos) -> Vector2D {
+        pos.resolve(self.start, self.end)
+    }
+
     /// Returns the angle of the line in radians.
     ///
     /// The angle is measured from the positive x-axis.
@@ -101,6 +158,12 @@ impl Line {
         self
     }
 
+    /// Sets the stroke color, and the fill color if one is set.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.vmobject.set_color(color);
+        self
+    }
+
     /// Creates a line path from start to end.
     fn create_line_path(start: Vector2D, end: Vector2D) -> Path {
         let mut path = Path::new();
@@ -118,6 +181,14 @@ impl Mobject for Line {
         self.vmobject.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.vmobject.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.vmobject.apply_default_style(style);
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.vmobject.apply_transform(transform);
         self.start = transform.apply(self.start);
@@ -129,7 +200,10 @@ impl Mobject for Line {
     }
 
     fn set_position(&mut self, pos: Vector2D) {
+        let delta = pos - self.vmobject.position();
         self.vmobject.set_position(pos);
+        self.start = self.start + delta;
+        self.end = self.end + delta;
     }
 
     fn opacity(&self) -> f64 {
@@ -140,12 +214,23 @@ impl Mobject for Line {
         self.vmobject.set_opacity(opacity);
     }
 
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.vmobject)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.vmobject)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
 }
 
 /// Builder for constructing lines.
+///
+/// Besides plain [`Line`]s, the builder can produce dashed connectors and
+/// arrows through [`LineBuilder::build_connector`].
 #[derive(Clone, Debug)]
 pub struct LineBuilder {
     start: Vector2D,
@@ -153,6 +238,8 @@ pub struct LineBuilder {
     stroke_color: Option<Color>,
     stroke_width: f64,
     opacity: f64,
+    dash: Option<(f64, f64)>,
+    tip: bool,
 }
 
 impl LineBuilder {
@@ -163,6 +250,8 @@ impl LineBuilder {
             stroke_color: Some(Color::WHITE),
             stroke_width: 2.0,
             opacity: 1.0,
+            dash: None,
+            tip: false,
         }
     }
 
@@ -191,6 +280,24 @@ impl LineBuilder {
         self
     }
 
+    /// Dashes the line with `on`-length dashes separated by `off`-length gaps.
+    ///
+    /// Only honored by [`LineBuilder::build_connector`]. A non-positive `on`
+    /// length leaves the line solid.
+    pub fn dashed(mut self, on: f64, off: f64) -> Self {
+        self.dash = Some((on, off.max(0.0)));
+        self
+    }
+
+    /// Adds an arrowhead at the end of the line.
+    ///
+    /// Only honored by [`LineBuilder::build_connector`].
+    pub fn with_tip(mut self, tip: bool) -> Self {
+        self.tip = tip;
+        self
+    }
+
+    /// Builds a solid [`Line`], ignoring any dash pattern or tip.
     pub fn build(self) -> Line {
         let mut line = Line::new(self.start, self.end);
 
@@ -204,6 +311,81 @@ impl LineBuilder {
 
         line
     }
+
+    /// Builds the connector described by this builder.
+    ///
+    /// Returns an [`Arrow`] when a tip was requested, a [`MobjectGroup`] of
+    /// dash segments when the line is dashed, and a plain [`Line`] otherwise.
+    /// A dashed line with a tip is a group of dashes followed by the arrow
+    /// built over the final stretch. Zero-length lines always build as a
+    /// plain [`Line`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Line;
+    ///
+    /// let connector = Line::builder()
+    ///     .start(Vector2D::new(0.0, 0.0))
+    ///     .end(Vector2D::new(4.0, 0.0))
+    ///     .dashed(0.5, 0.25)
+    ///     .with_tip(true)
+    ///     .build_connector();
+    /// assert!(connector.bounding_box().width() > 3.9);
+    /// ```
+    pub fn build_connector(self) -> Box<dyn Mobject> {
+        let length = (self.end - self.start).magnitude();
+        let dash = self.dash.filter(|&(on, _)| on > 0.0);
+
+        if length < f64::EPSILON || (dash.is_none() && !self.tip) {
+            return Box::new(self.build());
+        }
+
+        let Some((on, off)) = dash else {
+            return Box::new(self.arrow(self.start));
+        };
+
+        let direction = self.end - self.start;
+        let tip_length = if self.tip { 0.35_f64.min(length) } else { 0.0 };
+        let dashed_length = length - tip_length;
+
+        let mut group = MobjectGroup::new();
+        let mut offset = 0.0;
+        while offset < dashed_length {
+            let dash_end = (offset + on).min(dashed_length);
+            let segment = LineBuilder {
+                start: self.start + direction * (offset / length),
+                end: self.start + direction * (dash_end / length),
+                dash: None,
+                tip: false,
+                ..self.clone()
+            };
+            group.add(Box::new(segment.build()));
+            offset = dash_end + off;
+        }
+
+        if self.tip {
+            group.add(Box::new(
+                self.arrow(self.start + direction * (dashed_length / length)),
+            ));
+        }
+
+        Box::new(group)
+    }
+
+    /// Builds an arrow from `start` to this builder's end point.
+    fn arrow(&self, start: Vector2D) -> Arrow {
+        let mut builder = Arrow::builder()
+            .start(start)
+            .end(self.end)
+            .stroke_width(self.stroke_width)
+            .opacity(self.opacity);
+        if let Some(color) = self.stroke_color {
+            builder = builder.stroke_color(color);
+        }
+        builder.build()
+    }
 }
 
 impl Default for LineBuilder {
@@ -215,8 +397,25 @@ impl Default for LineBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backends::{DrawCommand, RecordingRenderer};
     use approx::assert_relative_eq;
 
+    /// Renders `mobject` and counts its stroke-only and filled path draws.
+    fn count_draws(mobject: &dyn Mobject) -> (usize, usize) {
+        let mut renderer = RecordingRenderer::new(800, 600);
+        mobject.render(&mut renderer).unwrap();
+        renderer
+            .commands()
+            .iter()
+            .fold((0, 0), |(strokes, fills), command| match command {
+                DrawCommand::Path { style, .. } if style.fill_color.is_some() => {
+                    (strokes, fills + 1)
+                }
+                DrawCommand::Path { .. } => (strokes + 1, fills),
+                _ => (strokes, fills),
+            })
+    }
+
     #[test]
     fn test_line_new() {
         let line = Line::new(Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 1.0));
@@ -248,6 +447,15 @@ mod tests {
         assert_eq!(line.end(), Vector2D::new(5.0, 6.0));
     }
 
+    #[test]
+    fn test_line_set_position_moves_endpoints() {
+        let mut line = Line::new(Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 0.0));
+        line.set_position(line.position() + Vector2D::new(1.0, 3.0));
+
+        assert_eq!(line.start(), Vector2D::new(1.0, 3.0));
+        assert_eq!(line.end(), Vector2D::new(3.0, 3.0));
+    }
+
     #[test]
     fn test_line_builder() {
         let line = Line::builder()
@@ -260,4 +468,59 @@ mod tests {
         assert_eq!(line.start(), Vector2D::new(-1.0, -1.0));
         assert_eq!(line.end(), Vector2D::new(1.0, 1.0));
     }
+
+    #[test]
+    fn test_line_builder_dashed() {
+        let connector = Line::builder()
+            .start(Vector2D::new(0.0, 0.0))
+            .end(Vector2D::new(10.0, 0.0))
+            .dashed(1.0, 1.0)
+            .build_connector();
+
+        let (strokes, fills) = count_draws(connector.as_ref());
+        assert_eq!(strokes, 5);
+        assert_eq!(fills, 0);
+    }
+
+    #[test]
+    fn test_line_builder_with_tip() {
+        let connector = Line::builder()
+            .start(Vector2D::new(0.0, 0.0))
+            .end(Vector2D::new(3.0, 0.0))
+            .with_tip(true)
+            .build_connector();
+
+        let (strokes, fills) = count_draws(connector.as_ref());
+        assert_eq!(strokes, 1);
+        assert_eq!(fills, 1);
+    }
+
+    #[test]
+    fn test_line_builder_dashed_with_tip() {
+        let connector = Line::builder()
+            .start(Vector2D::new(0.0, 0.0))
+            .end(Vector2D::new(4.0, 0.0))
+            .dashed(0.5, 0.5)
+            .with_tip(true)
+            .build_connector();
+
+        let (strokes, fills) = count_draws(connector.as_ref());
+        assert!(strokes > 1);
+        assert_eq!(fills, 1);
+    }
+
+    #[test]
+    fn test_line_builder_zero_length() {
+        let point = Vector2D::new(1.0, 1.0);
+        let connector = Line::builder()
+            .start(point)
+            .end(point)
+            .dashed(0.5, 0.5)
+            .with_tip(true)
+            .build_connector();
+
+        let (strokes, _) = count_draws(connector.as_ref());
+        assert_eq!(strokes, 1);
+        assert_eq!(connector.bounding_box().center(), point);
+    }
 }
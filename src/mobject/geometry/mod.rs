@@ -32,6 +32,6 @@ pub use arc::{Arc, ArcBuilder};
 pub use arrow::{Arrow, ArrowBuilder};
 pub use circle::{Circle, CircleBuilder};
 pub use ellipse::{Ellipse, EllipseBuilder};
-pub use line::{Line, LineBuilder};
-pub use polygon::{Polygon, PolygonBuilder};
+pub use line::{LabelPos, Line, LineBuilder};
+pub use polygon::{convex_hull, Polygon, PolygonBuilder};
 pub use rectangle::{Rectangle, RectangleBuilder, Square, SquareBuilder};
@@ -4,9 +4,13 @@
 
 use std::f64::consts::PI;
 
+use crate::core::geometry::{line_intersection, segment_intersection};
 use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
 use crate::mobject::{Mobject, VMobject};
-use crate::renderer::{Path, Renderer};
+use crate::renderer::{Path, PathStyle, Renderer};
+
+/// Distance tolerance for treating a point as lying on an edge.
+const BOUNDARY_EPSILON: f64 = 1e-10;
 
 /// A polygon mobject.
 ///
@@ -26,7 +30,7 @@ use crate::renderer::{Path, Renderer};
 /// // Regular hexagon
 /// let hexagon = Polygon::regular(6, 1.0);
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Polygon {
     vmobject: VMobject,
     vertices: Vec<Vector2D>,
@@ -92,18 +96,227 @@ impl Polygon {
         &self.vertices
     }
 
+    /// Returns `true` if `point` lies inside the polygon or on its boundary.
+    ///
+    /// Uses a ray cast over the vertices with the even-odd rule, so
+    /// self-intersecting outlines alternate between inside and outside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Polygon;
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(4.0, 0.0),
+    ///     Vector2D::new(0.0, 4.0),
+    /// ]);
+    /// assert!(triangle.contains_point(Vector2D::new(1.0, 1.0)));
+    /// assert!(triangle.contains_point(Vector2D::new(2.0, 0.0)));
+    /// assert!(!triangle.contains_point(Vector2D::new(3.0, 3.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vector2D) -> bool {
+        let count = self.vertices.len();
+        let mut inside = false;
+        for i in 0..count {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % count];
+            let edge = b - a;
+            let offset = point - a;
+
+            // Points on an edge count as inside
+            let along = edge.dot(offset);
+            if edge.cross(offset).abs() <= BOUNDARY_EPSILON * edge.magnitude().max(1.0)
+                && along >= 0.0
+                && along <= edge.magnitude_squared()
+            {
+                return true;
+            }
+
+            if (a.y > point.y) != (b.y > point.y) {
+                let crossing_x = a.x + (point.y - a.y) / edge.y * edge.x;
+                if point.x < crossing_x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Returns `true` if the polygon is convex.
+    ///
+    /// Every turn from one edge to the next must be in the same direction,
+    /// and the outline must wind around exactly once, which rules out star
+    /// polygons such as a pentagram. Collinear vertices are allowed. Fewer
+    /// than three vertices never form a convex polygon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Polygon;
+    ///
+    /// assert!(Polygon::regular(6, 1.0).is_convex());
+    ///
+    /// let arrowhead = Polygon::new(vec![
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(2.0, 1.0),
+    ///     Vector2D::new(0.0, 2.0),
+    ///     Vector2D::new(1.0, 1.0),
+    /// ]);
+    /// assert!(!arrowhead.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let count = self.vertices.len();
+        if count < 3 {
+            return false;
+        }
+        let mut sign = 0.0;
+        let mut turning = 0.0;
+        for i in 0..count {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % count];
+            let c = self.vertices[(i + 2) % count];
+            let (incoming, outgoing) = (b - a, c - b);
+            let cross = incoming.cross(outgoing);
+            if cross.abs() > BOUNDARY_EPSILON {
+                if sign * cross < 0.0 {
+                    return false;
+                }
+                sign = cross.signum();
+            }
+            turning += incoming.angle_to(outgoing).0;
+        }
+        // A convex outline turns through exactly one full revolution
+        (turning.abs() - 2.0 * PI).abs() < 1e-6
+    }
+
+    /// Returns `true` if no two edges of the polygon cross or touch, other
+    /// than consecutive edges meeting at their shared vertex.
+    ///
+    /// Every pair of non-adjacent edges is tested, so this takes quadratic
+    /// time in the number of vertices. Fewer than three vertices never form
+    /// a simple polygon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Polygon;
+    ///
+    /// let bowtie = Polygon::new(vec![
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(2.0, 2.0),
+    ///     Vector2D::new(2.0, 0.0),
+    ///     Vector2D::new(0.0, 2.0),
+    /// ]);
+    /// assert!(!bowtie.is_simple());
+    /// assert!(Polygon::regular(5, 1.0).is_simple());
+    /// ```
+    pub fn is_simple(&self) -> bool {
+        let count = self.vertices.len();
+        if count < 3 {
+            return false;
+        }
+        let edge = |i: usize| (self.vertices[i], self.vertices[(i + 1) % count]);
+        for i in 0..count {
+            // Skip the next edge, and the last edge when i is the first,
+            // since those share a vertex with edge i
+            for j in (i + 2)..count {
+                if i == 0 && j == count - 1 {
+                    continue;
+                }
+                let ((a1, a2), (b1, b2)) = (edge(i), edge(j));
+                if segment_intersection(a1, a2, b1, b2).is_some() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
     /// Sets the stroke color and width.
     pub fn set_stroke(&mut self, color: Color, width: f64) -> &mut Self {
         self.vmobject.set_stroke(color, width);
         self
     }
 
+    /// Sets the stroke color, and the fill color if one is set.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.vmobject.set_color(color);
+        self
+    }
+
     /// Sets the fill color.
     pub fn set_fill(&mut self, color: Color) -> &mut Self {
         self.vmobject.set_fill(color);
         self
     }
 
+    /// Returns a copy of the polygon with every edge moved along its normal.
+    ///
+    /// Positive distances grow the polygon outward and negative distances
+    /// shrink it inward, regardless of the vertex winding order. Each new
+    /// vertex is the intersection of its two adjacent offset edges (a miter
+    /// join). Styling is preserved.
+    ///
+    /// Large inward offsets can collapse or self-intersect the outline; such
+    /// results are returned as-is rather than cleaned up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Polygon;
+    ///
+    /// let square = Polygon::new(vec![
+    ///     Vector2D::new(-0.5, -0.5),
+    ///     Vector2D::new(0.5, -0.5),
+    ///     Vector2D::new(0.5, 0.5),
+    ///     Vector2D::new(-0.5, 0.5),
+    /// ]);
+    /// let border = square.offset(0.5);
+    /// assert!((border.vertices()[0].x + 1.0).abs() < 1e-10);
+    /// ```
+    pub fn offset(&self, distance: f64) -> Polygon {
+        let count = self.vertices.len();
+        let mut result = self.clone();
+        if count < 3 {
+            return result;
+        }
+
+        // Outward normals point right of the edge direction for CCW polygons
+        let signed_area: f64 = (0..count)
+            .map(|i| self.vertices[i].cross(self.vertices[(i + 1) % count]))
+            .sum();
+        let orientation = if signed_area < 0.0 { -1.0 } else { 1.0 };
+        let outward = |from: Vector2D, to: Vector2D| -> Vector2D {
+            let dir = to - from;
+            Vector2D::new(dir.y, -dir.x)
+                .normalize()
+                .map_or(Vector2D::ZERO, |n| n * orientation)
+        };
+
+        result.vertices = (0..count)
+            .map(|i| {
+                let prev = self.vertices[(i + count - 1) % count];
+                let current = self.vertices[i];
+                let next = self.vertices[(i + 1) % count];
+
+                let n1 = outward(prev, current);
+                let n2 = outward(current, next);
+                let p1 = current + n1 * distance;
+                let p2 = current + n2 * distance;
+
+                // Parallel or degenerate edges fall back to a plain normal offset
+                line_intersection(p1, current - prev, p2, next - current).unwrap_or(p1)
+            })
+            .collect();
+        *result.vmobject.path_mut() = Self::create_polygon_path(&result.vertices);
+        result
+    }
+
     /// Creates a polygon path from vertices.
     fn create_polygon_path(vertices: &[Vector2D]) -> Path {
         let mut path = Path::new();
@@ -120,6 +333,63 @@ impl Polygon {
     }
 }
 
+/// Returns the convex hull of a set of points as a polygon.
+///
+/// Uses Andrew's monotone chain algorithm. The hull vertices are in
+/// counter-clockwise order starting from the lowest-leftmost point, and
+/// points lying on a hull edge are left out. Non-finite points are ignored.
+/// Inputs with fewer than three distinct points, or with all points on one
+/// line, give a degenerate polygon: a single point or the segment between
+/// the two extreme points.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::Vector2D;
+/// use manim_rs::mobject::geometry::convex_hull;
+///
+/// let hull = convex_hull(&[
+///     Vector2D::new(0.0, 0.0),
+///     Vector2D::new(2.0, 0.0),
+///     Vector2D::new(1.0, 0.5),
+///     Vector2D::new(1.0, 2.0),
+/// ]);
+/// assert_eq!(hull.vertices().len(), 3);
+/// ```
+pub fn convex_hull(points: &[Vector2D]) -> Polygon {
+    let mut sorted: Vec<Vector2D> = points.iter().copied().filter(|p| p.is_finite()).collect();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return Polygon::new(sorted);
+    }
+
+    // Keeps only left turns; collinear points are dropped
+    let turns_left = |hull: &[Vector2D], p: Vector2D| {
+        let n = hull.len();
+        (hull[n - 1] - hull[n - 2]).cross(p - hull[n - 2]) > 0.0
+    };
+
+    let mut hull: Vec<Vector2D> = Vec::with_capacity(sorted.len() + 1);
+    for &p in &sorted {
+        while hull.len() >= 2 && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    let lower_len = hull.len() + 1;
+    for &p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // The last point repeats the first
+    hull.pop();
+
+    Polygon::new(hull)
+}
+
 impl Mobject for Polygon {
     fn render(&self, renderer: &mut dyn Renderer) -> Result<()> {
         self.vmobject.render(renderer)
@@ -129,6 +399,14 @@ impl Mobject for Polygon {
         self.vmobject.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.vertices.clone()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.vmobject.apply_default_style(style);
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.vmobject.apply_transform(transform);
         for vertex in &mut self.vertices {
@@ -141,7 +419,8 @@ impl Mobject for Polygon {
     }
 
     fn set_position(&mut self, pos: Vector2D) {
-        self.vmobject.set_position(pos);
+        let delta = pos - self.position();
+        self.apply_transform(&Transform::translate(delta.x, delta.y));
     }
 
     fn opacity(&self) -> f64 {
@@ -152,6 +431,14 @@ impl Mobject for Polygon {
         self.vmobject.set_opacity(opacity);
     }
 
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.vmobject)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.vmobject)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
@@ -318,4 +605,247 @@ mod tests {
 
         assert_eq!(polygon.vertices().len(), 5);
     }
+
+    fn unit_square() -> Polygon {
+        Polygon::new(vec![
+            Vector2D::new(-0.5, -0.5),
+            Vector2D::new(0.5, -0.5),
+            Vector2D::new(0.5, 0.5),
+            Vector2D::new(-0.5, 0.5),
+        ])
+    }
+
+    #[test]
+    fn test_polygon_offset_outward() {
+        let offset = unit_square().offset(0.5);
+        let bbox = offset.vmobject.path().bounding_box();
+
+        assert_eq!(offset.vertices().len(), 4);
+        assert_relative_eq!(bbox.width(), 2.0, epsilon = 1e-10);
+        assert_relative_eq!(bbox.height(), 2.0, epsilon = 1e-10);
+        assert_relative_eq!(offset.vertices()[2].x, 1.0, epsilon = 1e-10);
+        assert_relative_eq!(offset.vertices()[2].y, 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_polygon_offset_clockwise_is_outward() {
+        let mut vertices = unit_square().vertices().to_vec();
+        vertices.reverse();
+        let offset = Polygon::new(vertices).offset(0.5);
+        let bbox = offset.vmobject.path().bounding_box();
+
+        assert_relative_eq!(bbox.width(), 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_polygon_offset_inward_collapses() {
+        let offset = unit_square().offset(-0.5);
+
+        assert_eq!(offset.vertices().len(), 4);
+        for vertex in offset.vertices() {
+            assert!(vertex.magnitude() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_polygon_offset_degenerate_inputs() {
+        let large_inward = unit_square().offset(-5.0);
+        assert_eq!(large_inward.vertices().len(), 4);
+
+        // Repeated vertices and collinear edges must not panic
+        let collinear = Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(2.0, 0.0),
+        ]);
+        let result = collinear.offset(1.0);
+        assert!(result.vertices().iter().all(|v| v.x.is_finite()));
+
+        assert!(Polygon::new(vec![]).offset(1.0).vertices().is_empty());
+    }
+
+    #[test]
+    fn test_polygon_flip_horizontal() {
+        let vertices = vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(3.0, 0.0),
+            Vector2D::new(1.0, 2.0),
+        ];
+        let mut polygon = Polygon::new(vertices.clone());
+        let center_x = polygon.bounding_box().center().x;
+
+        polygon.flip_horizontal();
+
+        for (flipped, original) in polygon.vertices().iter().zip(&vertices) {
+            assert_relative_eq!(flipped.x - center_x, center_x - original.x, epsilon = 1e-10);
+            assert_relative_eq!(flipped.y, original.y, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_polygon_flip_twice_restores() {
+        let vertices = vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(3.0, 0.0),
+            Vector2D::new(1.0, 2.0),
+        ];
+        let mut polygon = Polygon::new(vertices.clone());
+
+        polygon.flip_vertical();
+        polygon.flip_vertical();
+        polygon.flip(Vector2D::new(1.0, 1.0));
+        polygon.flip(Vector2D::new(1.0, 1.0));
+
+        for (restored, original) in polygon.vertices().iter().zip(&vertices) {
+            assert_relative_eq!(restored.x, original.x, epsilon = 1e-10);
+            assert_relative_eq!(restored.y, original.y, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_polygon_contains_point() {
+        let triangle = Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 0.0),
+            Vector2D::new(0.0, 4.0),
+        ]);
+
+        assert!(triangle.contains_point(Vector2D::new(1.0, 1.0)));
+        assert!(triangle.contains_point(Vector2D::new(1.99, 1.99)));
+        assert!(!triangle.contains_point(Vector2D::new(2.01, 2.01)));
+        assert!(!triangle.contains_point(Vector2D::new(-0.01, 1.0)));
+
+        // Edges and vertices are inclusive
+        assert!(triangle.contains_point(Vector2D::new(2.0, 2.0)));
+        assert!(triangle.contains_point(Vector2D::new(0.0, 2.0)));
+        assert!(triangle.contains_point(Vector2D::new(4.0, 0.0)));
+        assert!(!triangle.contains_point(Vector2D::new(5.0, 0.0)));
+    }
+
+    #[test]
+    fn test_polygon_set_position_moves_vertices() {
+        let mut square = Polygon::regular(4, 1.0);
+        square.set_position(Vector2D::new(10.0, 0.0));
+
+        assert_eq!(square.position(), Vector2D::new(10.0, 0.0));
+        assert!(square.contains_point(Vector2D::new(10.0, 0.0)));
+        assert!(!square.contains_point(Vector2D::ZERO));
+    }
+
+    #[test]
+    fn test_polygon_get_points() {
+        let vertices = vec![
+            Vector2D::new(0.0, 1.0),
+            Vector2D::new(1.0, -1.0),
+            Vector2D::new(-1.0, -1.0),
+        ];
+        let mut polygon = Polygon::new(vertices.clone());
+        assert_eq!(polygon.get_points(), vertices);
+
+        polygon.apply_transform(&Transform::translate(1.0, 0.0));
+        assert_eq!(polygon.get_points()[0], Vector2D::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_convex_hull_drops_interior_points() {
+        let corners = [
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(0.0, 1.0),
+        ];
+        let mut points = vec![Vector2D::new(0.5, 0.5), Vector2D::new(0.5, 0.0)];
+        points.extend(corners.iter().rev());
+
+        let hull = convex_hull(&points);
+        assert_eq!(hull.vertices(), &corners);
+        assert!(Path::polyline(hull.vertices(), true).signed_area() > 0.0);
+    }
+
+    #[test]
+    fn test_convex_hull_degenerate_inputs() {
+        assert!(convex_hull(&[]).vertices().is_empty());
+
+        let point = Vector2D::new(1.0, 2.0);
+        assert_eq!(convex_hull(&[point, point]).vertices(), &[point]);
+
+        let collinear: Vec<Vector2D> = (0..5).map(|i| Vector2D::new(i as f64, i as f64)).collect();
+        assert_eq!(
+            convex_hull(&collinear).vertices(),
+            &[Vector2D::new(0.0, 0.0), Vector2D::new(4.0, 4.0)]
+        );
+    }
+
+    fn star(points: usize, outer: f64, inner: f64) -> Polygon {
+        let vertices = (0..2 * points)
+            .map(|i| {
+                let radius = if i % 2 == 0 { outer } else { inner };
+                let angle = PI * i as f64 / points as f64;
+                Vector2D::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect();
+        Polygon::new(vertices)
+    }
+
+    #[test]
+    fn test_polygon_convex_and_simple_predicates() {
+        let hexagon = Polygon::regular(6, 1.0);
+        assert!(hexagon.is_convex());
+        assert!(hexagon.is_simple());
+
+        let star = star(5, 2.0, 0.8);
+        assert!(!star.is_convex());
+        assert!(star.is_simple());
+
+        let bowtie = Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(2.0, 2.0),
+            Vector2D::new(2.0, 0.0),
+            Vector2D::new(0.0, 2.0),
+        ]);
+        assert!(!bowtie.is_convex());
+        assert!(!bowtie.is_simple());
+    }
+
+    #[test]
+    fn test_pentagram_is_neither_convex_nor_simple() {
+        // Every turn has the same sign, but the outline winds twice
+        let vertices = (0..5)
+            .map(|i| {
+                let angle = 4.0 * PI * i as f64 / 5.0;
+                Vector2D::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        let pentagram = Polygon::new(vertices);
+        assert!(!pentagram.is_convex());
+        assert!(!pentagram.is_simple());
+
+        // Clockwise order and collinear vertices are still convex
+        let square = Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(0.0, 1.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(1.0, 0.5),
+            Vector2D::new(1.0, 0.0),
+        ]);
+        assert!(square.is_convex());
+        assert!(square.is_simple());
+    }
+
+    #[test]
+    fn test_polygon_equality() {
+        let triangle = || {
+            Polygon::new(vec![
+                Vector2D::new(0.0, 0.0),
+                Vector2D::new(1.0, 0.0),
+                Vector2D::new(0.0, 1.0),
+            ])
+        };
+        assert_eq!(triangle(), triangle());
+
+        let mut moved = triangle();
+        moved.set_position(Vector2D::new(1.0, 1.0));
+        assert_ne!(moved, triangle());
+    }
 }
@@ -4,7 +4,7 @@
 
 use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
 use crate::mobject::{Mobject, VMobject};
-use crate::renderer::{Path, Renderer};
+use crate::renderer::{Path, PathStyle, Renderer};
 
 /// A rectangle mobject.
 ///
@@ -22,7 +22,7 @@ use crate::renderer::{Path, Renderer};
 ///     .stroke_color(Color::BLUE)
 ///     .build();
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Rectangle {
     vmobject: VMobject,
     width: f64,
@@ -87,6 +87,12 @@ impl Rectangle {
         self
     }
 
+    /// Sets the stroke color, and the fill color if one is set.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.vmobject.set_color(color);
+        self
+    }
+
     /// Creates a rectangular path.
     fn create_rectangle_path(width: f64, height: f64) -> Path {
         let mut path = Path::new();
@@ -112,6 +118,14 @@ impl Mobject for Rectangle {
         self.vmobject.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.vmobject.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.vmobject.apply_default_style(style);
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.vmobject.apply_transform(transform);
     }
@@ -132,6 +146,14 @@ impl Mobject for Rectangle {
         self.vmobject.set_opacity(opacity);
     }
 
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.vmobject)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.vmobject)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
@@ -248,7 +270,7 @@ impl Default for RectangleBuilder {
 ///     .fill_color(Color::RED)
 ///     .build();
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Square {
     rectangle: Rectangle,
 }
@@ -296,6 +318,12 @@ impl Square {
         self.rectangle.set_fill(color);
         self
     }
+
+    /// Sets the stroke color, and the fill color if one is set.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.rectangle.set_color(color);
+        self
+    }
 }
 
 impl Mobject for Square {
@@ -307,6 +335,14 @@ impl Mobject for Square {
         self.rectangle.bounding_box()
     }
 
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.rectangle.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.rectangle.apply_default_style(style);
+    }
+
     fn apply_transform(&mut self, transform: &Transform) {
         self.rectangle.apply_transform(transform);
     }
@@ -327,6 +363,14 @@ impl Mobject for Square {
         self.rectangle.set_opacity(opacity);
     }
 
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.rectangle)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.rectangle)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         Box::new(self.clone())
     }
@@ -487,4 +531,18 @@ mod tests {
 
         assert_eq!(square.side_length(), 4.0);
     }
+
+    #[test]
+    fn test_square_applies_default_style() {
+        let theme = PathStyle::stroke(Color::BLACK, 3.0);
+
+        let mut plain = Square::new(1.0);
+        plain.apply_default_style(&theme);
+        assert_eq!(plain.vmobject().unwrap().stroke_color(), Some(Color::BLACK));
+
+        let mut styled = Square::new(1.0);
+        styled.set_stroke(Color::RED, 2.0);
+        styled.apply_default_style(&theme);
+        assert_eq!(styled.vmobject().unwrap().stroke_color(), Some(Color::RED));
+    }
 }
@@ -4,9 +4,9 @@
 //! a single logical unit. Groups support hierarchical transformations where
 //! operations on the group affect all children.
 
-use crate::core::{BoundingBox, Result, Transform, Vector2D};
-use crate::mobject::Mobject;
-use crate::renderer::Renderer;
+use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
+use crate::mobject::{draw_order, Mobject};
+use crate::renderer::{PathStyle, Renderer, TextStyle};
 
 /// A container for multiple mobjects with hierarchical transformation support.
 ///
@@ -30,6 +30,8 @@ pub struct MobjectGroup {
     mobjects: Vec<Box<dyn Mobject>>,
     position: Vector2D,
     opacity: f64,
+    z_index: i32,
+    visible: bool,
 }
 
 impl Clone for MobjectGroup {
@@ -38,6 +40,8 @@ impl Clone for MobjectGroup {
             mobjects: self.mobjects.iter().map(|m| m.clone_mobject()).collect(),
             position: self.position,
             opacity: self.opacity,
+            z_index: self.z_index,
+            visible: self.visible,
         }
     }
 }
@@ -58,6 +62,20 @@ impl Default for MobjectGroup {
     }
 }
 
+impl FromIterator<Box<dyn Mobject>> for MobjectGroup {
+    fn from_iter<I: IntoIterator<Item = Box<dyn Mobject>>>(iter: I) -> Self {
+        let mut group = MobjectGroup::new();
+        group.extend(iter);
+        group
+    }
+}
+
+impl Extend<Box<dyn Mobject>> for MobjectGroup {
+    fn extend<I: IntoIterator<Item = Box<dyn Mobject>>>(&mut self, iter: I) {
+        MobjectGroup::extend(self, iter);
+    }
+}
+
 impl MobjectGroup {
     /// Creates a new empty mobject group.
     ///
@@ -74,6 +92,8 @@ impl MobjectGroup {
             mobjects: Vec::new(),
             position: Vector2D::ZERO,
             opacity: 1.0,
+            z_index: 0,
+            visible: true,
         }
     }
 
@@ -96,6 +116,29 @@ impl MobjectGroup {
         self
     }
 
+    /// Adds every mobject from an iterator to the group.
+    ///
+    /// Returns a mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::{Mobject, MobjectGroup};
+    ///
+    /// let circles: Vec<Box<dyn Mobject>> = (1..=3)
+    ///     .map(|r| Box::new(Circle::new(r as f64)) as Box<dyn Mobject>)
+    ///     .collect();
+    ///
+    /// let mut group = MobjectGroup::new();
+    /// group.extend(circles);
+    /// assert_eq!(group.len(), 3);
+    /// ```
+    pub fn extend<I: IntoIterator<Item = Box<dyn Mobject>>>(&mut self, iter: I) -> &mut Self {
+        self.mobjects.extend(iter);
+        self
+    }
+
     /// Removes and returns the mobject at the specified index.
     ///
     /// Returns `None` if the index is out of bounds.
@@ -206,26 +249,179 @@ impl MobjectGroup {
     pub fn clear(&mut self) {
         self.mobjects.clear();
     }
+
+    /// Reorders the children with a comparison function.
+    ///
+    /// The sort is stable, so children that compare equal keep their
+    /// relative order. Children are drawn in the new order, z-index ties
+    /// included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::{Mobject, MobjectGroup};
+    ///
+    /// let mut group: MobjectGroup = [2.0, -1.0, 0.5]
+    ///     .into_iter()
+    ///     .map(|x| {
+    ///         let mut circle = Circle::new(0.1);
+    ///         circle.set_position(Vector2D::new(x, 0.0));
+    ///         Box::new(circle) as Box<dyn Mobject>
+    ///     })
+    ///     .collect();
+    ///
+    /// // Left to right
+    /// group.sort_by(|a, b| a.position().x.total_cmp(&b.position().x));
+    /// let xs: Vec<f64> = group.iter().map(|m| m.position().x).collect();
+    /// assert_eq!(xs, vec![-1.0, 0.5, 2.0]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&dyn Mobject, &dyn Mobject) -> std::cmp::Ordering,
+    {
+        self.mobjects.sort_by(|a, b| cmp(a.as_ref(), b.as_ref()));
+    }
+
+    /// Splits the group into the children matching `pred` and the rest.
+    ///
+    /// Both groups keep the children's order and copy this group's
+    /// position, opacity, z-index and visibility.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::{Mobject, MobjectGroup};
+    ///
+    /// let group: MobjectGroup = [-2.0, -1.0, 1.0]
+    ///     .into_iter()
+    ///     .map(|x| {
+    ///         let mut circle = Circle::new(0.1);
+    ///         circle.set_position(Vector2D::new(x, 0.0));
+    ///         Box::new(circle) as Box<dyn Mobject>
+    ///     })
+    ///     .collect();
+    ///
+    /// let (left, right) = group.partition(|m| m.position().x < 0.0);
+    /// assert_eq!((left.len(), right.len()), (2, 1));
+    /// ```
+    pub fn partition<F>(self, pred: F) -> (MobjectGroup, MobjectGroup)
+    where
+        F: Fn(&dyn Mobject) -> bool,
+    {
+        let (matching, rest): (Vec<_>, Vec<_>) =
+            self.mobjects.into_iter().partition(|m| pred(m.as_ref()));
+        let with_children = |mobjects| Self {
+            mobjects,
+            position: self.position,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            visible: self.visible,
+        };
+        (with_children(matching), with_children(rest))
+    }
+
+    /// Replaces nested groups with their children, recursively.
+    ///
+    /// The result holds only leaf mobjects, listed in the order they are
+    /// drawn, and keeps this group's position, opacity, z-index and
+    /// visibility. Groups pass moves and opacity changes on to their
+    /// children as they happen, so the leaves already carry them. Each
+    /// leaf's z-index is reset to 0 so that the flat list draws in the
+    /// original order, and leaves of hidden nested groups are hidden.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::{Circle, Square};
+    /// use manim_rs::mobject::{Mobject, MobjectGroup};
+    ///
+    /// let mut inner = MobjectGroup::new();
+    /// inner
+    ///     .add(Box::new(Circle::new(1.0)))
+    ///     .add(Box::new(Square::new(1.0)));
+    ///
+    /// let mut outer = MobjectGroup::new();
+    /// outer.add(Box::new(inner)).add(Box::new(Circle::new(2.0)));
+    ///
+    /// let flat = outer.flattened();
+    /// assert_eq!(flat.len(), 3);
+    /// assert!(flat.iter().all(|m| m.as_group().is_none()));
+    /// ```
+    pub fn flattened(self) -> MobjectGroup {
+        let mut flat = Self {
+            mobjects: Vec::new(),
+            position: self.position,
+            opacity: self.opacity,
+            z_index: self.z_index,
+            visible: self.visible,
+        };
+        let mut children = self.mobjects;
+        children.sort_by_key(|m| m.z_index());
+        for child in children {
+            push_leaves(child, true, &mut flat.mobjects);
+        }
+        flat
+    }
+}
+
+/// Appends the leaves of `mobject` to `out` in draw order.
+///
+/// Children of nested groups are only borrowed through
+/// [`Mobject::as_group`], so they are cloned.
+fn push_leaves(mobject: Box<dyn Mobject>, visible: bool, out: &mut Vec<Box<dyn Mobject>>) {
+    if let Some(group) = mobject.as_group() {
+        let visible = visible && group.visible;
+        let mut children: Vec<&dyn Mobject> = group.iter().collect();
+        children.sort_by_key(|m| m.z_index());
+        for child in children {
+            push_leaves(child.clone_mobject(), visible, out);
+        }
+        return;
+    }
+    let mut leaf = mobject;
+    leaf.set_z_index(0);
+    if !visible {
+        leaf.set_visible(false);
+    }
+    out.push(leaf);
 }
 
 impl Mobject for MobjectGroup {
     fn render(&self, renderer: &mut dyn Renderer) -> Result<()> {
-        for mobject in &self.mobjects {
+        for mobject in draw_order(&self.mobjects) {
             mobject.render(renderer)?;
         }
         Ok(())
     }
 
     fn bounding_box(&self) -> BoundingBox {
-        if self.mobjects.is_empty() {
-            return BoundingBox::zero();
+        BoundingBox::from_bounding_boxes(
+            self.mobjects
+                .iter()
+                .filter(|m| m.is_visible())
+                .map(|m| m.bounding_box()),
+        )
+        .unwrap_or_else(BoundingBox::zero)
+    }
+
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.mobjects.iter().flat_map(|m| m.get_points()).collect()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        for mobject in &mut self.mobjects {
+            mobject.apply_default_style(style);
         }
+    }
 
-        let mut bbox = self.mobjects[0].bounding_box();
-        for mobject in self.mobjects.iter().skip(1) {
-            bbox = bbox.union(&mobject.bounding_box());
+    fn apply_default_text_style(&mut self, style: &TextStyle) {
+        for mobject in &mut self.mobjects {
+            mobject.apply_default_text_style(style);
         }
-        bbox
     }
 
     fn apply_transform(&mut self, transform: &Transform) {
@@ -260,10 +456,62 @@ impl Mobject for MobjectGroup {
         }
     }
 
+    fn describe(&self) -> String {
+        format!(
+            "MobjectGroup(children: {}, position: {}, opacity: {})",
+            self.mobjects.len(),
+            self.position(),
+            self.opacity
+        )
+    }
+
+    fn fade(&mut self, darkness: f64) {
+        let factor = 1.0 - darkness.clamp(0.0, 1.0);
+        self.opacity *= factor;
+        // Fade children individually so their relative opacities survive
+        for mobject in &mut self.mobjects {
+            mobject.fade(darkness);
+        }
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z: i32) {
+        self.z_index = z;
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn set_tint(&mut self, color: Color, amount: f64) {
+        for mobject in &mut self.mobjects {
+            mobject.set_tint(color, amount);
+        }
+    }
+
+    fn set_reveal(&mut self, stroke: f64, fill: f64) {
+        for mobject in &mut self.mobjects {
+            mobject.set_reveal(stroke, fill);
+        }
+    }
+
+    fn as_group(&self) -> Option<&MobjectGroup> {
+        Some(self)
+    }
+
     fn clone_mobject(&self) -> Box<dyn Mobject> {
         let mut group = MobjectGroup::new();
         group.position = self.position;
         group.opacity = self.opacity;
+        group.z_index = self.z_index;
+        group.visible = self.visible;
         for mobject in &self.mobjects {
             group.add(mobject.clone_mobject());
         }
@@ -274,44 +522,25 @@ impl Mobject for MobjectGroup {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backends::{DrawCommand, RecordingRenderer};
     use crate::core::Color;
+    use crate::mobject::geometry::Circle;
     use crate::mobject::VMobject;
-    use crate::renderer::{Path, PathStyle, TextStyle};
+    use crate::renderer::Path;
     use approx::assert_relative_eq;
 
-    struct TestRenderer {
-        render_count: usize,
-    }
-
-    impl TestRenderer {
-        fn new() -> Self {
-            Self { render_count: 0 }
-        }
-    }
-
-    impl Renderer for TestRenderer {
-        fn clear(&mut self, _color: Color) -> Result<()> {
-            Ok(())
-        }
-
-        fn draw_path(&mut self, _path: &Path, _style: &PathStyle) -> Result<()> {
-            self.render_count += 1;
-            Ok(())
-        }
-
-        fn draw_text(
-            &mut self,
-            _text: &str,
-            _position: Vector2D,
-            _style: &TextStyle,
-        ) -> Result<()> {
-            self.render_count += 1;
-            Ok(())
-        }
-
-        fn dimensions(&self) -> (u32, u32) {
-            (800, 600)
-        }
+    /// Renders `mobject` and returns the stroke width of every path drawn.
+    fn stroke_widths(mobject: &dyn Mobject) -> Vec<f64> {
+        let mut renderer = RecordingRenderer::new(800, 600);
+        mobject.render(&mut renderer).unwrap();
+        renderer
+            .commands()
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Path { style, .. } => Some(style.stroke_width),
+                _ => None,
+            })
+            .collect()
     }
 
     #[test]
@@ -409,20 +638,70 @@ mod tests {
             .add(Box::new(VMobject::new(Path::new())))
             .add(Box::new(VMobject::new(Path::new())));
 
-        let mut renderer = TestRenderer::new();
-        group.render(&mut renderer).unwrap();
-
         // Each VMobject calls draw_path once
-        assert_eq!(renderer.render_count, 3);
+        assert_eq!(stroke_widths(&group).len(), 3);
+    }
+
+    #[test]
+    fn test_group_render_sorts_by_z_index() {
+        let mobject = |width: f64, z: i32| {
+            let mut vmobject = VMobject::new(Path::new());
+            vmobject.set_stroke(Color::WHITE, width);
+            vmobject.set_z_index(z);
+            Box::new(vmobject)
+        };
+
+        let mut group = MobjectGroup::new();
+        group
+            .add(mobject(1.0, 2))
+            .add(mobject(2.0, 0))
+            .add(mobject(3.0, -1))
+            .add(mobject(4.0, 0));
+
+        assert_eq!(stroke_widths(&group), vec![3.0, 2.0, 4.0, 1.0]);
+    }
+
+    #[test]
+    fn test_group_clone_keeps_z_index() {
+        let mut group = MobjectGroup::new();
+        group.set_z_index(3);
+
+        assert_eq!(group.clone().z_index(), 3);
+        assert_eq!(group.clone_mobject().z_index(), 3);
+    }
+
+    #[test]
+    fn test_group_extend() {
+        let mut group = MobjectGroup::new();
+        group.add(Box::new(VMobject::new(Path::new())));
+
+        let more: Vec<Box<dyn Mobject>> = (0..3)
+            .map(|_| Box::new(VMobject::new(Path::new())) as Box<dyn Mobject>)
+            .collect();
+        group.extend(more);
+
+        assert_eq!(group.len(), 4);
+    }
+
+    #[test]
+    fn test_group_from_iterator() {
+        let group: MobjectGroup = (0..5)
+            .map(|_| Box::new(VMobject::new(Path::new())) as Box<dyn Mobject>)
+            .collect();
+        assert_eq!(group.len(), 5);
+
+        let mut extended = group.clone();
+        Extend::extend(&mut extended, std::iter::once(group.clone_mobject()));
+        assert_eq!(extended.len(), 6);
     }
 
     #[test]
     fn test_group_render_empty() {
         let group = MobjectGroup::new();
-        let mut renderer = TestRenderer::new();
+        let mut renderer = RecordingRenderer::new(800, 600);
         group.render(&mut renderer).unwrap();
 
-        assert_eq!(renderer.render_count, 0);
+        assert!(renderer.commands().is_empty());
     }
 
     #[test]
@@ -506,6 +785,40 @@ mod tests {
         assert_eq!(group.opacity(), 0.0);
     }
 
+    #[test]
+    fn test_group_fade_preserves_relative_opacity() {
+        let mut dim = VMobject::new(Path::new());
+        dim.set_opacity(0.5);
+
+        let mut group = MobjectGroup::new();
+        group
+            .add(Box::new(VMobject::new(Path::new())))
+            .add(Box::new(dim));
+
+        group.fade(0.5);
+        assert_relative_eq!(group.opacity(), 0.5);
+        let opacities: Vec<f64> = group.iter().map(|m| m.opacity()).collect();
+        assert_eq!(opacities, vec![0.5, 0.25]);
+
+        group.fade(0.5);
+        assert_relative_eq!(group.opacity(), 0.25);
+        let opacities: Vec<f64> = group.iter().map(|m| m.opacity()).collect();
+        assert_eq!(opacities, vec![0.25, 0.125]);
+    }
+
+    #[test]
+    fn test_group_describe() {
+        let mut group = MobjectGroup::new();
+        group
+            .add(Box::new(VMobject::new(Path::new())))
+            .add(Box::new(VMobject::new(Path::new())))
+            .add(Box::new(VMobject::new(Path::new())));
+
+        let description = group.describe();
+        assert!(description.starts_with("MobjectGroup("));
+        assert!(description.contains("children: 3"));
+    }
+
     #[test]
     fn test_group_clone() {
         let mut group = MobjectGroup::new();
@@ -532,4 +845,147 @@ mod tests {
 
         assert_eq!(outer_group.len(), 1);
     }
+
+    #[test]
+    fn test_group_get_points() {
+        let first = VMobject::from_points(&[Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 0.0)]);
+        let second = VMobject::from_points(&[Vector2D::new(2.0, 2.0), Vector2D::new(3.0, 2.0)]);
+
+        let mut group = MobjectGroup::new();
+        group.add(Box::new(first.clone()));
+        group.add(Box::new(second.clone()));
+
+        let mut expected = first.get_points();
+        expected.extend(second.get_points());
+        assert_eq!(group.get_points(), expected);
+        assert_eq!(group.get_points().len(), 4);
+        assert!(MobjectGroup::new().get_points().is_empty());
+    }
+
+    #[test]
+    fn test_group_bounding_box_skips_invisible_children() {
+        let near = VMobject::from_points(&[Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 1.0)]);
+        let mut far =
+            VMobject::from_points(&[Vector2D::new(100.0, 100.0), Vector2D::new(101.0, 101.0)]);
+
+        let mut group = MobjectGroup::new();
+        group.add(Box::new(near.clone()));
+        group.add(Box::new(far.clone()));
+        assert!(group.bounding_box().max().x > 100.0);
+
+        far.set_visible(false);
+        let mut group = MobjectGroup::new();
+        group.add(Box::new(near.clone()));
+        group.add(Box::new(far));
+        assert_eq!(group.bounding_box(), near.bounding_box());
+    }
+
+    #[test]
+    fn test_group_render_skips_invisible_children() {
+        let mut hidden = VMobject::new(Path::new());
+        hidden.set_visible(false);
+
+        let mut group = MobjectGroup::new();
+        group.add(Box::new(VMobject::new(Path::new())));
+        group.add(Box::new(hidden));
+
+        assert_eq!(stroke_widths(&group).len(), 1);
+
+        group.set_visible(false);
+        assert!(!group.clone().is_visible());
+        assert!(!group.clone_mobject().is_visible());
+    }
+
+    fn circles_at(xs: &[f64]) -> MobjectGroup {
+        xs.iter()
+            .map(|&x| {
+                let mut circle = Circle::new(0.5);
+                circle.set_position(Vector2D::new(x, 0.0));
+                Box::new(circle) as Box<dyn Mobject>
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_group_sort_by_x_position() {
+        let mut group = circles_at(&[3.0, -2.0, 1.0, 0.0]);
+        group.sort_by(|a, b| a.position().x.total_cmp(&b.position().x));
+
+        let xs: Vec<f64> = group.iter().map(|m| m.position().x).collect();
+        assert_eq!(xs, vec![-2.0, 0.0, 1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_group_partition_splits_counts() {
+        let mut group = circles_at(&[-3.0, 2.0, -1.0, 4.0, 5.0]);
+        group.set_opacity(0.5);
+
+        let (left, right) = group.partition(|m| m.position().x < 0.0);
+        assert_eq!(left.len(), 2);
+        assert_eq!(right.len(), 3);
+        assert_eq!(left.opacity(), 0.5);
+        let xs: Vec<f64> = right.iter().map(|m| m.position().x).collect();
+        assert_eq!(xs, vec![2.0, 4.0, 5.0]);
+    }
+
+    fn nested_group() -> MobjectGroup {
+        let mut inner = MobjectGroup::new();
+        inner
+            .add(Box::new(Circle::new(1.0)))
+            .add(Box::new(Circle::new(2.0)));
+        let mut middle = MobjectGroup::new();
+        middle
+            .add(Box::new(inner))
+            .add(Box::new(VMobject::new(Path::new())));
+        let mut outer = MobjectGroup::new();
+        outer.add(Box::new(Circle::new(3.0))).add(Box::new(middle));
+        outer
+    }
+
+    #[test]
+    fn test_flattened_two_levels() {
+        let flat = nested_group().flattened();
+        assert_eq!(flat.len(), 4);
+        assert!(flat.iter().all(|m| m.as_group().is_none()));
+
+        // Depth-first, in insertion order
+        let widths: Vec<f64> = flat.iter().map(|m| m.bounding_box().width()).collect();
+        assert!(widths[0] > widths[2] && widths[2] > widths[1]);
+    }
+
+    #[test]
+    fn test_flattened_keeps_group_changes() {
+        let mut group = nested_group();
+        group.set_position(Vector2D::new(5.0, 0.0));
+        group.set_opacity(0.5);
+        let before = group.bounding_box();
+
+        let flat = group.flattened();
+        assert_eq!(flat.position(), Vector2D::new(5.0, 0.0));
+        assert_eq!(flat.bounding_box(), before);
+        assert!(flat.iter().all(|m| m.opacity() == 0.5));
+    }
+
+    #[test]
+    fn test_flattened_follows_draw_order() {
+        let mut back = MobjectGroup::new();
+        back.add(Box::new(Circle::new(1.0)));
+        back.set_z_index(-1);
+        let mut hidden = MobjectGroup::new();
+        hidden.add(Box::new(Circle::new(2.0)));
+        hidden.set_visible(false);
+
+        let mut group = MobjectGroup::new();
+        group
+            .add(Box::new(Circle::new(3.0)))
+            .add(Box::new(back))
+            .add(Box::new(hidden));
+
+        let flat = group.flattened();
+        // The group with z-index -1 comes first
+        let widths: Vec<f64> = flat.iter().map(|m| m.bounding_box().width()).collect();
+        assert!(widths[0] < widths[2] && widths[2] < widths[1]);
+        assert!(flat.iter().all(|m| m.z_index() == 0));
+        assert!(!flat.iter().nth(2).unwrap().is_visible());
+    }
 }
@@ -0,0 +1,367 @@
+//! ImageMobject.
+//!
+//! Places raster pixel data in the scene graph.
+
+use crate::core::{BoundingBox, Error, Result, Transform, Vector2D};
+use crate::mobject::Mobject;
+use crate::renderer::Renderer;
+
+/// A mobject that displays RGBA pixel data.
+///
+/// Images are positioned by their center. At a scale of 1.0 each pixel
+/// covers one scene unit, so the bounding box is `width × height` scaled by
+/// [`scale`](ImageMobject::scale). Transforms are kept in full, so images
+/// can be rotated, sheared and mirrored like any other mobject. Rendering
+/// goes through [`Renderer::draw_image`]; backends that cannot embed images
+/// skip them.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::Vector2D;
+/// use manim_rs::mobject::{ImageMobject, Mobject};
+///
+/// // A 2x1 image: one red and one blue pixel
+/// let pixels = vec![255, 0, 0, 255, 0, 0, 255, 255];
+/// let mut image = ImageMobject::new(pixels, 2, 1);
+/// image.set_scale(10.0);
+/// image.set_position(Vector2D::new(5.0, 0.0));
+///
+/// assert_eq!(image.bounding_box().width(), 20.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ImageMobject {
+    pixels: Vec<u8>,
+    width: u32,
+    height: u32,
+    /// Placement of the `width × height` image centered on the origin.
+    transform: Transform,
+    opacity: f64,
+    z_index: i32,
+    visible: bool,
+}
+
+impl ImageMobject {
+    /// Creates an image from unpremultiplied RGBA pixels, top row first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data.len()` is not `width * height * 4`. Use
+    /// [`try_new`](ImageMobject::try_new) for data from outside the program.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::ImageMobject;
+    ///
+    /// let image = ImageMobject::new(vec![255; 4 * 4 * 4], 4, 4);
+    /// assert_eq!(image.pixel_size(), (4, 4));
+    /// ```
+    pub fn new(data: Vec<u8>, width: u32, height: u32) -> Self {
+        Self::try_new(data, width, height).expect("image data must hold width * height RGBA pixels")
+    }
+
+    /// Creates an image from unpremultiplied RGBA pixels, failing if the
+    /// buffer has the wrong size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if `data.len()` is not
+    /// `width * height * 4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::ImageMobject;
+    ///
+    /// assert!(ImageMobject::try_new(vec![255; 4 * 4 * 4], 4, 4).is_ok());
+    /// assert!(ImageMobject::try_new(vec![255; 5], 4, 4).is_err());
+    /// ```
+    pub fn try_new(data: Vec<u8>, width: u32, height: u32) -> Result<Self> {
+        let expected = width as usize * height as usize * 4;
+        if data.len() != expected {
+            return Err(Error::InvalidArgument(format!(
+                "Image data has {} bytes, expected {}x{} RGBA ({} bytes)",
+                data.len(),
+                width,
+                height,
+                expected
+            )));
+        }
+        Ok(Self {
+            pixels: data,
+            width,
+            height,
+            transform: Transform::identity(),
+            opacity: 1.0,
+            z_index: 0,
+            visible: true,
+        })
+    }
+
+    /// Loads an image from a PNG file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use manim_rs::mobject::ImageMobject;
+    ///
+    /// # fn main() -> manim_rs::core::Result<()> {
+    /// let logo = ImageMobject::from_png_file("assets/logo.png")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "raster")]
+    pub fn from_png_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        use crate::core::Error;
+
+        let image = image::open(path).map_err(|e| match e {
+            image::ImageError::IoError(io) => Error::Io(io),
+            other => Error::Render(format!("Failed to decode image: {}", other)),
+        })?;
+        let rgba = image.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        Self::try_new(rgba.into_raw(), width, height)
+    }
+
+    /// Returns the image size in pixels as `(width, height)`.
+    pub fn pixel_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the raw RGBA pixel data.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Returns the horizontal and vertical scale factors.
+    ///
+    /// These are the lengths of the image's transformed axes, so they stay
+    /// positive under rotation and reflection.
+    pub fn scale(&self) -> Vector2D {
+        let t = &self.transform;
+        Vector2D::new(t.a.hypot(t.b), t.c.hypot(t.d))
+    }
+
+    /// Sets a uniform scale factor, keeping the center, rotation and
+    /// reflection fixed.
+    pub fn set_scale(&mut self, scale: f64) -> &mut Self {
+        let current = self.scale();
+        let t = &mut self.transform;
+        if current.x > 0.0 {
+            t.a *= scale / current.x;
+            t.b *= scale / current.x;
+        } else {
+            t.a = scale;
+            t.b = 0.0;
+        }
+        if current.y > 0.0 {
+            t.c *= scale / current.y;
+            t.d *= scale / current.y;
+        } else {
+            t.c = 0.0;
+            t.d = scale;
+        }
+        self
+    }
+
+    /// Returns the transform placing the image, centered on the origin at
+    /// one scene unit per pixel, in the scene.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Transform;
+    /// use manim_rs::mobject::{ImageMobject, Mobject};
+    ///
+    /// let mut image = ImageMobject::new(vec![255; 4], 1, 1);
+    /// image.apply_transform(&Transform::rotate(std::f64::consts::FRAC_PI_2));
+    /// assert!((image.transform().b - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    /// Maps the unit square expected by [`Renderer::draw_image`] onto the
+    /// image's placement in the scene.
+    fn placement(&self) -> Transform {
+        let (w, h) = (self.width as f64, self.height as f64);
+        self.transform * Transform::translate(-w / 2.0, -h / 2.0) * Transform::scale(w, h)
+    }
+}
+
+impl Mobject for ImageMobject {
+    fn render(&self, renderer: &mut dyn Renderer) -> Result<()> {
+        renderer.draw_image(
+            &self.pixels,
+            self.width,
+            self.height,
+            &self.placement(),
+            self.opacity,
+        )
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        let half = Vector2D::new(self.width as f64, self.height as f64) / 2.0;
+        BoundingBox::new(-half, half).transform(&self.transform)
+    }
+
+    fn apply_transform(&mut self, transform: &Transform) {
+        self.transform = *transform * self.transform;
+    }
+
+    fn position(&self) -> Vector2D {
+        Vector2D::new(self.transform.tx, self.transform.ty)
+    }
+
+    fn set_position(&mut self, pos: Vector2D) {
+        self.transform.tx = pos.x;
+        self.transform.ty = pos.y;
+    }
+
+    fn opacity(&self) -> f64 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f64) {
+        self.opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z: i32) {
+        self.z_index = z;
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn clone_mobject(&self) -> Box<dyn Mobject> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::{DrawCommand, RecordingRenderer};
+
+    /// Renders `image` and returns the transform of its image draw.
+    fn placement_of(image: &ImageMobject) -> Transform {
+        let mut renderer = RecordingRenderer::new(800, 600);
+        image.render(&mut renderer).unwrap();
+        match renderer.commands() {
+            [DrawCommand::Image { transform, .. }] => *transform,
+            commands => panic!("expected a single image draw, got {commands:?}"),
+        }
+    }
+
+    fn checker() -> ImageMobject {
+        ImageMobject::new([0, 0, 0, 255, 255, 255, 255, 255].repeat(2), 2, 2)
+    }
+
+    #[test]
+    fn test_image_issues_one_draw_image() {
+        let mut image = checker();
+        image.set_opacity(0.5);
+        let mut renderer = RecordingRenderer::new(800, 600);
+
+        image.render(&mut renderer).unwrap();
+
+        let [DrawCommand::Image {
+            width,
+            height,
+            transform,
+            opacity,
+            ..
+        }] = renderer.commands()
+        else {
+            panic!("expected a single image draw");
+        };
+        assert_eq!((*width, *height), (2, 2));
+        let unit = BoundingBox::new(Vector2D::ZERO, Vector2D::new(1.0, 1.0));
+        assert_eq!(unit.transform(transform), image.bounding_box());
+        assert_eq!(*opacity, 0.5);
+    }
+
+    #[test]
+    fn test_image_scale_changes_bounding_box() {
+        let mut image = checker();
+        assert_eq!(image.bounding_box().width(), 2.0);
+
+        image.set_scale(3.0);
+        let bbox = image.bounding_box();
+        assert_eq!(bbox.width(), 6.0);
+        assert_eq!(bbox.height(), 6.0);
+        assert_eq!(bbox.center(), Vector2D::ZERO);
+
+        image.apply_transform(&Transform::scale(2.0, 1.0));
+        assert_eq!(image.bounding_box().width(), 12.0);
+        assert_eq!(image.bounding_box().height(), 6.0);
+    }
+
+    #[test]
+    fn test_image_position() {
+        let mut image = checker();
+        image.set_position(Vector2D::new(10.0, -5.0));
+        assert_eq!(image.bounding_box().center(), Vector2D::new(10.0, -5.0));
+
+        image.apply_transform(&Transform::translate(1.0, 1.0));
+        assert_eq!(image.position(), Vector2D::new(11.0, -4.0));
+    }
+
+    #[test]
+    fn test_image_rotation_is_kept() {
+        // 4x2 image rotated a quarter turn stands 2 wide and 4 tall
+        let mut image = ImageMobject::new(vec![255; 4 * 2 * 4], 4, 2);
+        image.apply_transform(&Transform::rotate(std::f64::consts::FRAC_PI_2));
+
+        let bbox = image.bounding_box();
+        assert!((bbox.width() - 2.0).abs() < 1e-9);
+        assert!((bbox.height() - 4.0).abs() < 1e-9);
+        assert_eq!(image.scale(), Vector2D::new(1.0, 1.0));
+
+        let placement = placement_of(&image);
+        // The first pixel's corner, top-left before the turn, ends up at the
+        // bottom-left of the rotated image
+        let top_left = placement.apply(Vector2D::new(0.0, 1.0));
+        assert!((top_left - Vector2D::new(-1.0, -2.0)).magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn test_image_mirror_is_kept() {
+        let mut image = checker();
+        image.apply_transform(&Transform::scale(-1.0, 1.0));
+        assert!(image.transform().determinant() < 0.0);
+
+        image.set_scale(2.0);
+        assert_eq!(image.transform().a, -2.0);
+        assert_eq!(image.bounding_box().width(), 4.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_image_rejects_wrong_length() {
+        ImageMobject::new(vec![0; 5], 1, 1);
+    }
+
+    #[test]
+    fn test_image_try_new_rejects_wrong_length() {
+        assert!(matches!(
+            ImageMobject::try_new(vec![0; 5], 1, 1),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(ImageMobject::try_new(vec![0; 4], 1, 1).is_ok());
+    }
+}
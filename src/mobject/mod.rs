@@ -4,6 +4,9 @@
 //! - [`Mobject`] - The fundamental trait for all drawable objects
 //! - [`VMobject`] - Vector-based mobject implementation
 //! - [`MobjectGroup`] - Container for hierarchical object composition
+//! - [`ImageMobject`] - Raster image placed in the scene
+//! - [`Text`] - A single line of text
+//! - [`Table`] - Text entries laid out in a grid
 //!
 //! # Overview
 //!
@@ -22,16 +25,24 @@
 //! // See VMobject and geometry submodules for concrete implementations
 //! ```
 
-use crate::core::{BoundingBox, Result, Transform, Vector2D};
-use crate::renderer::Renderer;
+use std::any::Any;
+
+use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
+use crate::renderer::{PathStyle, Renderer, TextStyle};
 
 mod bezier_path;
 pub mod geometry;
 mod group;
+mod image;
+mod table;
+mod text;
 mod vmobject;
 
 pub use bezier_path::BezierPath;
 pub use group::MobjectGroup;
+pub use image::ImageMobject;
+pub use table::{Table, TableBuilder};
+pub use text::Text;
 pub use vmobject::VMobject;
 
 /// Core trait for all mathematical objects that can be rendered and animated.
@@ -171,11 +182,152 @@ pub trait Mobject: Send + Sync {
     /// ```
     fn set_opacity(&mut self, opacity: f64);
 
+    /// Dims the mobject by multiplying its opacity by `1.0 - darkness`.
+    ///
+    /// `darkness` is clamped to [0.0, 1.0]. Unlike [`Mobject::set_opacity`],
+    /// repeated fades compose: fading twice by 0.5 leaves a quarter of the
+    /// original opacity. [`VMobject`] keeps the opacity it was given as a
+    /// base and scales it by the fade, so [`VMobject::clear_fade`] restores
+    /// it exactly. The default forwards to [`inner_mut`](Mobject::inner_mut),
+    /// or scales the opacity in place without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::{Mobject, VMobject};
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut mobject = VMobject::new(Path::new());
+    /// mobject.fade(0.5);
+    /// mobject.fade(0.5);
+    /// assert_eq!(mobject.opacity(), 0.25);
+    /// ```
+    fn fade(&mut self, darkness: f64) {
+        match self.inner_mut() {
+            Some(inner) => inner.fade(darkness),
+            None => {
+                let factor = 1.0 - darkness.clamp(0.0, 1.0);
+                self.set_opacity(self.opacity() * factor);
+            }
+        }
+    }
+
+    /// Applies a default style to a mobject that has not been styled yet.
+    ///
+    /// [`Scene`](crate::scene::Scene) calls this with its theme's path style
+    /// when a mobject is added. Vector mobjects whose stroke and fill were
+    /// never set to anything but the defaults, including shapes from
+    /// builders left at their default style, adopt the style's stroke color,
+    /// stroke width, and fill color; styled mobjects keep their own. The
+    /// default ignores the style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::{Mobject, VMobject};
+    /// use manim_rs::renderer::{Path, PathStyle};
+    ///
+    /// let theme = PathStyle::stroke(Color::BLACK, 3.0);
+    ///
+    /// let mut plain = VMobject::new(Path::new());
+    /// plain.apply_default_style(&theme);
+    /// assert_eq!(plain.stroke_color(), Some(Color::BLACK));
+    ///
+    /// let mut styled = VMobject::new(Path::new());
+    /// styled.set_stroke(Color::RED, 2.0);
+    /// styled.apply_default_style(&theme);
+    /// assert_eq!(styled.stroke_color(), Some(Color::RED));
+    /// ```
+    fn apply_default_style(&mut self, _style: &PathStyle) {}
+
+    /// Applies a default text style to text that has not been styled yet.
+    ///
+    /// [`Scene`](crate::scene::Scene) calls this with its theme's text style
+    /// when a mobject is added, alongside
+    /// [`Mobject::apply_default_style`]. Text created with
+    /// [`TextStyle::default`] and never restyled adopts the whole style;
+    /// other text keeps its own. The default ignores the style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::{Mobject, Text};
+    /// use manim_rs::renderer::TextStyle;
+    ///
+    /// let theme = TextStyle::new(Color::BLACK, 32.0);
+    ///
+    /// let mut plain = Text::new("a", TextStyle::default());
+    /// plain.apply_default_text_style(&theme);
+    /// assert_eq!(plain.style().color, Color::BLACK);
+    ///
+    /// let mut styled = Text::new("b", TextStyle::new(Color::RED, 24.0));
+    /// styled.apply_default_text_style(&theme);
+    /// assert_eq!(styled.style().color, Color::RED);
+    /// ```
+    fn apply_default_text_style(&mut self, _style: &TextStyle) {}
+
+    /// Returns the anchor points of the mobject's geometry.
+    ///
+    /// Vector mobjects return the anchors of their paths in drawing order,
+    /// without curve control points, and groups concatenate the points of
+    /// their children. The default returns the four bounding box corners,
+    /// counter-clockwise from the minimum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Polygon;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let triangle = Polygon::regular(3, 1.0);
+    /// assert_eq!(triangle.get_points().len(), 3);
+    /// ```
+    fn get_points(&self) -> Vec<Vector2D> {
+        let bbox = self.bounding_box();
+        let (min, max) = (bbox.min(), bbox.max());
+        vec![
+            min,
+            Vector2D::new(max.x, min.y),
+            max,
+            Vector2D::new(min.x, max.y),
+        ]
+    }
+
+    /// Returns a short human-readable summary of the mobject for debugging.
+    ///
+    /// The default reports the position, opacity, and bounding box.
+    /// Implementations override it to add type-specific details, such as a
+    /// circle's radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let circle: Box<dyn Mobject> = Box::new(Circle::new(2.0));
+    /// assert!(circle.describe().contains("radius: 2"));
+    /// ```
+    fn describe(&self) -> String {
+        format!(
+            "Mobject(position: {}, opacity: {}, bounds: {})",
+            self.position(),
+            self.opacity(),
+            self.bounding_box()
+        )
+    }
+
     /// Creates a boxed clone of the mobject.
     ///
     /// This method enables cloning through trait objects. Implementations
     /// should return `Box::new(self.clone())`.
     ///
+    /// The returned box erases the concrete type. When you hold a concrete
+    /// mobject such as a `Circle`, call `clone()` instead: every built-in
+    /// mobject implements [`Clone`], and the copy keeps its full concrete API.
+    ///
     /// # Examples
     ///
     /// ```
@@ -186,13 +338,351 @@ pub trait Mobject: Send + Sync {
     /// let cloned = mobject.clone_mobject();
     /// ```
     fn clone_mobject(&self) -> Box<dyn Mobject>;
+
+    /// Returns the mobject this one is built around, if any.
+    ///
+    /// Shapes such as [`Circle`](geometry::Circle) keep their path and style
+    /// in an inner [`VMobject`]. Returning it here lets the default
+    /// implementations of the layering and appearance methods below forward
+    /// to it, so a wrapper only overrides what it changes. The default is
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::{Mobject, VMobject};
+    /// use manim_rs::renderer::Path;
+    ///
+    /// assert!(Circle::new(1.0).inner().is_some());
+    /// assert!(VMobject::new(Path::new()).inner().is_none());
+    /// ```
+    fn inner(&self) -> Option<&dyn Mobject> {
+        None
+    }
+
+    /// Mutable counterpart of [`inner`](Mobject::inner).
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        None
+    }
+
+    /// Returns the [`VMobject`] holding this mobject's path and style, if
+    /// any.
+    ///
+    /// A [`VMobject`] returns itself; the default forwards to
+    /// [`inner`](Mobject::inner), so shapes built around one return it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::geometry::Square;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut square = Square::new(1.0);
+    /// square.set_stroke(Color::RED, 2.0);
+    ///
+    /// let vmobject = square.vmobject().unwrap();
+    /// assert_eq!(vmobject.stroke_color(), Some(Color::RED));
+    /// ```
+    fn vmobject(&self) -> Option<&VMobject> {
+        self.inner()?.vmobject()
+    }
+
+    /// Copies the stroke, fill and opacity of `other`, see
+    /// [`VMobject::match_style`].
+    ///
+    /// The default forwards to [`inner_mut`](Mobject::inner_mut), or ignores
+    /// the style without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::geometry::{Circle, Square};
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut square = Square::new(1.0);
+    /// square.set_stroke(Color::RED, 2.0);
+    ///
+    /// let mut circle = Circle::new(1.0);
+    /// circle.match_style(square.vmobject().unwrap());
+    /// assert_eq!(circle.vmobject().unwrap().stroke_width(), 2.0);
+    /// ```
+    fn match_style(&mut self, other: &VMobject) {
+        if let Some(inner) = self.inner_mut() {
+            inner.match_style(other);
+        }
+    }
+
+    /// Returns the draw-order index of the mobject.
+    ///
+    /// Containers draw children with lower indices first, so higher indices
+    /// appear on top. Mobjects with equal indices keep their insertion order.
+    /// The default forwards to [`inner`](Mobject::inner), or is 0 without one.
+    fn z_index(&self) -> i32 {
+        self.inner().map_or(0, |inner| inner.z_index())
+    }
+
+    /// Sets the draw-order index of the mobject.
+    ///
+    /// The default forwards to [`inner_mut`](Mobject::inner_mut), or ignores
+    /// the value without one; mobjects that support layering on their own
+    /// store it and return it from [`z_index`](Mobject::z_index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut circle = Circle::new(1.0);
+    /// circle.set_z_index(5);
+    /// assert_eq!(circle.z_index(), 5);
+    /// ```
+    fn set_z_index(&mut self, z: i32) {
+        if let Some(inner) = self.inner_mut() {
+            inner.set_z_index(z);
+        }
+    }
+
+    /// Tints the drawn colors towards `color` by `amount` in [0, 1].
+    ///
+    /// The tint is applied when rendering, so the mobject's own colors are
+    /// kept: an amount of 0 removes the tint and restores them exactly.
+    /// Animations use it for temporary highlights. The default forwards to
+    /// [`inner_mut`](Mobject::inner_mut), or ignores the tint without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut circle = Circle::new(1.0);
+    /// circle.set_tint(Color::YELLOW, 0.5);
+    /// circle.set_tint(Color::YELLOW, 0.0);
+    /// ```
+    fn set_tint(&mut self, color: Color, amount: f64) {
+        if let Some(inner) = self.inner_mut() {
+            inner.set_tint(color, amount);
+        }
+    }
+
+    /// Draws only part of the mobject, as if it were still being drawn.
+    ///
+    /// `stroke` is the fraction of the outline to trace and `fill` scales
+    /// the fill opacity; both are clamped to [0, 1], and (1, 1) shows the
+    /// mobject in full. Like [`set_tint`](Mobject::set_tint) this only
+    /// affects rendering, so the mobject's own style is kept. Text reveals
+    /// whole characters in proportion to `stroke`. The default forwards to
+    /// [`inner_mut`](Mobject::inner_mut), or ignores the values without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut circle = Circle::new(1.0);
+    /// circle.set_reveal(0.5, 0.0);
+    /// circle.set_reveal(1.0, 1.0);
+    /// ```
+    fn set_reveal(&mut self, stroke: f64, fill: f64) {
+        if let Some(inner) = self.inner_mut() {
+            inner.set_reveal(stroke, fill);
+        }
+    }
+
+    /// Returns `true` if the mobject should be drawn and laid out.
+    ///
+    /// Containers and scenes skip invisible children when rendering, and
+    /// groups leave them out of their bounding box. The default forwards to
+    /// [`inner`](Mobject::inner), or is `true` without one.
+    fn is_visible(&self) -> bool {
+        self.inner().is_none_or(|inner| inner.is_visible())
+    }
+
+    /// Shows or hides the mobject without changing its opacity.
+    ///
+    /// The default forwards to [`inner_mut`](Mobject::inner_mut), or ignores
+    /// the value without one; mobjects that support hiding on their own
+    /// store it and return it from [`is_visible`](Mobject::is_visible).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut circle = Circle::new(1.0);
+    /// circle.set_visible(false);
+    /// assert!(!circle.is_visible());
+    /// ```
+    fn set_visible(&mut self, visible: bool) {
+        if let Some(inner) = self.inner_mut() {
+            inner.set_visible(visible);
+        }
+    }
+
+    /// Returns the mobject as a [`MobjectGroup`] if it is one.
+    ///
+    /// This lets code holding a `dyn Mobject` descend into nested groups,
+    /// as [`MobjectGroup::flattened`] does. The default is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::{Mobject, MobjectGroup};
+    ///
+    /// assert!(Circle::new(1.0).as_group().is_none());
+    /// assert!(MobjectGroup::new().as_group().is_some());
+    /// ```
+    fn as_group(&self) -> Option<&MobjectGroup> {
+        None
+    }
+
+    /// Returns the mobject as [`Any`] for downcasting to its concrete type,
+    /// if the type allows it.
+    ///
+    /// This is an opt-in hook rather than a supertrait so that mobjects
+    /// need not be `'static`; types that opt in return `Some(self)`, as
+    /// [`Text`] does so tables can hand out their cells. The default is
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::{Mobject, Text};
+    /// use manim_rs::renderer::TextStyle;
+    ///
+    /// let text: Box<dyn Mobject> = Box::new(Text::new("a", TextStyle::default()));
+    /// let text = text.as_any().and_then(|any| any.downcast_ref::<Text>());
+    /// assert_eq!(text.unwrap().text(), "a");
+    ///
+    /// assert!(Circle::new(1.0).as_any().is_none());
+    /// ```
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+
+    /// Mirrors the mobject across a line through its center.
+    ///
+    /// The mirror line runs along `axis` through the center of the bounding
+    /// box, so the object stays in place. Flipping twice restores the
+    /// original geometry up to floating point rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Polygon;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut triangle = Polygon::new(vec![
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(2.0, 0.0),
+    ///     Vector2D::new(0.0, 1.0),
+    /// ]);
+    /// // Mirror across the horizontal line through the center
+    /// triangle.flip(Vector2D::new(1.0, 0.0));
+    /// assert_eq!(triangle.vertices()[2], Vector2D::new(0.0, 0.0));
+    /// ```
+    fn flip(&mut self, axis: Vector2D) {
+        let center = self.bounding_box().center();
+        self.apply_transform(&Transform::reflect_about(axis, center));
+    }
+
+    /// Mirrors the mobject left-to-right about its center.
+    fn flip_horizontal(&mut self) {
+        self.flip(Vector2D::new(0.0, 1.0));
+    }
+
+    /// Mirrors the mobject top-to-bottom about its center.
+    fn flip_vertical(&mut self) {
+        self.flip(Vector2D::new(1.0, 0.0));
+    }
+
+    /// Moves the mobject so its position lies on a grid of `spacing`.
+    ///
+    /// Each coordinate of [`position`](Mobject::position) is rounded to the
+    /// nearest multiple of `spacing`, as in [`Vector2D::snap_to_grid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let mut circle = Circle::new(1.0);
+    /// circle.set_position(Vector2D::new(0.9, -2.2));
+    /// circle.snap_to_grid(1.0);
+    /// assert_eq!(circle.position(), Vector2D::new(1.0, -2.0));
+    /// ```
+    fn snap_to_grid(&mut self, spacing: f64) {
+        let snapped = self.position().snap_to_grid(spacing);
+        self.set_position(snapped);
+    }
+
+    /// Boxes the mobject as a trait object.
+    ///
+    /// This is a convenience for adding concrete mobjects to heterogeneous
+    /// collections such as scenes and groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    ///
+    /// let circle = Circle::new(1.0);
+    /// let copy = circle.clone();
+    /// assert_eq!(copy.radius(), 1.0);
+    ///
+    /// let boxed: Box<dyn Mobject> = copy.into_boxed();
+    /// ```
+    fn into_boxed(self) -> Box<dyn Mobject>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+/// Stores a tint as passed to [`Mobject::set_tint`], dropping empty tints.
+pub(crate) fn make_tint(color: Color, amount: f64) -> Option<(Color, f64)> {
+    let amount = amount.clamp(0.0, 1.0);
+    (amount > 0.0).then_some((color, amount))
+}
+
+/// Applies a stored tint to a color, keeping the color's own alpha.
+pub(crate) fn apply_tint(color: Color, tint: Option<(Color, f64)>) -> Color {
+    match tint {
+        Some((target, amount)) => color.lerp(target, amount).with_alpha(color.a),
+        None => color,
+    }
+}
+
+/// Returns the visible mobjects in draw order: stably sorted by ascending
+/// z-index.
+pub(crate) fn draw_order(mobjects: &[Box<dyn Mobject>]) -> Vec<&dyn Mobject> {
+    let mut ordered: Vec<&dyn Mobject> = mobjects
+        .iter()
+        .map(|m| m.as_ref())
+        .filter(|m| m.is_visible())
+        .collect();
+    ordered.sort_by_key(|m| m.z_index());
+    ordered
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::Color;
-    use crate::renderer::{Path, PathStyle, TextStyle};
+    use crate::backends::RecordingRenderer;
 
     /// Mock mobject for testing the trait interface
     #[derive(Clone)]
@@ -244,42 +734,6 @@ mod tests {
         }
     }
 
-    /// Test renderer for checking render calls
-    struct TestRenderer {
-        render_count: usize,
-    }
-
-    impl TestRenderer {
-        fn new() -> Self {
-            Self { render_count: 0 }
-        }
-    }
-
-    impl Renderer for TestRenderer {
-        fn clear(&mut self, _color: Color) -> Result<()> {
-            Ok(())
-        }
-
-        fn draw_path(&mut self, _path: &Path, _style: &PathStyle) -> Result<()> {
-            self.render_count += 1;
-            Ok(())
-        }
-
-        fn draw_text(
-            &mut self,
-            _text: &str,
-            _position: Vector2D,
-            _style: &TextStyle,
-        ) -> Result<()> {
-            self.render_count += 1;
-            Ok(())
-        }
-
-        fn dimensions(&self) -> (u32, u32) {
-            (800, 600)
-        }
-    }
-
     #[test]
     fn test_mobject_trait_is_object_safe() {
         // This test verifies that we can create trait objects
@@ -334,11 +788,11 @@ mod tests {
     #[test]
     fn test_mobject_render() {
         let mobject = MockMobject::new();
-        let mut renderer = TestRenderer::new();
+        let mut renderer = RecordingRenderer::new(800, 600);
 
         mobject.render(&mut renderer).unwrap();
-        // MockMobject doesn't actually draw anything, so count stays 0
-        assert_eq!(renderer.render_count, 0);
+        // MockMobject doesn't actually draw anything
+        assert!(renderer.commands().is_empty());
     }
 
     #[test]
@@ -366,4 +820,72 @@ mod tests {
         assert_eq!(mobject.position(), Vector2D::new(5.0, 6.0));
         assert_eq!(mobject.opacity(), 0.7);
     }
+
+    #[test]
+    fn test_mobject_into_boxed() {
+        let mut mobject = MockMobject::new();
+        mobject.set_position(Vector2D::new(1.0, 2.0));
+        let boxed = mobject.into_boxed();
+
+        assert_eq!(boxed.position(), Vector2D::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_mobject_fade() {
+        let mut mobject = MockMobject::new();
+        mobject.fade(0.5);
+        assert_eq!(mobject.opacity(), 0.5);
+        mobject.fade(0.5);
+        assert_eq!(mobject.opacity(), 0.25);
+
+        mobject.fade(2.0);
+        assert_eq!(mobject.opacity(), 0.0);
+    }
+
+    #[test]
+    fn test_mobject_describe_default() {
+        let mut mobject = MockMobject::new();
+        mobject.set_position(Vector2D::new(1.0, 2.0));
+        let description = mobject.describe();
+
+        assert!(description.starts_with("Mobject("));
+        assert!(description.contains("position: (1, 2)"));
+        assert!(description.contains("opacity: 1"));
+    }
+
+    #[test]
+    fn test_mobject_snap_to_grid() {
+        let mut mobject = MockMobject::new();
+        mobject.set_position(Vector2D::new(1.3, 2.7));
+        mobject.snap_to_grid(0.5);
+        assert_eq!(mobject.position(), Vector2D::new(1.5, 2.5));
+    }
+
+    #[test]
+    fn test_z_index_forwards_to_inner() {
+        use crate::mobject::geometry::Square;
+
+        let mut square = Square::new(1.0);
+        square.set_z_index(3);
+        assert_eq!(square.z_index(), 3);
+        assert_eq!(square.inner().unwrap().z_index(), 3);
+
+        let mut mock = MockMobject::new();
+        mock.set_z_index(3);
+        assert_eq!(mock.z_index(), 0);
+    }
+
+    #[test]
+    fn test_visibility_forwards_to_inner() {
+        use crate::mobject::geometry::Arrow;
+
+        let mut arrow = Arrow::new(Vector2D::ZERO, Vector2D::new(1.0, 0.0));
+        arrow.set_visible(false);
+        assert!(!arrow.is_visible());
+        assert!(!arrow.inner().unwrap().is_visible());
+
+        let mut mock = MockMobject::new();
+        mock.set_visible(false);
+        assert!(mock.is_visible());
+    }
 }
@@ -0,0 +1,494 @@
+//! Table mobject.
+//!
+//! Lays out text entries in a grid, optionally with separator lines or the
+//! enclosing brackets of a matrix.
+
+use crate::core::{BoundingBox, Result, Transform, Vector2D};
+use crate::mobject::geometry::Line;
+use crate::mobject::{Mobject, MobjectGroup, Text, VMobject};
+use crate::renderer::{
+    baseline_offset, measure_text, Path, PathStyle, Renderer, TextAlignment, TextStyle,
+};
+
+/// Horizontal cell padding on each side, as a fraction of the font size.
+const CELL_PADDING_X: f64 = 0.4;
+
+/// Vertical cell padding on each side, as a fraction of the font size.
+const CELL_PADDING_Y: f64 = 0.3;
+
+/// Width of the bracket serifs, as a fraction of the font size.
+const BRACKET_SERIF: f64 = 0.3;
+
+/// A grid of text entries.
+///
+/// Columns are as wide as their widest entry (measured with
+/// [`measure_text`]) plus padding, and every row has the same height. The
+/// table is centered on the origin. Cells occupy the first
+/// `rows * columns` children of the underlying group in row-major order;
+/// separator lines and brackets follow them. Ragged input is padded with
+/// empty cells.
+///
+/// A table built with [`TextStyle::default`] adopts a scene theme's text
+/// style through [`Mobject::apply_default_text_style`]. It is then laid out
+/// again in that style, keeping its transform, opacity, z-index and
+/// visibility; edits made through [`Table::get_cell_mut`] are discarded.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::Color;
+/// use manim_rs::mobject::Table;
+/// use manim_rs::renderer::TextStyle;
+///
+/// let matrix = Table::builder(
+///     vec![
+///         vec!["1".to_string(), "0".to_string()],
+///         vec!["0".to_string(), "1".to_string()],
+///     ],
+///     TextStyle::new(Color::WHITE, 24.0),
+/// )
+/// .brackets(true)
+/// .build();
+///
+/// assert_eq!(matrix.rows(), 2);
+/// assert!(matrix.get_cell(1, 1).is_some());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Table {
+    group: MobjectGroup,
+    rows: usize,
+    columns: usize,
+    /// Entries and options the table was laid out from
+    builder: TableBuilder,
+    /// Every transform applied since layout, for laying out again
+    transform: Transform,
+}
+
+impl Table {
+    /// Creates a plain table without separators or brackets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::Table;
+    /// use manim_rs::renderer::TextStyle;
+    ///
+    /// let table = Table::new(
+    ///     vec![vec!["a".to_string(), "b".to_string(), "c".to_string()]],
+    ///     TextStyle::new(Color::WHITE, 24.0),
+    /// );
+    /// assert_eq!(table.columns(), 3);
+    /// ```
+    pub fn new(rows: Vec<Vec<String>>, style: TextStyle) -> Self {
+        Self::builder(rows, style).build()
+    }
+
+    /// Returns a builder for a table with the given entries and text style.
+    pub fn builder(rows: Vec<Vec<String>>, style: TextStyle) -> TableBuilder {
+        TableBuilder::new(rows, style)
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the cell at `row` and `column`, or `None` if out of range.
+    pub fn get_cell(&self, row: usize, column: usize) -> Option<&dyn Mobject> {
+        let index = self.cell_index(row, column)?;
+        self.group.iter().nth(index)
+    }
+
+    /// Returns the text of the cell at `row` and `column`, or `None` if out
+    /// of range.
+    pub fn get_text(&self, row: usize, column: usize) -> Option<&Text> {
+        self.get_cell(row, column)?.as_any()?.downcast_ref()
+    }
+
+    /// Returns the cell at `row` and `column` mutably, or `None` if out of
+    /// range.
+    pub fn get_cell_mut(&mut self, row: usize, column: usize) -> Option<&mut Box<dyn Mobject>> {
+        let index = self.cell_index(row, column)?;
+        self.group.mobjects_mut().get_mut(index)
+    }
+
+    /// Returns the group holding the cells and decorations.
+    pub fn group(&self) -> &MobjectGroup {
+        &self.group
+    }
+
+    /// Converts the table into its underlying group.
+    pub fn into_group(self) -> MobjectGroup {
+        self.group
+    }
+
+    fn cell_index(&self, row: usize, column: usize) -> Option<usize> {
+        (row < self.rows && column < self.columns).then_some(row * self.columns + column)
+    }
+}
+
+impl Mobject for Table {
+    fn render(&self, renderer: &mut dyn Renderer) -> Result<()> {
+        self.group.render(renderer)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.group.bounding_box()
+    }
+
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.group.get_points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        self.group.apply_default_style(style);
+    }
+
+    fn apply_default_text_style(&mut self, style: &TextStyle) {
+        if self.builder.style != TextStyle::default() {
+            return;
+        }
+        self.builder.style = style.clone();
+        let mut group = self.builder.layout();
+        group.apply_transform(&self.transform);
+        group.set_opacity(self.group.opacity());
+        group.set_z_index(self.group.z_index());
+        group.set_visible(self.group.is_visible());
+        self.group = group;
+    }
+
+    fn apply_transform(&mut self, transform: &Transform) {
+        self.group.apply_transform(transform);
+        self.transform = *transform * self.transform;
+    }
+
+    fn position(&self) -> Vector2D {
+        self.group.position()
+    }
+
+    fn set_position(&mut self, pos: Vector2D) {
+        let delta = pos - self.group.position();
+        self.group.set_position(pos);
+        self.transform = Transform::translate(delta.x, delta.y) * self.transform;
+    }
+
+    fn opacity(&self) -> f64 {
+        self.group.opacity()
+    }
+
+    fn set_opacity(&mut self, opacity: f64) {
+        self.group.set_opacity(opacity);
+    }
+
+    fn inner(&self) -> Option<&dyn Mobject> {
+        Some(&self.group)
+    }
+
+    fn inner_mut(&mut self) -> Option<&mut dyn Mobject> {
+        Some(&mut self.group)
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "Table(rows: {}, columns: {}, position: {})",
+            self.rows,
+            self.columns,
+            self.position()
+        )
+    }
+
+    fn clone_mobject(&self) -> Box<dyn Mobject> {
+        Box::new(self.clone())
+    }
+}
+
+/// Builder for constructing tables.
+#[derive(Clone, Debug)]
+pub struct TableBuilder {
+    entries: Vec<Vec<String>>,
+    style: TextStyle,
+    grid_lines: bool,
+    brackets: bool,
+}
+
+impl TableBuilder {
+    /// Creates a builder for the given rows of entries, drawn in `style`.
+    ///
+    /// Separators and brackets are off until enabled with
+    /// [`grid_lines`](TableBuilder::grid_lines) and
+    /// [`brackets`](TableBuilder::brackets).
+    pub fn new(rows: Vec<Vec<String>>, style: TextStyle) -> Self {
+        Self {
+            entries: rows,
+            style,
+            grid_lines: false,
+            brackets: false,
+        }
+    }
+
+    /// Draws lines between rows and between columns.
+    pub fn grid_lines(mut self, enabled: bool) -> Self {
+        self.grid_lines = enabled;
+        self
+    }
+
+    /// Encloses the table in square brackets, as for a matrix.
+    pub fn brackets(mut self, enabled: bool) -> Self {
+        self.brackets = enabled;
+        self
+    }
+
+    /// Lays out the entries and returns the finished table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::TableBuilder;
+    /// use manim_rs::renderer::TextStyle;
+    ///
+    /// let table = TableBuilder::new(
+    ///     vec![vec!["x".to_string(), "y".to_string()]],
+    ///     TextStyle::new(Color::WHITE, 24.0),
+    /// )
+    /// .grid_lines(true)
+    /// .build();
+    ///
+    /// assert_eq!(table.get_text(0, 1).unwrap().text(), "y");
+    /// // Two cells and the line between them
+    /// assert_eq!(table.group().len(), 3);
+    /// ```
+    pub fn build(self) -> Table {
+        let group = self.layout();
+        Table {
+            group,
+            rows: self.entries.len(),
+            columns: self.columns(),
+            builder: self,
+            transform: Transform::identity(),
+        }
+    }
+
+    fn columns(&self) -> usize {
+        self.entries.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Lays out the cells and decorations centered on the origin.
+    fn layout(&self) -> MobjectGroup {
+        let rows = self.entries.len();
+        let columns = self.columns();
+        let font_size = self.style.font_size;
+        let cell_style = self.style.clone().with_alignment(TextAlignment::Center);
+
+        let mut column_widths = vec![0.0_f64; columns];
+        for row in &self.entries {
+            for (column, entry) in row.iter().enumerate() {
+                let width = measure_text(entry, &cell_style).x;
+                column_widths[column] = column_widths[column].max(width);
+            }
+        }
+        for width in &mut column_widths {
+            *width += 2.0 * CELL_PADDING_X * font_size;
+        }
+        let row_height = font_size * (1.0 + 2.0 * CELL_PADDING_Y);
+
+        let total_width: f64 = column_widths.iter().sum();
+        let total_height = row_height * rows as f64;
+        let left = -total_width / 2.0;
+        let right = total_width / 2.0;
+        let top = total_height / 2.0;
+        let bottom = -total_height / 2.0;
+
+        // Left edge of every column, plus the right edge of the table
+        let mut column_edges = Vec::with_capacity(columns + 1);
+        let mut x = left;
+        column_edges.push(x);
+        for width in &column_widths {
+            x += width;
+            column_edges.push(x);
+        }
+
+        let mut group = MobjectGroup::new();
+        for row in 0..rows {
+            let center_y = top - (row as f64 + 0.5) * row_height;
+            for column in 0..columns {
+                let entry = self.entries[row].get(column).cloned().unwrap_or_default();
+                let center_x = (column_edges[column] + column_edges[column + 1]) / 2.0;
+                let mut text = Text::new(entry, cell_style.clone());
+                text.set_position(Vector2D::new(
+                    center_x,
+                    center_y + baseline_offset(&cell_style),
+                ));
+                group.add(Box::new(text));
+            }
+        }
+
+        let color = self.style.color;
+        if self.grid_lines {
+            for row in 1..rows {
+                let y = top - row as f64 * row_height;
+                let line = Line::builder()
+                    .start(Vector2D::new(left, y))
+                    .end(Vector2D::new(right, y))
+                    .stroke_color(color)
+                    .build();
+                group.add(Box::new(line));
+            }
+            for &x in &column_edges[1..columns.max(1)] {
+                let line = Line::builder()
+                    .start(Vector2D::new(x, bottom))
+                    .end(Vector2D::new(x, top))
+                    .stroke_color(color)
+                    .build();
+                group.add(Box::new(line));
+            }
+        }
+
+        if self.brackets && rows > 0 {
+            let serif = BRACKET_SERIF * font_size;
+            for (edge, inward) in [(left, serif), (right, -serif)] {
+                let path = Path::polyline(
+                    &[
+                        Vector2D::new(edge + inward, top),
+                        Vector2D::new(edge, top),
+                        Vector2D::new(edge, bottom),
+                        Vector2D::new(edge + inward, bottom),
+                    ],
+                    false,
+                );
+                let mut bracket = VMobject::new(path);
+                bracket.set_stroke(color, 2.0);
+                group.add(Box::new(bracket));
+            }
+        }
+
+        group
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Color;
+
+    fn entries(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|s| s.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_table_cells_are_text() {
+        let table = Table::new(
+            entries(&[&["1", "2"], &["3", "4"]]),
+            TextStyle::new(Color::WHITE, 20.0),
+        );
+
+        assert_eq!(table.rows(), 2);
+        assert_eq!(table.columns(), 2);
+        assert_eq!(table.group().len(), 4);
+        let texts = table
+            .group()
+            .iter()
+            .filter_map(|m| m.as_any())
+            .filter(|any| any.is::<Text>())
+            .count();
+        assert_eq!(texts, 4);
+        assert!(table.get_cell(2, 0).is_none());
+        assert!(table.get_cell(0, 2).is_none());
+    }
+
+    #[test]
+    fn test_table_cells_do_not_overlap() {
+        let table = Table::new(
+            entries(&[&["alpha", "b"], &["c", "delta"]]),
+            TextStyle::new(Color::WHITE, 20.0),
+        );
+
+        let boxes: Vec<BoundingBox> = (0..2)
+            .flat_map(|r| (0..2).map(move |c| (r, c)))
+            .map(|(r, c)| table.get_cell(r, c).unwrap().bounding_box())
+            .collect();
+
+        for i in 0..boxes.len() {
+            for j in (i + 1)..boxes.len() {
+                assert!(boxes[i].intersection(&boxes[j]).is_none());
+                assert_ne!(boxes[i].center(), boxes[j].center());
+            }
+        }
+
+        // Row 0 sits above row 1, column 0 left of column 1
+        assert!(boxes[0].center().y > boxes[2].center().y);
+        assert!(boxes[0].center().x < boxes[1].center().x);
+    }
+
+    #[test]
+    fn test_table_decorations() {
+        let style = TextStyle::new(Color::WHITE, 20.0);
+        let rows = entries(&[&["1", "2", "3"], &["4", "5", "6"]]);
+
+        let grid = Table::builder(rows.clone(), style.clone())
+            .grid_lines(true)
+            .build();
+        // 6 cells, 1 row separator, 2 column separators
+        assert_eq!(grid.group().len(), 9);
+
+        let matrix = Table::builder(rows, style).brackets(true).build();
+        assert_eq!(matrix.group().len(), 8);
+        let cells = BoundingBox::from_bounding_boxes(
+            (0..3).map(|c| matrix.get_cell(0, c).unwrap().bounding_box()),
+        )
+        .unwrap();
+        assert!(matrix.bounding_box().width() > cells.width());
+    }
+
+    #[test]
+    fn test_table_ragged_rows_are_padded() {
+        let mut table = Table::new(
+            entries(&[&["a"], &["b", "c"]]),
+            TextStyle::new(Color::WHITE, 20.0),
+        );
+        assert_eq!(table.columns(), 2);
+        assert_eq!(table.get_text(0, 1).unwrap().text(), "");
+        assert_eq!(table.get_text(1, 1).unwrap().text(), "c");
+
+        table.get_cell_mut(1, 1).unwrap().set_opacity(0.5);
+        assert_eq!(table.get_cell(1, 1).unwrap().opacity(), 0.5);
+    }
+
+    #[test]
+    fn test_table_default_text_style_relayouts_unstyled() {
+        let theme = TextStyle::new(Color::BLACK, 24.0);
+        let mut table = Table::builder(entries(&[&["a", "bb"]]), TextStyle::default())
+            .grid_lines(true)
+            .build();
+        table.set_position(Vector2D::new(5.0, 0.0));
+        let before = table.bounding_box();
+
+        table.apply_default_text_style(&theme);
+        let cell = table.get_text(0, 1).unwrap();
+        assert_eq!(cell.style().color, Color::BLACK);
+        assert_eq!(cell.style().font_size, 24.0);
+        assert_eq!(table.position(), Vector2D::new(5.0, 0.0));
+        assert!((table.bounding_box().center().x - 5.0).abs() < 1e-9);
+        assert!(table.bounding_box().width() < before.width());
+
+        // The table is styled now, so a second theme leaves it alone
+        table.apply_default_text_style(&TextStyle::new(Color::RED, 10.0));
+        assert_eq!(table.get_text(0, 0).unwrap().style().color, Color::BLACK);
+    }
+
+    #[test]
+    fn test_table_default_text_style_keeps_styled() {
+        let style = TextStyle::new(Color::WHITE, 20.0);
+        let mut table = Table::new(entries(&[&["a"]]), style);
+        table.apply_default_text_style(&TextStyle::new(Color::BLACK, 24.0));
+        assert_eq!(table.get_text(0, 0).unwrap().style().color, Color::WHITE);
+    }
+}
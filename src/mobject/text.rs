@@ -0,0 +1,296 @@
+//! Text mobject.
+//!
+//! Places a single line of text in the scene graph.
+
+use std::any::Any;
+
+use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
+use crate::mobject::{apply_tint, make_tint, Mobject};
+use crate::renderer::{text_bounds, Renderer, TextStyle};
+
+/// A mobject that displays a single line of text.
+///
+/// The position is the text anchor passed to [`Renderer::draw_text`]: it lies
+/// on the baseline and at the left edge, center, or right edge depending on
+/// the style's alignment. Bounding boxes come from the estimated metrics of
+/// [`text_bounds`](crate::renderer::text_bounds).
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::{Color, Vector2D};
+/// use manim_rs::mobject::{Mobject, Text};
+/// use manim_rs::renderer::TextStyle;
+///
+/// let mut label = Text::new("x + y", TextStyle::new(Color::WHITE, 24.0));
+/// label.set_position(Vector2D::new(10.0, 5.0));
+///
+/// assert_eq!(label.text(), "x + y");
+/// assert_eq!(label.bounding_box().min().x, 10.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Text {
+    text: String,
+    style: TextStyle,
+    position: Vector2D,
+    z_index: i32,
+    visible: bool,
+    tint: Option<(Color, f64)>,
+    /// Fractions of the outline and fill drawn, see [`Mobject::set_reveal`]
+    reveal: (f64, f64),
+    /// Whether the style was set explicitly to something other than the
+    /// default, which opts out of themes
+    styled: bool,
+}
+
+impl Text {
+    /// Creates a text mobject anchored at the origin.
+    ///
+    /// Text created with [`TextStyle::default`] counts as unstyled, so a
+    /// scene theme can replace its style, see
+    /// [`Mobject::apply_default_text_style`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::Text;
+    /// use manim_rs::renderer::TextStyle;
+    ///
+    /// let text = Text::new("Hello", TextStyle::new(Color::WHITE, 48.0));
+    /// assert_eq!(text.style().font_size, 48.0);
+    /// ```
+    pub fn new(text: impl Into<String>, style: TextStyle) -> Self {
+        Self {
+            text: text.into(),
+            styled: style != TextStyle::default(),
+            style,
+            position: Vector2D::ZERO,
+            z_index: 0,
+            visible: true,
+            tint: None,
+            reveal: (1.0, 1.0),
+        }
+    }
+
+    /// Returns the displayed text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces the displayed text.
+    pub fn set_text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Returns the text style.
+    pub fn style(&self) -> &TextStyle {
+        &self.style
+    }
+
+    /// Replaces the text style.
+    pub fn set_style(&mut self, style: TextStyle) -> &mut Self {
+        self.style = style;
+        self.update_styled();
+        self
+    }
+
+    /// Sets the text color.
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.style.color = color;
+        self.update_styled();
+        self
+    }
+
+    /// Marks the text as styled unless its style is still the default, the
+    /// same rule [`VMobject`](crate::mobject::VMobject) uses for stroke and
+    /// fill.
+    fn update_styled(&mut self) {
+        self.styled |= self.style != TextStyle::default();
+    }
+}
+
+impl Mobject for Text {
+    fn render(&self, renderer: &mut dyn Renderer) -> Result<()> {
+        let mut text = self.text.as_str();
+        if self.reveal.0 < 1.0 {
+            let shown = (text.chars().count() as f64 * self.reveal.0).floor() as usize;
+            if let Some((end, _)) = text.char_indices().nth(shown) {
+                text = &text[..end];
+            }
+            if text.is_empty() {
+                return Ok(());
+            }
+        }
+        if self.tint.is_none() {
+            return renderer.draw_text(text, self.position, &self.style);
+        }
+        let mut style = self.style.clone();
+        style.color = apply_tint(style.color, self.tint);
+        renderer.draw_text(text, self.position, &style)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        text_bounds(&self.text, self.position, &self.style)
+    }
+
+    /// Moves the anchor and scales the font size by the transform's area
+    /// scale factor.
+    ///
+    /// Text is always drawn upright, so rotation and shear are not
+    /// represented.
+    fn apply_transform(&mut self, transform: &Transform) {
+        self.position = transform.apply(self.position);
+        self.style.font_size *= transform.determinant().abs().sqrt();
+    }
+
+    fn position(&self) -> Vector2D {
+        self.position
+    }
+
+    fn set_position(&mut self, pos: Vector2D) {
+        self.position = pos;
+    }
+
+    fn opacity(&self) -> f64 {
+        self.style.opacity
+    }
+
+    fn set_opacity(&mut self, opacity: f64) {
+        self.style.opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn set_z_index(&mut self, z: i32) {
+        self.z_index = z;
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn set_tint(&mut self, color: Color, amount: f64) {
+        self.tint = make_tint(color, amount);
+    }
+
+    fn set_reveal(&mut self, stroke: f64, fill: f64) {
+        self.reveal = (stroke.clamp(0.0, 1.0), fill.clamp(0.0, 1.0));
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "Text({:?}, font_size: {}, position: {}, opacity: {})",
+            self.text, self.style.font_size, self.position, self.style.opacity
+        )
+    }
+
+    fn apply_default_text_style(&mut self, style: &TextStyle) {
+        if !self.styled {
+            self.style = style.clone();
+        }
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
+    fn clone_mobject(&self) -> Box<dyn Mobject> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::{DrawCommand, RecordingRenderer};
+    use crate::renderer::TextAlignment;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_text_render() {
+        let mut text = Text::new("label", TextStyle::new(Color::WHITE, 12.0));
+        text.set_position(Vector2D::new(1.0, 2.0));
+        text.set_opacity(0.5);
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        text.render(&mut renderer).unwrap();
+        let [DrawCommand::Text {
+            text,
+            position,
+            style,
+        }] = renderer.commands()
+        else {
+            panic!("expected a single text draw");
+        };
+        assert_eq!(
+            (text.as_str(), *position, style.opacity),
+            ("label", Vector2D::new(1.0, 2.0), 0.5)
+        );
+    }
+
+    #[test]
+    fn test_text_bounding_box_follows_alignment() {
+        let style = TextStyle::new(Color::WHITE, 10.0).with_alignment(TextAlignment::Center);
+        let mut text = Text::new("abcd", style);
+        text.set_position(Vector2D::new(3.0, 0.0));
+
+        let bbox = text.bounding_box();
+        assert_relative_eq!(bbox.center().x, 3.0);
+        assert!(bbox.width() > 0.0);
+    }
+
+    #[test]
+    fn test_text_apply_transform_scales_font() {
+        let mut text = Text::new("a", TextStyle::new(Color::WHITE, 10.0));
+        text.apply_transform(&Transform::scale(2.0, 2.0));
+        assert_relative_eq!(text.style().font_size, 20.0);
+
+        text.apply_transform(&Transform::translate(1.0, 1.0));
+        assert_eq!(text.position(), Vector2D::new(1.0, 1.0));
+        assert_relative_eq!(text.style().font_size, 20.0);
+    }
+
+    #[test]
+    fn test_text_describe() {
+        let text = Text::new("hi", TextStyle::new(Color::WHITE, 10.0));
+        assert!(text.describe().starts_with("Text(\"hi\""));
+    }
+
+    #[test]
+    fn test_text_default_style_only_replaces_unstyled() {
+        let theme = TextStyle::new(Color::BLACK, 30.0);
+
+        let mut plain = Text::new("a", TextStyle::default());
+        plain.apply_default_text_style(&theme);
+        assert_eq!(plain.style(), &theme);
+
+        let mut recolored = Text::new("b", TextStyle::default());
+        recolored.set_color(Color::RED);
+        recolored.apply_default_text_style(&theme);
+        assert_eq!(recolored.style().color, Color::RED);
+        assert_eq!(recolored.style().font_size, 48.0);
+    }
+
+    #[test]
+    fn test_text_default_style_via_setter_stays_unstyled() {
+        let theme = TextStyle::new(Color::BLACK, 30.0);
+
+        let mut text = Text::new("a", TextStyle::default());
+        text.set_style(TextStyle::default());
+        text.apply_default_text_style(&theme);
+        assert_eq!(text.style(), &theme);
+
+        let mut colored = Text::new("b", TextStyle::default());
+        colored.set_color(Color::RED);
+        colored.apply_default_text_style(&theme);
+        assert_eq!(colored.style().color, Color::RED);
+    }
+}
@@ -4,10 +4,18 @@
 //! manim-rs. It wraps a [`Path`] and styling information, providing the core
 //! implementation of the [`Mobject`] trait for path-based objects.
 
-use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
-use crate::mobject::Mobject;
+use std::sync::OnceLock;
+
+use crate::core::{BoundingBox, Color, Error, Result, Transform, Vector2D};
+use crate::mobject::{apply_tint, make_tint, Mobject};
 use crate::renderer::{Path, PathStyle, Renderer};
 
+/// Stroke color of a new VMobject.
+const DEFAULT_STROKE_COLOR: Option<Color> = Some(Color::WHITE);
+
+/// Stroke width of a new VMobject.
+const DEFAULT_STROKE_WIDTH: f64 = 2.0;
+
 /// A mobject based on vector paths.
 ///
 /// [`VMobject`] is the building block for most geometric shapes. It combines a
@@ -38,8 +46,64 @@ pub struct VMobject {
     stroke_color: Option<Color>,
     stroke_width: f64,
     fill_color: Option<Color>,
+    fill_opacity: f64,
+    stroke_opacity: f64,
+    /// Base opacity, as last set with [`Mobject::set_opacity`]
     opacity: f64,
+    /// Multiplier applied to the base opacity by [`Mobject::fade`]
+    fade: f64,
     position: Vector2D,
+    z_index: i32,
+    visible: bool,
+    tint: Option<(Color, f64)>,
+    /// Fractions of the outline and fill drawn, see [`Mobject::set_reveal`]
+    reveal: (f64, f64),
+    /// Whether stroke or fill were set explicitly to something other than
+    /// the defaults, which opts out of themes
+    styled: bool,
+    /// Whether transforms scale the stroke width along with the geometry
+    scale_stroke_with_transform: bool,
+    /// Stroke-expanded bounds, computed lazily and reset on every mutation
+    cached_bounds: OnceLock<BoundingBox>,
+}
+
+/// VMobjects are equal when they have the same path, style and state.
+///
+/// The cached bounds are derived from the rest and are not compared.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::{Color, Vector2D};
+/// use manim_rs::mobject::VMobject;
+/// use manim_rs::renderer::Path;
+///
+/// let path = Path::polyline(&[Vector2D::ZERO, Vector2D::new(1.0, 0.0)], false);
+/// let mut a = VMobject::new(path.clone());
+/// let b = VMobject::new(path);
+/// assert_eq!(a, b);
+///
+/// a.set_stroke(Color::RED, 2.0);
+/// assert_ne!(a, b);
+/// ```
+impl PartialEq for VMobject {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+            && self.stroke_color == other.stroke_color
+            && self.stroke_width == other.stroke_width
+            && self.fill_color == other.fill_color
+            && self.fill_opacity == other.fill_opacity
+            && self.stroke_opacity == other.stroke_opacity
+            && self.opacity == other.opacity
+            && self.fade == other.fade
+            && self.position == other.position
+            && self.z_index == other.z_index
+            && self.visible == other.visible
+            && self.tint == other.tint
+            && self.reveal == other.reveal
+            && self.styled == other.styled
+            && self.scale_stroke_with_transform == other.scale_stroke_with_transform
+    }
 }
 
 impl VMobject {
@@ -59,11 +123,21 @@ impl VMobject {
     pub fn new(path: Path) -> Self {
         Self {
             path,
-            stroke_color: Some(Color::WHITE),
-            stroke_width: 2.0,
+            stroke_color: DEFAULT_STROKE_COLOR,
+            stroke_width: DEFAULT_STROKE_WIDTH,
             fill_color: None,
+            fill_opacity: 1.0,
+            stroke_opacity: 1.0,
             opacity: 1.0,
+            fade: 1.0,
             position: Vector2D::ZERO,
+            z_index: 0,
+            visible: true,
+            tint: None,
+            reveal: (1.0, 1.0),
+            styled: false,
+            scale_stroke_with_transform: false,
+            cached_bounds: OnceLock::new(),
         }
     }
 
@@ -96,6 +170,82 @@ impl VMobject {
         Self::new(path)
     }
 
+    /// Creates a VMobject from SVG path data, as found in the `d` attribute
+    /// of `<path>`, with default styling.
+    ///
+    /// Coordinates are taken as-is. SVG's Y axis points down, so artwork
+    /// drawn for the screen appears upside down until flipped with
+    /// `Transform::scale(1.0, -1.0)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the path data is malformed, see
+    /// [`Path::from_svg_d`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::VMobject;
+    ///
+    /// let square = VMobject::from_svg_path_data("M 0 0 L 1 0 L 1 1 L 0 1 Z").unwrap();
+    /// assert_eq!(square.path().len(), 5);
+    /// ```
+    pub fn from_svg_path_data(d: &str) -> Result<Self> {
+        Ok(Self::new(Path::from_svg_d(d)?))
+    }
+
+    /// Creates a VMobject from the first `<path>` element of an SVG document.
+    ///
+    /// Only the element's `d` attribute is read; its styling, any transforms
+    /// and all other elements are ignored, as are paths inside comments and
+    /// CDATA sections. See
+    /// [`VMobject::from_svg_path_data`] for how coordinates are treated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the document has no `<path>`
+    /// with a `d` attribute, or if the path data is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::VMobject;
+    ///
+    /// let svg = r#"<svg xmlns="http://www.w3.org/2000/svg">
+    ///     <path id="icon" fill="red" d="M 0 0 L 4 0 L 2 3 Z"/>
+    /// </svg>"#;
+    /// let icon = VMobject::from_svg_document(svg).unwrap();
+    /// assert_eq!(icon.path().len(), 4);
+    /// ```
+    pub fn from_svg_document(svg: &str) -> Result<Self> {
+        let missing =
+            || Error::InvalidArgument("SVG document has no <path> with a d attribute".into());
+        let tag = first_path_tag(svg).ok_or_else(missing)?;
+
+        // Find a standalone `d` attribute, not the tail of one like `id`
+        let bytes = tag.as_bytes();
+        let mut search = 0;
+        let data = loop {
+            let offset = tag[search..].find('d').ok_or_else(missing)?;
+            let at = search + offset;
+            search = at + 1;
+            if at == 0 || !bytes[at - 1].is_ascii_whitespace() {
+                continue;
+            }
+            let rest = tag[at + 1..].trim_start();
+            let Some(rest) = rest.strip_prefix('=') else {
+                continue;
+            };
+            let rest = rest.trim_start();
+            let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+                continue;
+            };
+            let value = &rest[1..];
+            break &value[..value.find(quote).ok_or_else(missing)?];
+        };
+        Self::from_svg_path_data(data)
+    }
+
     /// Sets the stroke color and width.
     ///
     /// Returns a mutable reference to self for method chaining.
@@ -114,6 +264,8 @@ impl VMobject {
     pub fn set_stroke(&mut self, color: Color, width: f64) -> &mut Self {
         self.stroke_color = Some(color);
         self.stroke_width = width;
+        self.invalidate_bounds();
+        self.update_styled();
         self
     }
 
@@ -130,6 +282,8 @@ impl VMobject {
     /// ```
     pub fn clear_stroke(&mut self) -> &mut Self {
         self.stroke_color = None;
+        self.invalidate_bounds();
+        self.update_styled();
         self
     }
 
@@ -149,6 +303,7 @@ impl VMobject {
     /// ```
     pub fn set_fill(&mut self, color: Color) -> &mut Self {
         self.fill_color = Some(color);
+        self.update_styled();
         self
     }
 
@@ -165,6 +320,153 @@ impl VMobject {
     /// ```
     pub fn clear_fill(&mut self) -> &mut Self {
         self.fill_color = None;
+        self.update_styled();
+        self
+    }
+
+    /// Sets the color of the object.
+    ///
+    /// The stroke takes the new color while keeping its current width. The
+    /// fill is recolored only if the object already has one, so outline-only
+    /// shapes stay unfilled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::VMobject;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut vmobject = VMobject::new(Path::new());
+    /// vmobject.set_stroke(Color::WHITE, 3.0).set_fill(Color::RED);
+    /// vmobject.set_color(Color::BLUE);
+    ///
+    /// assert_eq!(vmobject.stroke_color(), Some(Color::BLUE));
+    /// assert_eq!(vmobject.stroke_width(), 3.0);
+    /// assert_eq!(vmobject.fill_color(), Some(Color::BLUE));
+    /// ```
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.stroke_color = Some(color);
+        self.invalidate_bounds();
+        if self.fill_color.is_some() {
+            self.fill_color = Some(color);
+        }
+        self.update_styled();
+        self
+    }
+
+    /// Marks the object as styled unless its stroke and fill are still the
+    /// defaults.
+    ///
+    /// This is the rule [`Text`](crate::mobject::Text) uses for its style:
+    /// builders that pass the default style through the setters leave the
+    /// object open to themes, while any other explicit style keeps it.
+    fn update_styled(&mut self) {
+        self.styled |= self.stroke_color != DEFAULT_STROKE_COLOR
+            || self.stroke_width != DEFAULT_STROKE_WIDTH
+            || self.fill_color.is_some();
+    }
+
+    /// Copies the appearance of `other`: stroke color and width, fill color,
+    /// fill and stroke opacity, and overall opacity.
+    ///
+    /// The path, position, z-index and visibility are left unchanged, so
+    /// only how the object looks changes, not where it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::VMobject;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut target = VMobject::new(Path::new());
+    /// target.set_stroke(Color::RED, 4.0).set_fill(Color::BLUE);
+    ///
+    /// let mut source = VMobject::new(Path::new());
+    /// source.match_style(&target);
+    /// assert_eq!(source.stroke_color(), Some(Color::RED));
+    /// assert_eq!(source.stroke_width(), 4.0);
+    /// assert_eq!(source.fill_color(), Some(Color::BLUE));
+    /// ```
+    pub fn match_style(&mut self, other: &VMobject) -> &mut Self {
+        self.stroke_color = other.stroke_color;
+        self.stroke_width = other.stroke_width;
+        self.fill_color = other.fill_color;
+        self.fill_opacity = other.fill_opacity;
+        self.stroke_opacity = other.stroke_opacity;
+        self.opacity = other.opacity;
+        self.fade = other.fade;
+        self.styled = other.styled;
+        self.invalidate_bounds();
+        self
+    }
+
+    /// Replaces this object's shape and appearance with those of `other`.
+    ///
+    /// The path, position and everything copied by [`match_style`] are
+    /// taken from `other`; z-index and visibility are kept, so the object
+    /// keeps its place in a scene. This is Manim's `become`, renamed because
+    /// `become` is a reserved word in Rust.
+    ///
+    /// [`match_style`]: VMobject::match_style
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Color, Vector2D};
+    /// use manim_rs::mobject::VMobject;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut target = VMobject::from_points(&[Vector2D::ZERO, Vector2D::new(1.0, 1.0)]);
+    /// target.set_stroke(Color::RED, 4.0);
+    ///
+    /// let mut object = VMobject::new(Path::new());
+    /// object.become_copy_of(&target);
+    /// assert_eq!(object.path(), target.path());
+    /// assert_eq!(object.stroke_color(), Some(Color::RED));
+    /// ```
+    pub fn become_copy_of(&mut self, other: &VMobject) -> &mut Self {
+        self.path = other.path.clone();
+        self.position = other.position;
+        self.match_style(other)
+    }
+
+    /// Sets the fill opacity (clamped to [0, 1]).
+    ///
+    /// Unlike [`Mobject::set_opacity`], this only affects the fill, so an
+    /// opaque outline can be drawn over a translucent interior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::VMobject;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut vmobject = VMobject::new(Path::new());
+    /// vmobject.set_fill(Color::RED).set_fill_opacity(0.3);
+    /// assert_eq!(vmobject.fill_opacity(), 0.3);
+    /// ```
+    pub fn set_fill_opacity(&mut self, opacity: f64) -> &mut Self {
+        self.fill_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the stroke opacity (clamped to [0, 1]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::VMobject;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut vmobject = VMobject::new(Path::new());
+    /// vmobject.set_stroke_opacity(0.5);
+    /// assert_eq!(vmobject.stroke_opacity(), 0.5);
+    /// ```
+    pub fn set_stroke_opacity(&mut self, opacity: f64) -> &mut Self {
+        self.stroke_opacity = opacity.clamp(0.0, 1.0);
         self
     }
 
@@ -186,6 +488,9 @@ impl VMobject {
 
     /// Returns a mutable reference to the underlying path.
     ///
+    /// The cached bounding box is invalidated, since the caller may edit the
+    /// path.
+    ///
     /// # Examples
     ///
     /// ```
@@ -197,6 +502,7 @@ impl VMobject {
     /// vmobject.path_mut().move_to(Vector2D::new(1.0, 2.0));
     /// ```
     pub fn path_mut(&mut self) -> &mut Path {
+        self.invalidate_bounds();
         &mut self.path
     }
 
@@ -210,36 +516,193 @@ impl VMobject {
         self.stroke_width
     }
 
+    /// Sets whether transforms scale the stroke width with the geometry.
+    ///
+    /// By default the stroke keeps its width however the object is
+    /// transformed, which suits diagrams with a constant line weight. When
+    /// enabled, [`Mobject::apply_transform`] multiplies the stroke width by
+    /// the transform's average scale, the square root of the absolute
+    /// determinant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    /// use manim_rs::mobject::{Mobject, VMobject};
+    ///
+    /// let mut vmobject = VMobject::from_points(&[Vector2D::ZERO, Vector2D::new(1.0, 0.0)]);
+    /// vmobject.set_scale_stroke_with_transform(true);
+    /// vmobject.apply_transform(&Transform::scale(3.0, 3.0));
+    /// assert_eq!(vmobject.stroke_width(), 6.0);
+    /// ```
+    pub fn set_scale_stroke_with_transform(&mut self, enabled: bool) -> &mut Self {
+        self.scale_stroke_with_transform = enabled;
+        self
+    }
+
+    /// Returns whether transforms scale the stroke width.
+    pub fn scale_stroke_with_transform(&self) -> bool {
+        self.scale_stroke_with_transform
+    }
+
     /// Returns the fill color, if any.
     pub fn fill_color(&self) -> Option<Color> {
         self.fill_color
     }
+
+    /// Returns the fill opacity.
+    pub fn fill_opacity(&self) -> f64 {
+        self.fill_opacity
+    }
+
+    /// Returns the stroke opacity.
+    pub fn stroke_opacity(&self) -> f64 {
+        self.stroke_opacity
+    }
+
+    /// Returns the opacity before any [`fade`](Mobject::fade).
+    ///
+    /// [`Mobject::opacity`] is this base scaled by the fades applied since
+    /// the last [`Mobject::set_opacity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::{Mobject, VMobject};
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut vmobject = VMobject::new(Path::new());
+    /// vmobject.set_opacity(0.8);
+    /// vmobject.fade(0.5);
+    /// assert_eq!(vmobject.base_opacity(), 0.8);
+    /// assert_eq!(vmobject.opacity(), 0.4);
+    /// ```
+    pub fn base_opacity(&self) -> f64 {
+        self.opacity
+    }
+
+    /// Undoes every [`fade`](Mobject::fade), returning to the base opacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::{Mobject, VMobject};
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut vmobject = VMobject::new(Path::new());
+    /// vmobject.set_opacity(0.7);
+    /// vmobject.fade(1.0);
+    /// assert_eq!(vmobject.opacity(), 0.0);
+    ///
+    /// vmobject.clear_fade();
+    /// assert_eq!(vmobject.opacity(), 0.7);
+    /// ```
+    pub fn clear_fade(&mut self) -> &mut Self {
+        self.fade = 1.0;
+        self
+    }
+
+    /// Warps the shape by mapping every point of its path through `f`.
+    ///
+    /// Anchors and control points are mapped directly, which only
+    /// approximates curves under a nonlinear `f`. Set `flatten_first` to
+    /// replace curves with short line segments before mapping, trading
+    /// smoothness for accuracy. The position is mapped as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::VMobject;
+    ///
+    /// let mut line = VMobject::from_points(&[Vector2D::new(-1.0, 0.0), Vector2D::new(1.0, 0.0)]);
+    /// line.apply_function(|p| Vector2D::new(p.x, p.y + 1.0), false);
+    /// assert_eq!(line.path().points()[0], Vector2D::new(-1.0, 1.0));
+    /// ```
+    pub fn apply_function(&mut self, f: impl Fn(Vector2D) -> Vector2D, flatten_first: bool) {
+        if flatten_first {
+            self.path = self.path.flattened();
+        }
+        self.path.apply_function(&f);
+        self.position = f(self.position);
+        self.invalidate_bounds();
+    }
+
+    /// Discards the cached bounding box so the next query recomputes it.
+    fn invalidate_bounds(&mut self) {
+        self.cached_bounds = OnceLock::new();
+    }
 }
 
 impl Mobject for VMobject {
+    fn vmobject(&self) -> Option<&VMobject> {
+        Some(self)
+    }
+
+    fn match_style(&mut self, other: &VMobject) {
+        VMobject::match_style(self, other);
+    }
+
     fn render(&self, renderer: &mut dyn Renderer) -> Result<()> {
-        let style = PathStyle {
-            stroke_color: self.stroke_color,
-            stroke_width: self.stroke_width,
-            fill_color: self.fill_color,
-            fill_rule: crate::renderer::PathFillRule::NonZero,
-            opacity: self.opacity,
+        let (stroke_reveal, fill_reveal) = self.reveal;
+        let partial;
+        let path = if stroke_reveal < 1.0 {
+            partial = self.path.partial(stroke_reveal);
+            if partial.is_empty() {
+                return Ok(());
+            }
+            &partial
+        } else {
+            &self.path
         };
-        renderer.draw_path(&self.path, &style)
+        let mut style = PathStyle::default()
+            .with_opacity(self.opacity())
+            .with_fill_opacity(self.fill_opacity * fill_reveal)
+            .with_stroke_opacity(self.stroke_opacity);
+        style.stroke_color = self.stroke_color.map(|c| apply_tint(c, self.tint));
+        style.stroke_width = self.stroke_width;
+        style.fill_color = self.fill_color.map(|c| apply_tint(c, self.tint));
+        renderer.draw_path(path, &style)
     }
 
     fn bounding_box(&self) -> BoundingBox {
-        let mut bbox = self.path.bounding_box();
-        // Expand by stroke width to account for strokes extending beyond path
-        if self.stroke_color.is_some() && self.stroke_width > 0.0 {
-            bbox = bbox.expand_by_margin(self.stroke_width / 2.0);
+        *self.cached_bounds.get_or_init(|| {
+            // Recomputed from the transformed path's curve extrema, so rotated
+            // curves stay tight instead of transforming the old box
+            let mut bbox = self.path.bounding_box();
+            // Expand by stroke width to account for strokes extending beyond path
+            if self.stroke_color.is_some() && self.stroke_width > 0.0 {
+                bbox = bbox.expand_by_margin(self.stroke_width / 2.0);
+            }
+            bbox
+        })
+    }
+
+    fn get_points(&self) -> Vec<Vector2D> {
+        self.path.points()
+    }
+
+    fn apply_default_style(&mut self, style: &PathStyle) {
+        if self.styled {
+            return;
         }
-        bbox
+        self.stroke_color = style.stroke_color;
+        self.stroke_width = style.stroke_width;
+        self.fill_color = style.fill_color;
+        self.invalidate_bounds();
     }
 
     fn apply_transform(&mut self, transform: &Transform) {
+        // Leave the cached bounds alone when nothing would move
+        if transform.is_identity(0.0) {
+            return;
+        }
         self.path.apply_transform(transform);
         self.position = transform.apply(self.position);
+        if self.scale_stroke_with_transform {
+            self.stroke_width *= transform.determinant().abs().sqrt();
+        }
+        self.invalidate_bounds();
     }
 
     fn position(&self) -> Vector2D {
@@ -251,63 +714,98 @@ impl Mobject for VMobject {
         let translation = Transform::translate(delta.x, delta.y);
         self.path.apply_transform(&translation);
         self.position = pos;
+        self.invalidate_bounds();
     }
 
     fn opacity(&self) -> f64 {
-        self.opacity
+        self.opacity * self.fade
     }
 
+    /// Sets the base opacity and clears any fade.
     fn set_opacity(&mut self, opacity: f64) {
         self.opacity = opacity.clamp(0.0, 1.0);
+        self.fade = 1.0;
     }
 
-    fn clone_mobject(&self) -> Box<dyn Mobject> {
-        Box::new(self.clone())
+    fn fade(&mut self, darkness: f64) {
+        self.fade *= 1.0 - darkness.clamp(0.0, 1.0);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::renderer::{PathStyle, TextStyle};
-    use approx::assert_relative_eq;
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
 
-    struct TestRenderer {
-        last_path: Option<Path>,
-        last_style: Option<PathStyle>,
+    fn set_z_index(&mut self, z: i32) {
+        self.z_index = z;
     }
 
-    impl TestRenderer {
-        fn new() -> Self {
-            Self {
-                last_path: None,
-                last_style: None,
-            }
-        }
+    fn is_visible(&self) -> bool {
+        self.visible
     }
 
-    impl Renderer for TestRenderer {
-        fn clear(&mut self, _color: Color) -> Result<()> {
-            Ok(())
-        }
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
 
-        fn draw_path(&mut self, path: &Path, style: &PathStyle) -> Result<()> {
-            self.last_path = Some(path.clone());
-            self.last_style = Some(style.clone());
-            Ok(())
-        }
+    fn set_tint(&mut self, color: Color, amount: f64) {
+        self.tint = make_tint(color, amount);
+    }
+
+    fn set_reveal(&mut self, stroke: f64, fill: f64) {
+        self.reveal = (stroke.clamp(0.0, 1.0), fill.clamp(0.0, 1.0));
+    }
 
-        fn draw_text(
-            &mut self,
-            _text: &str,
-            _position: Vector2D,
-            _style: &TextStyle,
-        ) -> Result<()> {
-            Ok(())
+    fn clone_mobject(&self) -> Box<dyn Mobject> {
+        Box::new(self.clone())
+    }
+}
+
+/// Returns the attribute text of the first `<path>` element in `svg`.
+///
+/// Comments and CDATA sections are skipped, the tag name must end at
+/// whitespace, `/` or `>` so elements like `<pattern>` are not matched, and
+/// a `>` inside a quoted attribute value does not end the tag.
+fn first_path_tag(svg: &str) -> Option<&str> {
+    let mut rest = svg;
+    loop {
+        rest = &rest[rest.find('<')?..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = &after[after.find("-->")? + "-->".len()..];
+        } else if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            rest = &after[after.find("]]>")? + "]]>".len()..];
+        } else if let Some(after) = rest.strip_prefix("<path").filter(|after| {
+            after.starts_with(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+        }) {
+            let mut quote = None;
+            for (i, c) in after.char_indices() {
+                match quote {
+                    Some(open) if c == open => quote = None,
+                    Some(_) => {}
+                    None if c == '"' || c == '\'' => quote = Some(c),
+                    None if c == '>' => return Some(&after[..i]),
+                    None => {}
+                }
+            }
+            return None;
+        } else {
+            rest = &rest[1..];
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::{DrawCommand, RecordingRenderer};
+    use approx::assert_relative_eq;
 
-        fn dimensions(&self) -> (u32, u32) {
-            (800, 600)
+    /// Renders `vmobject` and returns the style of the path it drew.
+    fn drawn_style(vmobject: &VMobject) -> PathStyle {
+        let mut renderer = RecordingRenderer::new(800, 600);
+        vmobject.render(&mut renderer).unwrap();
+        match renderer.commands() {
+            [DrawCommand::Path { style, .. }] => style.clone(),
+            commands => panic!("expected a single path draw, got {commands:?}"),
         }
     }
 
@@ -395,10 +893,7 @@ mod tests {
         let mut vmobject = VMobject::new(Path::new());
         vmobject.set_stroke(Color::BLUE, 2.0).clear_fill();
 
-        let mut renderer = TestRenderer::new();
-        vmobject.render(&mut renderer).unwrap();
-
-        let style = renderer.last_style.unwrap();
+        let style = drawn_style(&vmobject);
         assert_eq!(style.stroke_color, Some(Color::BLUE));
         assert_eq!(style.stroke_width, 2.0);
         assert!(style.fill_color.is_none());
@@ -409,10 +904,7 @@ mod tests {
         let mut vmobject = VMobject::new(Path::new());
         vmobject.clear_stroke().set_fill(Color::RED);
 
-        let mut renderer = TestRenderer::new();
-        vmobject.render(&mut renderer).unwrap();
-
-        let style = renderer.last_style.unwrap();
+        let style = drawn_style(&vmobject);
         assert!(style.stroke_color.is_none());
         assert_eq!(style.fill_color, Some(Color::RED));
     }
@@ -424,10 +916,7 @@ mod tests {
             .set_stroke(Color::BLACK, 1.0)
             .set_fill(Color::YELLOW);
 
-        let mut renderer = TestRenderer::new();
-        vmobject.render(&mut renderer).unwrap();
-
-        let style = renderer.last_style.unwrap();
+        let style = drawn_style(&vmobject);
         assert_eq!(style.stroke_color, Some(Color::BLACK));
         assert_eq!(style.fill_color, Some(Color::YELLOW));
     }
@@ -437,10 +926,7 @@ mod tests {
         let mut vmobject = VMobject::new(Path::new());
         vmobject.set_opacity(0.5);
 
-        let mut renderer = TestRenderer::new();
-        vmobject.render(&mut renderer).unwrap();
-
-        let style = renderer.last_style.unwrap();
+        let style = drawn_style(&vmobject);
         assert_relative_eq!(style.opacity, 0.5);
     }
 
@@ -469,6 +955,29 @@ mod tests {
         assert!(bbox.height() >= 3.0);
     }
 
+    #[test]
+    fn test_vmobject_bounding_box_cache() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(2.0, 3.0));
+
+        let mut vmobject = VMobject::new(path);
+        vmobject.clear_stroke();
+        let first = vmobject.bounding_box();
+        assert_eq!(vmobject.bounding_box(), first);
+
+        vmobject.apply_transform(&Transform::translate(1.0, 1.0));
+        let moved = vmobject.bounding_box();
+        assert_eq!(moved.min(), Vector2D::new(1.0, 1.0));
+        assert_eq!(moved.max(), Vector2D::new(3.0, 4.0));
+
+        vmobject.path_mut().line_to(Vector2D::new(10.0, 4.0));
+        assert_eq!(vmobject.bounding_box().max(), Vector2D::new(10.0, 4.0));
+
+        vmobject.set_stroke(Color::WHITE, 2.0);
+        assert_eq!(vmobject.bounding_box().max(), Vector2D::new(11.0, 5.0));
+    }
+
     #[test]
     fn test_vmobject_transform() {
         let mut path = Path::new();
@@ -525,4 +1034,217 @@ mod tests {
         let path = vmobject.path();
         assert_eq!(path.len(), 1);
     }
+
+    #[test]
+    fn test_vmobject_set_color_keeps_width() {
+        let mut vmobject = VMobject::new(Path::new());
+        vmobject.set_stroke(Color::WHITE, 4.0).set_fill(Color::RED);
+        vmobject.set_color(Color::GREEN);
+
+        assert_eq!(vmobject.stroke_color(), Some(Color::GREEN));
+        assert_eq!(vmobject.stroke_width(), 4.0);
+        assert_eq!(vmobject.fill_color(), Some(Color::GREEN));
+    }
+
+    #[test]
+    fn test_vmobject_set_color_without_fill() {
+        let mut vmobject = VMobject::new(Path::new());
+        vmobject.set_color(Color::GREEN);
+
+        assert_eq!(vmobject.stroke_color(), Some(Color::GREEN));
+        assert!(vmobject.fill_color().is_none());
+    }
+
+    #[test]
+    fn test_vmobject_fill_and_stroke_opacity() {
+        let mut vmobject = VMobject::new(Path::new());
+        vmobject
+            .set_fill(Color::RED)
+            .set_fill_opacity(0.3)
+            .set_stroke_opacity(2.0);
+
+        assert_eq!(vmobject.fill_opacity(), 0.3);
+        assert_eq!(vmobject.stroke_opacity(), 1.0);
+
+        let style = drawn_style(&vmobject);
+        assert_relative_eq!(style.fill_opacity(), 0.3);
+        assert_relative_eq!(style.stroke_opacity(), 1.0);
+        assert_relative_eq!(style.opacity, 1.0);
+    }
+
+    #[test]
+    fn test_vmobject_apply_function_makes_parabola() {
+        let points: Vec<Vector2D> = (-4..=4)
+            .map(|i| Vector2D::new(i as f64 * 0.5, 0.0))
+            .collect();
+        let mut vmobject = VMobject::from_points(&points);
+        vmobject.apply_function(|p| Vector2D::new(p.x, p.x * p.x), false);
+
+        for point in vmobject.path().points() {
+            assert_relative_eq!(point.y, point.x * point.x);
+        }
+        let bbox = vmobject.path().bounding_box();
+        assert_relative_eq!(bbox.min().y, 0.0);
+        assert_relative_eq!(bbox.max().y, 4.0);
+    }
+
+    #[test]
+    fn test_vmobject_apply_function_flatten_first() {
+        // A straight quadratic whose midpoint only lands on the parabola
+        // once the curve is flattened into sampled points
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(-1.0, 0.0))
+            .quadratic_to(Vector2D::ZERO, Vector2D::new(1.0, 0.0));
+        let f = |p: Vector2D| Vector2D::new(p.x, p.x * p.x);
+
+        let mut flat = VMobject::new(path);
+        flat.apply_function(f, true);
+        let points = flat.path().points();
+        assert!(points.len() > 3);
+        for point in points {
+            assert_relative_eq!(point.y, point.x * point.x, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_vmobject_match_style_keeps_geometry() {
+        let mut target = VMobject::from_points(&[Vector2D::ZERO, Vector2D::new(5.0, 5.0)]);
+        target
+            .set_stroke(Color::RED, 6.0)
+            .set_fill(Color::GREEN)
+            .set_fill_opacity(0.4);
+        target.set_opacity(0.5);
+
+        let mut source = VMobject::from_points(&[Vector2D::ZERO, Vector2D::new(1.0, 0.0)]);
+        source.set_position(Vector2D::new(2.0, 2.0));
+        let points = source.get_points();
+        source.match_style(&target);
+
+        assert_eq!(source.stroke_color(), Some(Color::RED));
+        assert_eq!(source.stroke_width(), 6.0);
+        assert_eq!(source.fill_color(), Some(Color::GREEN));
+        assert_eq!(source.fill_opacity(), 0.4);
+        assert_eq!(source.opacity(), 0.5);
+        assert_eq!(source.get_points(), points);
+        assert_eq!(source.position(), Vector2D::new(2.0, 2.0));
+    }
+
+    #[test]
+    fn test_vmobject_become_copy_of() {
+        let mut target = VMobject::from_points(&[
+            Vector2D::ZERO,
+            Vector2D::new(2.0, 0.0),
+            Vector2D::new(2.0, 2.0),
+        ]);
+        target.set_stroke(Color::YELLOW, 3.0).set_fill(Color::GREEN);
+        target.set_position(Vector2D::new(1.0, -1.0));
+
+        let mut object = VMobject::from_points(&[Vector2D::ZERO, Vector2D::new(1.0, 0.0)]);
+        object.set_z_index(4);
+        let cached = object.bounding_box();
+        object.become_copy_of(&target);
+
+        assert_eq!(object.path(), target.path());
+        assert_eq!(object.position(), target.position());
+        assert_eq!(object.stroke_color(), Some(Color::YELLOW));
+        assert_eq!(object.stroke_width(), 3.0);
+        assert_eq!(object.fill_color(), Some(Color::GREEN));
+        assert_eq!(object.z_index(), 4);
+        assert_ne!(object.bounding_box(), cached);
+        assert_eq!(object.bounding_box(), target.bounding_box());
+    }
+
+    #[test]
+    fn test_vmobject_from_svg_path_data() {
+        let vmobject = VMobject::from_svg_path_data("M 0 0 L 1 0 L 1 1 Z").unwrap();
+        assert_eq!(vmobject.path().len(), 4);
+        let bbox = vmobject.path().bounding_box();
+        assert_eq!(bbox.min(), Vector2D::ZERO);
+        assert_eq!(bbox.max(), Vector2D::new(1.0, 1.0));
+        assert_eq!(vmobject.stroke_color(), Some(Color::WHITE));
+
+        assert!(VMobject::from_svg_path_data("M 0").is_err());
+    }
+
+    #[test]
+    fn test_vmobject_from_svg_document_reads_first_path() {
+        let svg = "<svg><rect width='5'/><path id='a' data-x='1'\n d='M 0 0 h 3'/><path d=\"M 9 9 h 1\"/></svg>";
+        let vmobject = VMobject::from_svg_document(svg).unwrap();
+        assert_eq!(
+            vmobject.path().points(),
+            vec![Vector2D::ZERO, Vector2D::new(3.0, 0.0)]
+        );
+
+        assert!(VMobject::from_svg_document("<svg><rect/></svg>").is_err());
+        assert!(VMobject::from_svg_document("<svg><path id='x'/></svg>").is_err());
+    }
+
+    #[test]
+    fn test_vmobject_from_svg_document_skips_lookalikes() {
+        let svg = r#"<svg>
+            <!-- <path d="M 5 5 h 1"/> -->
+            <style><![CDATA[ <path d="M 6 6 h 1"/> ]]></style>
+            <pathology d="M 7 7 h 1"/>
+            <path data-note="a > b" d="M 0 0 v 2"/>
+        </svg>"#;
+        let vmobject = VMobject::from_svg_document(svg).unwrap();
+        assert_eq!(
+            vmobject.path().points(),
+            vec![Vector2D::ZERO, Vector2D::new(0.0, 2.0)]
+        );
+
+        assert!(VMobject::from_svg_document("<svg><!-- <path d='M 0 0 h 1'/> --></svg>").is_err());
+    }
+
+    #[test]
+    fn test_vmobject_stroke_scaling_with_transform() {
+        let mut fixed = VMobject::from_points(&[Vector2D::ZERO, Vector2D::new(1.0, 0.0)]);
+        fixed.set_stroke(Color::WHITE, 3.0);
+        assert!(!fixed.scale_stroke_with_transform());
+        let mut scaled = fixed.clone();
+        scaled.set_scale_stroke_with_transform(true);
+
+        let double = Transform::scale(2.0, 2.0);
+        fixed.apply_transform(&double);
+        scaled.apply_transform(&double);
+        assert_eq!(fixed.stroke_width(), 3.0);
+        assert_eq!(scaled.stroke_width(), 6.0);
+
+        // Rotations and mirrors keep the width
+        scaled.apply_transform(&Transform::rotate(1.0));
+        scaled.apply_transform(&Transform::scale(-1.0, 1.0));
+        assert_relative_eq!(scaled.stroke_width(), 6.0);
+    }
+
+    #[test]
+    fn test_vmobject_fade_keeps_base_opacity() {
+        let mut vmobject = VMobject::new(Path::new());
+        vmobject.set_opacity(0.8);
+
+        vmobject.fade(0.5);
+        vmobject.fade(0.5);
+        assert_relative_eq!(vmobject.opacity(), 0.2);
+        assert_eq!(vmobject.base_opacity(), 0.8);
+
+        // A full fade no longer loses the opacity it started from
+        vmobject.fade(1.0);
+        assert_eq!(vmobject.opacity(), 0.0);
+        vmobject.clear_fade();
+        assert_eq!(vmobject.opacity(), 0.8);
+
+        vmobject.fade(0.5);
+        vmobject.set_opacity(0.3);
+        assert_eq!(vmobject.opacity(), 0.3);
+    }
+
+    #[test]
+    fn test_wrapper_fade_reaches_vmobject() {
+        use crate::mobject::geometry::Circle;
+
+        let mut circle = Circle::new(1.0);
+        circle.set_opacity(0.6);
+        circle.fade(0.5);
+        assert_relative_eq!(circle.opacity(), 0.3);
+        assert_eq!(circle.vmobject().unwrap().base_opacity(), 0.6);
+    }
 }
@@ -10,6 +10,7 @@
 //!   raster, GPU, etc.)
 //! - [`Path`] describes geometry as a sequence of drawing commands
 //! - [`PathStyle`] and [`TextStyle`] configure stroke, fill, and typography
+//! - [`measure_text`] and [`text_bounds`] estimate text extents for layout
 //!
 //! # Examples
 //!
@@ -29,13 +30,97 @@
 //! // allocations. The concrete backend decides how to rasterize the commands.
 //! ```
 
-use crate::core::{BoundingBox, Color, Result, Transform, Vector2D};
+use crate::core::{BoundingBox, Color, Error, Result, Transform, Vector2D};
 
+mod fnv;
 mod path;
 mod style;
+mod svg_path;
+mod text;
+mod transform_stack;
 
 pub use path::{Path, PathCommand, PathCursor};
-pub use style::{FontWeight, PathFillRule, PathStyle, TextAlignment, TextStyle};
+pub use style::{
+    FontWeight, PathFillRule, PathStyle, PathStyleOverride, TextAlignment, TextStyle,
+    TextStyleOverride, VerticalAlignment,
+};
+pub(crate) use text::baseline_offset;
+pub use text::{measure_text, text_bounds};
+pub use transform_stack::TransformStack;
+
+/// Optional features a [`Renderer`] backend actually draws.
+///
+/// Several trait methods have no-op defaults, so a backend can accept a call
+/// without drawing anything. Query [`Renderer::capabilities`] before relying
+/// on one of these features.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::backends::SvgRenderer;
+/// use manim_rs::renderer::Renderer;
+///
+/// let renderer = SvgRenderer::new(800, 600);
+/// assert!(renderer.capabilities().text);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RendererCapabilities {
+    /// [`Renderer::draw_text`] draws glyphs
+    pub text: bool,
+
+    /// [`Renderer::draw_image`] draws pixel data
+    pub images: bool,
+
+    /// Drawing can be clipped to a region
+    pub clipping: bool,
+
+    /// Paths can be filled or stroked with gradients
+    pub gradients: bool,
+
+    /// Partial opacity and [`Renderer::push_group_opacity`] are honored
+    pub transparency: bool,
+}
+
+/// Quality settings a backend applies to everything it draws.
+///
+/// Backends read the fields that make sense for their output and ignore the
+/// rest: SVG output uses [`precision`](RenderConfig::precision), raster
+/// output uses [`antialias`](RenderConfig::antialias) and
+/// [`pixel_ratio`](RenderConfig::pixel_ratio).
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::renderer::RenderConfig;
+///
+/// let config = RenderConfig {
+///     precision: 4,
+///     ..RenderConfig::default()
+/// };
+/// assert!(config.antialias);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderConfig {
+    /// Smooth the edges of filled and stroked shapes
+    pub antialias: bool,
+
+    /// Output pixels per unit of scene coordinates, such as 2.0 for HiDPI
+    /// displays
+    pub pixel_ratio: f64,
+
+    /// Decimal places kept when writing coordinates as text
+    pub precision: usize,
+}
+
+impl Default for RenderConfig {
+    fn default() -> Self {
+        Self {
+            antialias: true,
+            pixel_ratio: 1.0,
+            precision: 2,
+        }
+    }
+}
 
 /// Core trait implemented by all rendering backends.
 ///
@@ -43,6 +128,25 @@ pub use style::{FontWeight, PathFillRule, PathStyle, TextAlignment, TextStyle};
 /// capabilities (SIMD, multithreading, GPU) to keep rendering overhead low. The
 /// trait is intentionally object-safe so that higher-level APIs can operate on
 /// `dyn Renderer` trait objects.
+///
+/// # Frame Lifecycle
+///
+/// Each frame follows the order [`begin_frame`](Renderer::begin_frame) →
+/// draw calls → [`end_frame`](Renderer::end_frame) → optional
+/// [`flush`](Renderer::flush). `end_frame` finalizes the logical frame, while
+/// `flush` forces buffered output to its destination; streaming backends may
+/// also flush between frames without ending one.
+///
+/// # Transforms
+///
+/// [`push_transform`](Renderer::push_transform) and
+/// [`pop_transform`](Renderer::pop_transform) have no state of their own.
+/// Their defaults work only for backends that keep a [`TransformStack`] and
+/// return it from [`transform_stack`](Renderer::transform_stack); a backend
+/// that neither does so nor overrides both methods returns [`Error::Render`]
+/// from them. Callers that cannot rely on transform support can check for
+/// that error and transform geometry themselves, as
+/// [`Path::transformed`] does.
 pub trait Renderer {
     /// Begins rendering a new frame.
     ///
@@ -60,6 +164,16 @@ pub trait Renderer {
         Ok(())
     }
 
+    /// Forces any buffered output to be written.
+    ///
+    /// Unlike [`Renderer::end_frame`], this does not finalize a frame and
+    /// must not discard drawn content; calling it mid-frame is allowed. The
+    /// default implementation does nothing, which suits backends that write
+    /// output only on explicit save.
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
     /// Clears the current frame buffer to the provided color.
     fn clear(&mut self, color: Color) -> Result<()>;
 
@@ -76,10 +190,133 @@ pub trait Renderer {
     /// (e.g., convert to vector paths) when deterministic output is needed.
     fn draw_text(&mut self, text: &str, position: Vector2D, style: &TextStyle) -> Result<()>;
 
+    /// Draws an open polyline through `points`.
+    ///
+    /// The default implementation builds the polyline as a [`Path`] and
+    /// passes it to [`Renderer::draw_path`]; backends with a faster route
+    /// for plain line strips can override it. Fewer than two points draw
+    /// nothing.
+    fn draw_polyline(&mut self, points: &[Vector2D], style: &PathStyle) -> Result<()> {
+        if points.len() < 2 {
+            return Ok(());
+        }
+        self.draw_path(&Path::polyline(points, false), style)
+    }
+
+    /// Draws a dot of `radius` at each of `points`.
+    ///
+    /// The default implementation collects every dot into a single [`Path`]
+    /// of circles and makes one [`Renderer::draw_path`] call, rather than
+    /// one call per point. Backends that can stamp circles directly may
+    /// override it.
+    fn draw_points(&mut self, points: &[Vector2D], radius: f64, style: &PathStyle) -> Result<()> {
+        if points.is_empty() {
+            return Ok(());
+        }
+        let mut cursor = PathCursor::new();
+        for point in points {
+            cursor.circle(*point, radius);
+        }
+        self.draw_path(cursor.path(), style)
+    }
+
+    /// Draws straight RGBA pixel data placed by `transform`.
+    ///
+    /// `pixels` holds `width * height` rows of unpremultiplied RGBA bytes,
+    /// top row first. The image covers the unit square from (0, 0) to
+    /// (1, 1), with its top row along y = 1, and `transform` maps that square
+    /// into the scene, so images can be rotated, sheared or mirrored like
+    /// paths. `opacity` attenuates the whole image. The default
+    /// implementation ignores images, for backends that cannot embed them.
+    fn draw_image(
+        &mut self,
+        _pixels: &[u8],
+        _width: u32,
+        _height: u32,
+        _transform: &Transform,
+        _opacity: f64,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Begins a group whose contents are composited with the given opacity.
+    ///
+    /// Groups nest: everything drawn until the matching
+    /// [`Renderer::pop_group_opacity`] is attenuated by the product of all
+    /// open group opacities. Every push must be balanced by a pop. The
+    /// default implementation ignores group opacity.
+    fn push_group_opacity(&mut self, _opacity: f64) -> Result<()> {
+        Ok(())
+    }
+
+    /// Ends the group started by the most recent
+    /// [`Renderer::push_group_opacity`].
+    fn pop_group_opacity(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Pushes a transform applied to everything drawn until the matching
+    /// [`Renderer::pop_transform`].
+    ///
+    /// Transforms nest like coordinate frames: a transform pushed inside
+    /// another is applied first, so children draw in their parent's frame.
+    /// Every push must be balanced by a pop.
+    ///
+    /// The default implementation pushes onto [`Renderer::transform_stack`];
+    /// the backend maps its draw calls through that stack.
+    ///
+    /// # Errors
+    ///
+    /// The default implementation returns [`Error::Render`] when the backend
+    /// keeps no transform stack, because silently ignoring a transform would
+    /// draw content in the wrong place.
+    fn push_transform(&mut self, transform: &Transform) -> Result<()> {
+        self.transform_stack()
+            .ok_or_else(unsupported_transforms)?
+            .push(transform);
+        Ok(())
+    }
+
+    /// Ends the coordinate frame started by the most recent
+    /// [`Renderer::push_transform`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Render`] if no transform is pushed, or, for the
+    /// default implementation, if the backend keeps no transform stack.
+    fn pop_transform(&mut self) -> Result<()> {
+        self.transform_stack()
+            .ok_or_else(unsupported_transforms)?
+            .pop()
+    }
+
+    /// Returns the stack used by the default [`Renderer::push_transform`]
+    /// and [`Renderer::pop_transform`].
+    ///
+    /// Backends that keep a [`TransformStack`] return it here and map each
+    /// path and text anchor through it before drawing. The default returns
+    /// `None`, for backends that override the push and pop methods or do not
+    /// support transforms.
+    fn transform_stack(&mut self) -> Option<&mut TransformStack> {
+        None
+    }
+
+    /// Returns the optional features this backend draws.
+    ///
+    /// The default reports no optional features; backends override it to
+    /// advertise what they support.
+    fn capabilities(&self) -> RendererCapabilities {
+        RendererCapabilities::default()
+    }
+
     /// Returns the current viewport dimensions in pixels.
     fn dimensions(&self) -> (u32, u32);
 }
 
+fn unsupported_transforms() -> Error {
+    Error::Render("This renderer does not support transform stacks".to_string())
+}
+
 /// Trait for types that can provide a cached path representation.
 ///
 /// This enables higher-level objects (mobjects) to expose precomputed paths to
@@ -165,4 +402,139 @@ mod tests {
         assert!(renderer.last_style.is_some());
         assert!(renderer.last_text.is_some());
     }
+
+    #[test]
+    fn test_default_transform_stack_is_unsupported() {
+        let mut renderer = TestRenderer::new(100, 100);
+        assert!(renderer
+            .push_transform(&Transform::translate(1.0, 0.0))
+            .is_err());
+        assert!(renderer.pop_transform().is_err());
+    }
+
+    /// Renderer relying on the default transform stack methods
+    #[derive(Default)]
+    struct StackRenderer {
+        transforms: TransformStack,
+        paths: Vec<Path>,
+        anchors: Vec<Vector2D>,
+    }
+
+    impl Renderer for StackRenderer {
+        fn clear(&mut self, _color: Color) -> Result<()> {
+            Ok(())
+        }
+
+        fn draw_path(&mut self, path: &Path, _style: &PathStyle) -> Result<()> {
+            self.paths
+                .push(self.transforms.apply_path(path).into_owned());
+            Ok(())
+        }
+
+        fn draw_text(&mut self, _text: &str, position: Vector2D, _style: &TextStyle) -> Result<()> {
+            self.anchors.push(self.transforms.apply_point(position));
+            Ok(())
+        }
+
+        fn transform_stack(&mut self) -> Option<&mut TransformStack> {
+            Some(&mut self.transforms)
+        }
+
+        fn dimensions(&self) -> (u32, u32) {
+            (100, 100)
+        }
+    }
+
+    #[test]
+    fn test_default_transform_stack_shifts_draws() {
+        let mut renderer = StackRenderer::default();
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .line_to(Vector2D::new(1.0, 0.0));
+
+        renderer
+            .push_transform(&Transform::translate(10.0, 0.0))
+            .unwrap();
+        renderer
+            .push_transform(&Transform::scale(2.0, 2.0))
+            .unwrap();
+        renderer.draw_path(&path, &PathStyle::default()).unwrap();
+        renderer.pop_transform().unwrap();
+        renderer
+            .draw_text("a", Vector2D::new(0.0, 1.0), &TextStyle::default())
+            .unwrap();
+        renderer.pop_transform().unwrap();
+        renderer.draw_path(&path, &PathStyle::default()).unwrap();
+
+        assert_eq!(
+            renderer.paths[0].commands(),
+            &[
+                PathCommand::MoveTo(Vector2D::new(10.0, 0.0)),
+                PathCommand::LineTo(Vector2D::new(12.0, 0.0)),
+            ]
+        );
+        assert_eq!(renderer.anchors[0], Vector2D::new(10.0, 1.0));
+        assert_eq!(renderer.paths[1], path);
+        assert!(renderer.pop_transform().is_err());
+    }
+
+    #[test]
+    fn test_default_capabilities_are_empty() {
+        let renderer = TestRenderer::new(10, 10);
+        assert_eq!(renderer.capabilities(), RendererCapabilities::default());
+        assert!(!renderer.capabilities().text);
+    }
+
+    #[test]
+    fn test_default_draw_polyline_is_one_path() {
+        use crate::backends::{DrawCommand, RecordingRenderer};
+
+        let points = [
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 2.0),
+            Vector2D::new(3.0, 1.0),
+        ];
+        let mut renderer = RecordingRenderer::new(100, 100);
+        renderer
+            .draw_polyline(&points, &PathStyle::stroke(Color::RED, 1.0))
+            .unwrap();
+        renderer
+            .draw_polyline(&points[..1], &PathStyle::default())
+            .unwrap();
+
+        assert_eq!(renderer.commands().len(), 1);
+        match &renderer.commands()[0] {
+            DrawCommand::Path { path, .. } => {
+                assert_eq!(
+                    path.commands(),
+                    &[
+                        PathCommand::MoveTo(points[0]),
+                        PathCommand::LineTo(points[1]),
+                        PathCommand::LineTo(points[2]),
+                    ]
+                );
+            }
+            other => panic!("expected a path, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_default_draw_points_batches_circles() {
+        use crate::backends::{DrawCommand, RecordingRenderer};
+
+        let points = [Vector2D::new(-5.0, 0.0), Vector2D::new(5.0, 0.0)];
+        let mut renderer = RecordingRenderer::new(100, 100);
+        renderer
+            .draw_points(&points, 0.5, &PathStyle::fill(Color::WHITE))
+            .unwrap();
+
+        assert_eq!(renderer.commands().len(), 1);
+        let DrawCommand::Path { path, .. } = &renderer.commands()[0] else {
+            panic!("expected a path");
+        };
+        assert_eq!(path.subpath_count(), 2);
+        let bbox = path.bounding_box();
+        assert!((bbox.width() - 11.0).abs() < 1e-10);
+        assert!((bbox.height() - 1.0).abs() < 1e-10);
+    }
 }
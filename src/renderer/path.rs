@@ -28,9 +28,13 @@
 //! assert_eq!(bounds.height(), 1.0);
 //! ```
 
+use std::f64::consts::{FRAC_PI_2, TAU};
+use std::ops::Range;
+
 use smallvec::SmallVec;
 
-use crate::core::{BoundingBox, Transform, Vector2D};
+use crate::core::{BoundingBox, CubicBezier, QuadraticBezier, Radians, Transform, Vector2D};
+use crate::renderer::fnv::Fnv1a;
 
 /// A command in a 2D vector path.
 ///
@@ -72,6 +76,62 @@ pub enum PathCommand {
 /// This means circles, squares, triangles, and most simple shapes are stack-allocated.
 type PathCommands = SmallVec<[PathCommand; 16]>;
 
+/// Pen state after a sequence of path commands.
+#[derive(Debug, Clone, Copy, Default)]
+struct Pen {
+    /// Position after the last command, `None` for an empty path
+    current: Option<Vector2D>,
+    /// Start of the current subpath, where `Close` returns to
+    subpath_start: Option<Vector2D>,
+}
+
+impl Pen {
+    /// Returns the pen state after all of `commands`.
+    fn scan(commands: &[PathCommand]) -> Self {
+        let mut pen = Self::default();
+        for cmd in commands {
+            pen.advance(cmd);
+        }
+        pen
+    }
+
+    /// Moves the pen as `cmd` would.
+    fn advance(&mut self, cmd: &PathCommand) {
+        match cmd {
+            PathCommand::MoveTo(p) => {
+                self.current = Some(*p);
+                self.subpath_start = Some(*p);
+            }
+            PathCommand::LineTo(p)
+            | PathCommand::QuadraticTo { to: p, .. }
+            | PathCommand::CubicTo { to: p, .. } => self.current = Some(*p),
+            PathCommand::Close => self.current = self.subpath_start,
+        }
+    }
+}
+
+/// Number of line segments used per curve when flattening a path.
+const FLATTEN_SEGMENTS: usize = 16;
+
+/// Number of quantization steps per unit used by [`Path::content_hash`].
+const HASH_PRECISION: f64 = 1_000_000.0;
+
+/// Returns the signed area of a closed polygon, positive when
+/// counterclockwise.
+fn polygon_signed_area(polygon: &[Vector2D]) -> f64 {
+    let n = polygon.len();
+    (0..n)
+        .map(|i| polygon[i].cross(polygon[(i + 1) % n]))
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Feeds a point into a hasher, quantizing each coordinate.
+fn hash_point(point: Vector2D, hasher: &mut Fnv1a) {
+    hasher.write_i64((point.x * HASH_PRECISION).round() as i64);
+    hasher.write_i64((point.y * HASH_PRECISION).round() as i64);
+}
+
 /// A 2D vector path composed of drawing commands.
 ///
 /// Paths are built using a fluent API with methods like [`move_to`](Path::move_to),
@@ -94,6 +154,8 @@ type PathCommands = SmallVec<[PathCommand; 16]>;
 pub struct Path {
     commands: PathCommands,
     cached_bounds: Option<BoundingBox>,
+    /// Kept in step with `commands` so appending need not rescan them
+    pen: Pen,
 }
 
 impl Path {
@@ -112,6 +174,7 @@ impl Path {
         Self {
             commands: SmallVec::new(),
             cached_bounds: None,
+            pen: Pen::default(),
         }
     }
 
@@ -133,6 +196,68 @@ impl Path {
         Self {
             commands: SmallVec::with_capacity(capacity),
             cached_bounds: None,
+            pen: Pen::default(),
+        }
+    }
+
+    /// Creates a polyline path through the given points.
+    ///
+    /// The first point starts the path and each following point adds a line
+    /// segment. When `closed` is `true` the path ends with a
+    /// [`PathCommand::Close`]. An empty slice yields an empty path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::{Path, PathCommand};
+    ///
+    /// let points = [
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(1.0, 0.0),
+    ///     Vector2D::new(0.0, 1.0),
+    /// ];
+    /// let path = Path::polyline(&points, true);
+    /// assert_eq!(path.commands().last(), Some(&PathCommand::Close));
+    /// ```
+    pub fn polyline(points: &[Vector2D], closed: bool) -> Path {
+        let mut path = Path::with_capacity(points.len() + 1);
+        if let Some((first, rest)) = points.split_first() {
+            path.move_to(*first);
+            for point in rest {
+                path.line_to(*point);
+            }
+            if closed {
+                path.close();
+            }
+        }
+        path
+    }
+
+    /// Creates a path from a sequence of commands.
+    ///
+    /// The commands are taken as-is, so the result equals the path built by
+    /// calling the matching builder methods in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::{Path, PathCommand};
+    ///
+    /// let path = Path::from_commands(vec![
+    ///     PathCommand::MoveTo(Vector2D::new(0.0, 0.0)),
+    ///     PathCommand::LineTo(Vector2D::new(1.0, 0.0)),
+    ///     PathCommand::Close,
+    /// ]);
+    /// assert_eq!(path.len(), 3);
+    /// ```
+    pub fn from_commands(commands: impl IntoIterator<Item = PathCommand>) -> Path {
+        let commands: PathCommands = commands.into_iter().collect();
+        Self {
+            pen: Pen::scan(&commands),
+            commands,
+            cached_bounds: None,
         }
     }
 
@@ -154,6 +279,37 @@ impl Path {
         &self.commands
     }
 
+    /// Returns the anchor points of the path in order.
+    ///
+    /// Every `MoveTo`, `LineTo` and curve endpoint is included; curve control
+    /// points are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::new(0.0, 0.0))
+    ///     .quadratic_to(Vector2D::new(1.0, 1.0), Vector2D::new(2.0, 0.0));
+    ///
+    /// assert_eq!(
+    ///     path.points(),
+    ///     vec![Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 0.0)]
+    /// );
+    /// ```
+    pub fn points(&self) -> Vec<Vector2D> {
+        self.commands
+            .iter()
+            .filter_map(|cmd| match cmd {
+                PathCommand::MoveTo(p) | PathCommand::LineTo(p) => Some(*p),
+                PathCommand::QuadraticTo { to, .. } | PathCommand::CubicTo { to, .. } => Some(*to),
+                PathCommand::Close => None,
+            })
+            .collect()
+    }
+
     /// Moves the pen to a position without drawing.
     ///
     /// # Examples
@@ -167,8 +323,7 @@ impl Path {
     /// ```
     #[inline]
     pub fn move_to(&mut self, point: Vector2D) -> &mut Self {
-        self.commands.push(PathCommand::MoveTo(point));
-        self.cached_bounds = None;
+        self.push(PathCommand::MoveTo(point));
         self
     }
 
@@ -186,8 +341,7 @@ impl Path {
     /// ```
     #[inline]
     pub fn line_to(&mut self, point: Vector2D) -> &mut Self {
-        self.commands.push(PathCommand::LineTo(point));
-        self.cached_bounds = None;
+        self.push(PathCommand::LineTo(point));
         self
     }
 
@@ -205,8 +359,7 @@ impl Path {
     /// ```
     #[inline]
     pub fn quadratic_to(&mut self, control: Vector2D, to: Vector2D) -> &mut Self {
-        self.commands.push(PathCommand::QuadraticTo { control, to });
-        self.cached_bounds = None;
+        self.push(PathCommand::QuadraticTo { control, to });
         self
     }
 
@@ -228,12 +381,11 @@ impl Path {
     /// ```
     #[inline]
     pub fn cubic_to(&mut self, control1: Vector2D, control2: Vector2D, to: Vector2D) -> &mut Self {
-        self.commands.push(PathCommand::CubicTo {
+        self.push(PathCommand::CubicTo {
             control1,
             control2,
             to,
         });
-        self.cached_bounds = None;
         self
     }
 
@@ -255,12 +407,279 @@ impl Path {
     /// ```
     #[inline]
     pub fn close(&mut self) -> &mut Self {
-        self.commands.push(PathCommand::Close);
+        self.push(PathCommand::Close);
         self
     }
 
+    /// Appends a command, keeping the pen and bounds cache in step.
+    fn push(&mut self, cmd: PathCommand) {
+        self.pen.advance(&cmd);
+        self.commands.push(cmd);
+        self.cached_bounds = None;
+    }
+
+    /// Appends a quadratic Bézier curve.
+    ///
+    /// A `MoveTo` to the curve's start is emitted first when the path is
+    /// empty or the pen is elsewhere, so the curve always starts at `q.p0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{QuadraticBezier, Vector2D};
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::new();
+    /// path.add_quadratic(&QuadraticBezier::new(
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(1.0, 1.0),
+    ///     Vector2D::new(2.0, 0.0),
+    /// ));
+    /// assert_eq!(path.len(), 2);
+    /// ```
+    pub fn add_quadratic(&mut self, q: &QuadraticBezier) -> &mut Self {
+        self.continue_from(q.p0);
+        self.quadratic_to(q.p1, q.p2)
+    }
+
+    /// Appends a cubic Bézier curve.
+    ///
+    /// A `MoveTo` to the curve's start is emitted first when the path is
+    /// empty or the pen is elsewhere, so the curve always starts at `c.p0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{CubicBezier, Vector2D};
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let first = CubicBezier::new(
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(0.0, 1.0),
+    ///     Vector2D::new(1.0, 1.0),
+    ///     Vector2D::new(1.0, 0.0),
+    /// );
+    /// let second = CubicBezier::new(
+    ///     Vector2D::new(1.0, 0.0),
+    ///     Vector2D::new(1.0, -1.0),
+    ///     Vector2D::new(2.0, -1.0),
+    ///     Vector2D::new(2.0, 0.0),
+    /// );
+    ///
+    /// let mut path = Path::new();
+    /// path.add_cubic(&first).add_cubic(&second);
+    /// // The second curve continues from the first without a new MoveTo
+    /// assert_eq!(path.len(), 3);
+    /// ```
+    pub fn add_cubic(&mut self, c: &CubicBezier) -> &mut Self {
+        self.continue_from(c.p0);
+        self.cubic_to(c.p1, c.p2, c.p3)
+    }
+
+    /// Returns every curve segment of the path as a cubic Bézier.
+    ///
+    /// Quadratic segments are elevated to cubics that trace the same shape.
+    /// Straight segments, including the closing segment of a subpath, are
+    /// not curves and are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{CubicBezier, Vector2D};
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Vector2D::new(0.0, 0.0),
+    ///     Vector2D::new(0.0, 1.0),
+    ///     Vector2D::new(1.0, 1.0),
+    ///     Vector2D::new(1.0, 0.0),
+    /// );
+    ///
+    /// let mut path = Path::new();
+    /// path.add_cubic(&curve);
+    /// assert_eq!(path.cubics(), vec![curve]);
+    /// ```
+    pub fn cubics(&self) -> Vec<CubicBezier> {
+        let mut cubics = Vec::new();
+        let mut current = Vector2D::ZERO;
+        let mut subpath_start = Vector2D::ZERO;
+        for cmd in &self.commands {
+            match cmd {
+                PathCommand::MoveTo(p) => {
+                    current = *p;
+                    subpath_start = *p;
+                }
+                PathCommand::LineTo(p) => current = *p,
+                PathCommand::QuadraticTo { control, to } => {
+                    cubics.push(QuadraticBezier::new(current, *control, *to).to_cubic());
+                    current = *to;
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    cubics.push(CubicBezier::new(current, *control1, *control2, *to));
+                    current = *to;
+                }
+                PathCommand::Close => current = subpath_start,
+            }
+        }
+        cubics
+    }
+
+    /// Returns the leading portion of the path covering `fraction` of its
+    /// length.
+    ///
+    /// `fraction` is clamped to [0.0, 1.0]. Lengths are measured along the
+    /// drawn segments, including the closing line of closed subpaths, so a
+    /// partial path traces the outline the way a pen would. The segment
+    /// where the cut falls is split, and a path cut at 0.0 is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::{Path, PathCommand};
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::new(0.0, 0.0))
+    ///     .line_to(Vector2D::new(2.0, 0.0))
+    ///     .line_to(Vector2D::new(2.0, 2.0));
+    ///
+    /// let half = path.partial(0.5);
+    /// assert_eq!(
+    ///     half.commands(),
+    ///     &[
+    ///         PathCommand::MoveTo(Vector2D::new(0.0, 0.0)),
+    ///         PathCommand::LineTo(Vector2D::new(2.0, 0.0)),
+    ///     ]
+    /// );
+    /// assert!(path.partial(0.0).is_empty());
+    /// ```
+    pub fn partial(&self, fraction: f64) -> Path {
+        let fraction = fraction.clamp(0.0, 1.0);
+        if fraction >= 1.0 {
+            return self.clone();
+        }
+
+        let lengths = self.segment_lengths();
+        let total: f64 = lengths.iter().sum();
+        let mut remaining = total * fraction;
+        let mut partial = Path::new();
+        if remaining <= 0.0 {
+            return partial;
+        }
+
+        let mut current = Vector2D::ZERO;
+        let mut subpath_start = Vector2D::ZERO;
+        for (cmd, length) in self.commands.iter().zip(lengths) {
+            if let PathCommand::MoveTo(p) = cmd {
+                partial.move_to(*p);
+                current = *p;
+                subpath_start = *p;
+                continue;
+            }
+            if remaining <= 0.0 {
+                break;
+            }
+            if length <= remaining {
+                partial.push(cmd.clone());
+                remaining -= length;
+                current = match cmd {
+                    PathCommand::LineTo(p)
+                    | PathCommand::QuadraticTo { to: p, .. }
+                    | PathCommand::CubicTo { to: p, .. } => *p,
+                    _ => subpath_start,
+                };
+                continue;
+            }
+
+            let t = remaining / length;
+            match cmd {
+                PathCommand::LineTo(p) => {
+                    partial.line_to(current.lerp(*p, t));
+                }
+                PathCommand::QuadraticTo { control, to } => {
+                    let (first, _) = QuadraticBezier::new(current, *control, *to).split(t);
+                    partial.quadratic_to(first.p1, first.p2);
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    let (first, _) = CubicBezier::new(current, *control1, *control2, *to).split(t);
+                    partial.cubic_to(first.p1, first.p2, first.p3);
+                }
+                PathCommand::Close => {
+                    partial.line_to(current.lerp(subpath_start, t));
+                }
+                PathCommand::MoveTo(_) => unreachable!(),
+            }
+            break;
+        }
+        partial
+    }
+
+    /// Returns the drawn length of each command, with zero for moves.
+    fn segment_lengths(&self) -> Vec<f64> {
+        let mut current = Vector2D::ZERO;
+        let mut subpath_start = Vector2D::ZERO;
+        self.commands
+            .iter()
+            .map(|cmd| match cmd {
+                PathCommand::MoveTo(p) => {
+                    current = *p;
+                    subpath_start = *p;
+                    0.0
+                }
+                PathCommand::LineTo(p) => {
+                    let length = (*p - current).magnitude();
+                    current = *p;
+                    length
+                }
+                PathCommand::QuadraticTo { control, to } => {
+                    let curve = QuadraticBezier::new(current, *control, *to);
+                    current = *to;
+                    curve.arc_length_estimate(FLATTEN_SEGMENTS)
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    let curve = CubicBezier::new(current, *control1, *control2, *to);
+                    current = *to;
+                    curve.arc_length_estimate(FLATTEN_SEGMENTS)
+                }
+                PathCommand::Close => {
+                    let length = (subpath_start - current).magnitude();
+                    current = subpath_start;
+                    length
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the pen position after the last command, or `None` if the
+    /// path is empty.
+    fn current_point(&self) -> Option<Vector2D> {
+        self.pen.current
+    }
+
+    /// Moves the pen to `start` unless it is already there.
+    fn continue_from(&mut self, start: Vector2D) {
+        if self.current_point() != Some(start) {
+            self.move_to(start);
+        }
+    }
+
     /// Returns the bounding box of the path.
     ///
+    /// Curves contribute their exact extent rather than their control
+    /// points, so curved shapes report tight bounds.
+    ///
     /// The bounding box is cached, so repeated calls are cheap. The cache is
     /// invalidated whenever the path is modified.
     ///
@@ -283,35 +702,46 @@ impl Path {
             return bounds;
         }
 
-        // Collect all points from commands
-        let mut points = Vec::new();
+        // Union the anchors with each curve's exact extent, so control
+        // points never inflate the box
+        let mut bounds: Option<BoundingBox> = None;
+        let mut include = |bbox: BoundingBox| {
+            bounds = Some(match bounds {
+                Some(existing) => existing.union(&bbox),
+                None => bbox,
+            });
+        };
+        let mut current = Vector2D::ZERO;
+        let mut subpath_start = Vector2D::ZERO;
         for cmd in &self.commands {
             match cmd {
-                PathCommand::MoveTo(p) | PathCommand::LineTo(p) => {
-                    points.push(*p);
+                PathCommand::MoveTo(p) => {
+                    include(BoundingBox::new(*p, *p));
+                    current = *p;
+                    subpath_start = *p;
+                }
+                PathCommand::LineTo(p) => {
+                    include(BoundingBox::new(*p, *p));
+                    current = *p;
                 }
                 PathCommand::QuadraticTo { control, to } => {
-                    points.push(*control);
-                    points.push(*to);
+                    include(QuadraticBezier::new(current, *control, *to).bounding_box());
+                    current = *to;
                 }
                 PathCommand::CubicTo {
                     control1,
                     control2,
                     to,
                 } => {
-                    points.push(*control1);
-                    points.push(*control2);
-                    points.push(*to);
+                    include(CubicBezier::new(current, *control1, *control2, *to).bounding_box());
+                    current = *to;
                 }
-                PathCommand::Close => {}
+                // Drawing continues from the start of the closed subpath
+                PathCommand::Close => current = subpath_start,
             }
         }
 
-        if points.is_empty() {
-            BoundingBox::zero()
-        } else {
-            BoundingBox::from_points(points).unwrap_or_else(BoundingBox::zero)
-        }
+        bounds.unwrap_or_else(BoundingBox::zero)
     }
 
     /// Applies a transformation to all points in the path.
@@ -353,119 +783,860 @@ impl Path {
                 PathCommand::Close => {}
             }
         }
+        self.pen = Pen::scan(&self.commands);
         self.cached_bounds = None;
     }
-}
-
-impl Default for Path {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl PartialEq for Path {
-    fn eq(&self, other: &Self) -> bool {
-        self.commands == other.commands
-    }
-}
-
-/// A helper for building paths with cursor tracking.
-///
-/// [`PathCursor`] maintains the current pen position, making it easier to build
-/// paths with relative movements.
-///
-/// # Examples
-///
-/// ```
-/// use manim_rs::core::Vector2D;
-/// use manim_rs::renderer::PathCursor;
-///
-/// let mut cursor = PathCursor::new();
-/// cursor.move_to(Vector2D::new(0.0, 0.0))
-///       .line_to(Vector2D::new(1.0, 0.0))
-///       .relative_line_to(Vector2D::new(0.0, 1.0)); // Goes to (1.0, 1.0)
-///
-/// let path = cursor.into_path();
-/// ```
-#[derive(Debug, Clone)]
-pub struct PathCursor {
-    path: Path,
-    current: Vector2D,
-}
-
-impl PathCursor {
-    /// Creates a new cursor at the origin.
-    #[inline]
-    pub fn new() -> Self {
-        Self {
-            path: Path::new(),
-            current: Vector2D::ZERO,
-        }
-    }
-
-    /// Returns the current cursor position.
-    #[inline]
-    pub fn position(&self) -> Vector2D {
-        self.current
-    }
-
-    /// Moves the cursor to an absolute position.
-    #[inline]
-    pub fn move_to(&mut self, point: Vector2D) -> &mut Self {
-        self.current = point;
-        self.path.move_to(point);
-        self
-    }
-
-    /// Draws a line to an absolute position.
-    #[inline]
-    pub fn line_to(&mut self, point: Vector2D) -> &mut Self {
-        self.current = point;
-        self.path.line_to(point);
-        self
-    }
 
-    /// Draws a line relative to the current position.
+    /// Maps every anchor and control point through `f`.
+    ///
+    /// Unlike [`apply_transform`](Path::apply_transform), `f` may be any
+    /// coordinate map. Curves are only approximated: their control points
+    /// move, but a nonlinear `f` does not map the curve between them
+    /// exactly. Call [`flattened`](Path::flattened) first when accuracy
+    /// matters.
     ///
     /// # Examples
     ///
     /// ```
     /// use manim_rs::core::Vector2D;
-    /// use manim_rs::renderer::PathCursor;
-    ///
-    /// let mut cursor = PathCursor::new();
-    /// cursor.move_to(Vector2D::new(1.0, 1.0))
-    ///       .relative_line_to(Vector2D::new(2.0, 3.0)); // Goes to (3.0, 4.0)
+    /// use manim_rs::renderer::Path;
     ///
-    /// assert_eq!(cursor.position(), Vector2D::new(3.0, 4.0));
+    /// let mut path = Path::polyline(&[Vector2D::new(2.0, 0.0), Vector2D::new(3.0, 0.0)], false);
+    /// path.apply_function(|p| Vector2D::new(p.x, p.x * p.x));
+    /// assert_eq!(path.points(), vec![Vector2D::new(2.0, 4.0), Vector2D::new(3.0, 9.0)]);
     /// ```
+    pub fn apply_function(&mut self, f: impl Fn(Vector2D) -> Vector2D) {
+        for cmd in &mut self.commands {
+            match cmd {
+                PathCommand::MoveTo(p) | PathCommand::LineTo(p) => *p = f(*p),
+                PathCommand::QuadraticTo { control, to } => {
+                    *control = f(*control);
+                    *to = f(*to);
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    *control1 = f(*control1);
+                    *control2 = f(*control2);
+                    *to = f(*to);
+                }
+                PathCommand::Close => {}
+            }
+        }
+        self.pen = Pen::scan(&self.commands);
+        self.cached_bounds = None;
+    }
+
+    /// Returns a copy of the path with every curve replaced by straight
+    /// lines.
+    ///
+    /// Each curve becomes a fixed number of line segments, the same sampling
+    /// [`Path::winding_number`] uses. Moves and closes are kept, so subpaths
+    /// stay open or closed as before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::{Path, PathCommand};
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::ZERO)
+    ///     .quadratic_to(Vector2D::new(1.0, 1.0), Vector2D::new(2.0, 0.0));
+    ///
+    /// let flat = path.flattened();
+    /// assert!(flat
+    ///     .commands()
+    ///     .iter()
+    ///     .all(|cmd| matches!(cmd, PathCommand::MoveTo(_) | PathCommand::LineTo(_))));
+    /// ```
+    pub fn flattened(&self) -> Path {
+        let mut flat = Path::with_capacity(self.commands.len());
+        let mut current = Vector2D::ZERO;
+        let mut subpath_start = Vector2D::ZERO;
+        let steps = |flat: &mut Path, evaluate: &dyn Fn(f64) -> Vector2D| {
+            for i in 1..=FLATTEN_SEGMENTS {
+                flat.line_to(evaluate(i as f64 / FLATTEN_SEGMENTS as f64));
+            }
+        };
+        for cmd in &self.commands {
+            match cmd {
+                PathCommand::MoveTo(p) => {
+                    flat.move_to(*p);
+                    current = *p;
+                    subpath_start = *p;
+                }
+                PathCommand::LineTo(p) => {
+                    flat.line_to(*p);
+                    current = *p;
+                }
+                PathCommand::QuadraticTo { control, to } => {
+                    let curve = QuadraticBezier::new(current, *control, *to);
+                    steps(&mut flat, &|t| curve.evaluate(t));
+                    current = *to;
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    let curve = CubicBezier::new(current, *control1, *control2, *to);
+                    steps(&mut flat, &|t| curve.evaluate(t));
+                    current = *to;
+                }
+                PathCommand::Close => {
+                    flat.close();
+                    current = subpath_start;
+                }
+            }
+        }
+        flat
+    }
+
+    /// Returns a copy of the path traced in the opposite direction.
+    ///
+    /// Subpaths come out in reverse order, each starting where it used to
+    /// end, with curve control points swapped to keep the same shape. Closed
+    /// subpaths stay closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let path = Path::polyline(&[Vector2D::ZERO, Vector2D::new(1.0, 0.0), Vector2D::new(1.0, 1.0)], false);
+    /// assert_eq!(
+    ///     path.reversed().points(),
+    ///     vec![Vector2D::new(1.0, 1.0), Vector2D::new(1.0, 0.0), Vector2D::ZERO]
+    /// );
+    /// ```
+    pub fn reversed(&self) -> Path {
+        let mut subpaths: Vec<Subpath> = Vec::new();
+        for (start, range) in self.subpath_ranges() {
+            let mut subpath = Subpath::new(start);
+            let mut current = start;
+            for cmd in &self.commands[range] {
+                match cmd {
+                    PathCommand::MoveTo(_) => {}
+                    PathCommand::Close => subpath.closed = true,
+                    PathCommand::LineTo(to)
+                    | PathCommand::QuadraticTo { to, .. }
+                    | PathCommand::CubicTo { to, .. } => {
+                        subpath.segments.push((current, cmd.clone()));
+                        current = *to;
+                    }
+                }
+            }
+            subpaths.push(subpath);
+        }
+
+        let mut reversed = Path::with_capacity(self.commands.len());
+        for subpath in subpaths.into_iter().rev() {
+            let end = match subpath.segments.last() {
+                Some((
+                    _,
+                    PathCommand::LineTo(to)
+                    | PathCommand::QuadraticTo { to, .. }
+                    | PathCommand::CubicTo { to, .. },
+                )) => *to,
+                _ => subpath.start,
+            };
+            reversed.move_to(end);
+            for (from, cmd) in subpath.segments.into_iter().rev() {
+                match cmd {
+                    PathCommand::QuadraticTo { control, .. } => {
+                        reversed.quadratic_to(control, from);
+                    }
+                    PathCommand::CubicTo {
+                        control1, control2, ..
+                    } => {
+                        reversed.cubic_to(control2, control1, from);
+                    }
+                    _ => {
+                        reversed.line_to(from);
+                    }
+                }
+            }
+            if subpath.closed {
+                reversed.close();
+            }
+        }
+        reversed
+    }
+
+    /// Appends `other` traced backwards, continuing from the current point.
+    ///
+    /// The leading move of the reversed path becomes a line, so the two
+    /// paths join into one subpath. Tracing an upper edge forwards and a
+    /// lower edge backwards, then closing, outlines the region between them.
+    /// If `self` is empty the move is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::{Path, PathCommand};
+    ///
+    /// let top = Path::polyline(&[Vector2D::new(0.0, 1.0), Vector2D::new(2.0, 1.0)], false);
+    /// let bottom = Path::polyline(&[Vector2D::ZERO, Vector2D::new(2.0, 0.0)], false);
+    ///
+    /// let mut region = top.clone();
+    /// region.append_reversed(&bottom).close();
+    /// assert_eq!(
+    ///     region.commands(),
+    ///     &[
+    ///         PathCommand::MoveTo(Vector2D::new(0.0, 1.0)),
+    ///         PathCommand::LineTo(Vector2D::new(2.0, 1.0)),
+    ///         PathCommand::LineTo(Vector2D::new(2.0, 0.0)),
+    ///         PathCommand::LineTo(Vector2D::ZERO),
+    ///         PathCommand::Close,
+    ///     ]
+    /// );
+    /// ```
+    pub fn append_reversed(&mut self, other: &Path) -> &mut Self {
+        let joins = !self.is_empty();
+        for (i, cmd) in other.reversed().commands.into_iter().enumerate() {
+            match cmd {
+                PathCommand::MoveTo(p) if i == 0 && joins => self.push(PathCommand::LineTo(p)),
+                _ => self.push(cmd),
+            }
+        }
+        self
+    }
+
+    /// Splits the path into its subpaths.
+    ///
+    /// A subpath starts at each `MoveTo` and keeps every command up to the
+    /// next subpath. Drawing on after a `Close` without a move also starts
+    /// a subpath, from the point the close returned to; that subpath gets
+    /// an explicit `MoveTo` there so it draws the same on its own. Apart
+    /// from those added moves, concatenating the results reproduces the
+    /// original command sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::ZERO)
+    ///     .line_to(Vector2D::new(1.0, 0.0))
+    ///     .move_to(Vector2D::new(0.0, 1.0))
+    ///     .line_to(Vector2D::new(1.0, 1.0));
+    ///
+    /// let parts = path.subpaths();
+    /// assert_eq!(parts.len(), 2);
+    /// assert_eq!(parts[1].points(), vec![Vector2D::new(0.0, 1.0), Vector2D::new(1.0, 1.0)]);
+    /// ```
+    pub fn subpaths(&self) -> Vec<Path> {
+        self.subpath_ranges()
+            .into_iter()
+            .map(|(start, range)| {
+                let mut subpath = Path::new();
+                // Past the first, only a subpath following a close can start
+                // without a move
+                let after_close = range.start > 0
+                    && !matches!(self.commands[range.start], PathCommand::MoveTo(_));
+                if after_close {
+                    subpath.move_to(start);
+                }
+                subpath
+                    .commands
+                    .extend(self.commands[range].iter().cloned());
+                subpath
+            })
+            .collect()
+    }
+
+    /// Returns the number of subpaths, as split by [`Path::subpaths`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::new();
+    /// assert_eq!(path.subpath_count(), 0);
+    ///
+    /// path.move_to(Vector2D::ZERO).line_to(Vector2D::new(1.0, 0.0)).close();
+    /// assert_eq!(path.subpath_count(), 1);
+    /// ```
+    pub fn subpath_count(&self) -> usize {
+        self.subpath_ranges().len()
+    }
+
+    /// Returns the command range of each subpath with the point it starts
+    /// from.
+    ///
+    /// A subpath starts at each `MoveTo`, at a drawing command that follows
+    /// a `Close`, and at the first command if it is not a move. After a
+    /// close the pen is back at the start of the closed subpath, and before
+    /// any move it is at the origin.
+    fn subpath_ranges(&self) -> Vec<(Vector2D, Range<usize>)> {
+        let mut ranges: Vec<(Vector2D, Range<usize>)> = Vec::new();
+        let mut current = Vector2D::ZERO;
+        let mut subpath_start = Vector2D::ZERO;
+        for (i, cmd) in self.commands.iter().enumerate() {
+            let after_close = i > 0 && matches!(self.commands[i - 1], PathCommand::Close);
+            match cmd {
+                PathCommand::MoveTo(p) => {
+                    current = *p;
+                    subpath_start = *p;
+                    ranges.push((current, i..i));
+                }
+                _ if i == 0 || after_close => ranges.push((current, i..i)),
+                _ => {}
+            }
+            ranges.last_mut().expect("subpath pushed above").1.end = i + 1;
+            match cmd {
+                PathCommand::LineTo(to)
+                | PathCommand::QuadraticTo { to, .. }
+                | PathCommand::CubicTo { to, .. } => current = *to,
+                PathCommand::Close => current = subpath_start,
+                PathCommand::MoveTo(_) => {}
+            }
+        }
+        ranges
+    }
+
+    /// Scales the path uniformly about the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::polyline(&[Vector2D::new(1.0, 2.0)], false);
+    /// path.scale(3.0);
+    /// assert_eq!(path.points(), vec![Vector2D::new(3.0, 6.0)]);
+    /// ```
+    pub fn scale(&mut self, factor: f64) -> &mut Self {
+        self.apply_transform(&Transform::scale(factor, factor));
+        self
+    }
+
+    /// Rotates the path counter-clockwise about the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Radians, Vector2D};
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::polyline(&[Vector2D::new(1.0, 0.0)], false);
+    /// path.rotate(Radians(std::f64::consts::FRAC_PI_2));
+    /// assert!((path.points()[0] - Vector2D::new(0.0, 1.0)).magnitude() < 1e-10);
+    /// ```
+    pub fn rotate(&mut self, angle: Radians) -> &mut Self {
+        self.apply_transform(&Transform::rotate(angle.0));
+        self
+    }
+
+    /// Moves every point of the path by `delta`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::polyline(&[Vector2D::new(1.0, 2.0)], false);
+    /// path.translate(Vector2D::new(1.0, -1.0));
+    /// assert_eq!(path.points(), vec![Vector2D::new(2.0, 1.0)]);
+    /// ```
+    pub fn translate(&mut self, delta: Vector2D) -> &mut Self {
+        self.apply_transform(&Transform::translate(delta.x, delta.y));
+        self
+    }
+
+    /// Returns a transformed copy of the path, leaving `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Transform, Vector2D};
+    /// use manim_rs::renderer::{Path, PathCommand};
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::new(1.0, 0.0));
+    ///
+    /// let moved = path.transformed(&Transform::translate(0.0, 2.0));
+    /// assert_eq!(moved.commands()[0], PathCommand::MoveTo(Vector2D::new(1.0, 2.0)));
+    /// assert_eq!(path.commands()[0], PathCommand::MoveTo(Vector2D::new(1.0, 0.0)));
+    /// ```
+    pub fn transformed(&self, transform: &Transform) -> Path {
+        let mut path = self.clone();
+        path.apply_transform(transform);
+        path
+    }
+
+    /// Returns the signed area enclosed by the path.
+    ///
+    /// Curves are flattened into line segments and every subpath is treated
+    /// as closed. Counterclockwise subpaths contribute positive area and
+    /// clockwise subpaths negative area, so a shape with a reversed hole
+    /// reports its net filled area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::new(0.0, 0.0))
+    ///     .line_to(Vector2D::new(2.0, 0.0))
+    ///     .line_to(Vector2D::new(2.0, 2.0))
+    ///     .line_to(Vector2D::new(0.0, 2.0))
+    ///     .close();
+    ///
+    /// assert_eq!(path.signed_area(), 4.0);
+    /// ```
+    pub fn signed_area(&self) -> f64 {
+        self.flatten()
+            .iter()
+            .map(|polygon| polygon_signed_area(polygon))
+            .sum()
+    }
+
+    /// Returns `true` if the path winds clockwise.
+    ///
+    /// For a path with several subpaths this is the orientation of the one
+    /// enclosing the most area, such as the outer contour of a shape with
+    /// holes, so the answer does not depend on how the holes wind.
+    /// Degenerate paths with zero area are not clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::new(0.0, 0.0))
+    ///     .line_to(Vector2D::new(0.0, 1.0))
+    ///     .line_to(Vector2D::new(1.0, 1.0))
+    ///     .close();
+    ///
+    /// assert!(path.is_clockwise());
+    /// ```
+    pub fn is_clockwise(&self) -> bool {
+        self.flatten()
+            .iter()
+            .map(|polygon| polygon_signed_area(polygon))
+            .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+            .is_some_and(|area| area < 0.0)
+    }
+
+    /// Returns the winding number of the path around a point.
+    ///
+    /// Counts how many times the path winds counterclockwise around `point`
+    /// (clockwise loops count negatively). Every subpath is treated as
+    /// closed. A non-zero result means the point is inside under the
+    /// non-zero fill rule; an odd result means inside under even-odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vector2D::new(-1.0, -1.0))
+    ///     .line_to(Vector2D::new(1.0, -1.0))
+    ///     .line_to(Vector2D::new(1.0, 1.0))
+    ///     .line_to(Vector2D::new(-1.0, 1.0))
+    ///     .close();
+    ///
+    /// assert_eq!(path.winding_number(Vector2D::ZERO), 1);
+    /// assert_eq!(path.winding_number(Vector2D::new(5.0, 0.0)), 0);
+    /// ```
+    pub fn winding_number(&self, point: Vector2D) -> i32 {
+        let mut winding = 0;
+        for polygon in self.flatten() {
+            let n = polygon.len();
+            for i in 0..n {
+                let a = polygon[i];
+                let b = polygon[(i + 1) % n];
+                let side = (b - a).cross(point - a);
+                if a.y <= point.y {
+                    // Upward crossing with the point on the left
+                    if b.y > point.y && side > 0.0 {
+                        winding += 1;
+                    }
+                } else if b.y <= point.y && side < 0.0 {
+                    // Downward crossing with the point on the right
+                    winding -= 1;
+                }
+            }
+        }
+        winding
+    }
+
+    /// Returns a hash of the path's geometry.
+    ///
+    /// Coordinates are rounded to a millionth of a unit before hashing, so
+    /// paths that differ only by floating point noise share a hash. Caches
+    /// of tessellations or other derived data can be keyed on it. The hash
+    /// is a fixed FNV-1a over the quantized commands, so it is stable across
+    /// builds and platforms and may be persisted. Equal hashes do not prove
+    /// equal paths; compare with `==` where a collision would matter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::Path;
+    ///
+    /// let points = [Vector2D::ZERO, Vector2D::new(1.0, 0.0), Vector2D::new(0.0, 1.0)];
+    /// let a = Path::polyline(&points, true);
+    /// let mut b = Path::polyline(&points, true);
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    ///
+    /// b.translate(Vector2D::new(1e-9, 0.0));
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    ///
+    /// b.translate(Vector2D::new(0.5, 0.0));
+    /// assert_ne!(a.content_hash(), b.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+        hasher.write_u64(self.commands.len() as u64);
+        for cmd in &self.commands {
+            match cmd {
+                PathCommand::MoveTo(p) => {
+                    hasher.write_u8(0);
+                    hash_point(*p, &mut hasher);
+                }
+                PathCommand::LineTo(p) => {
+                    hasher.write_u8(1);
+                    hash_point(*p, &mut hasher);
+                }
+                PathCommand::QuadraticTo { control, to } => {
+                    hasher.write_u8(2);
+                    hash_point(*control, &mut hasher);
+                    hash_point(*to, &mut hasher);
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    hasher.write_u8(3);
+                    hash_point(*control1, &mut hasher);
+                    hash_point(*control2, &mut hasher);
+                    hash_point(*to, &mut hasher);
+                }
+                PathCommand::Close => hasher.write_u8(4),
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Flattens the path into one polyline per subpath.
+    ///
+    /// Curves are sampled as by [`Path::flattened`]. Closing segments are
+    /// implicit: the returned polylines do not repeat their first point.
+    pub(crate) fn flatten(&self) -> Vec<Vec<Vector2D>> {
+        let mut polylines: Vec<Vec<Vector2D>> = Vec::new();
+        let mut current: Vec<Vector2D> = Vec::new();
+
+        for cmd in &self.flattened().commands {
+            match cmd {
+                PathCommand::MoveTo(p) => {
+                    if current.len() > 1 {
+                        polylines.push(std::mem::take(&mut current));
+                    }
+                    current.clear();
+                    current.push(*p);
+                }
+                // Only line segments remain after flattening
+                PathCommand::LineTo(p)
+                | PathCommand::QuadraticTo { to: p, .. }
+                | PathCommand::CubicTo { to: p, .. } => current.push(*p),
+                PathCommand::Close => {
+                    if current.len() > 1 {
+                        let start = current[0];
+                        polylines.push(std::mem::take(&mut current));
+                        // Drawing may continue from the subpath start
+                        current.push(start);
+                    }
+                }
+            }
+        }
+        if current.len() > 1 {
+            polylines.push(current);
+        }
+
+        // Drop explicit closing points that duplicate the start
+        for polyline in &mut polylines {
+            if polyline.len() > 1 && polyline.first() == polyline.last() {
+                polyline.pop();
+            }
+        }
+        polylines
+    }
+}
+
+impl Default for Path {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for Path {
+    fn eq(&self, other: &Self) -> bool {
+        self.commands == other.commands
+    }
+}
+
+impl FromIterator<PathCommand> for Path {
+    fn from_iter<I: IntoIterator<Item = PathCommand>>(iter: I) -> Self {
+        Self::from_commands(iter)
+    }
+}
+
+impl Extend<PathCommand> for Path {
+    fn extend<I: IntoIterator<Item = PathCommand>>(&mut self, iter: I) {
+        for cmd in iter {
+            self.push(cmd);
+        }
+    }
+}
+
+/// One subpath, as collected by [`Path::reversed`].
+struct Subpath {
+    start: Vector2D,
+    /// Drawing commands, each paired with the point it starts from
+    segments: Vec<(Vector2D, PathCommand)>,
+    closed: bool,
+}
+
+impl Subpath {
+    fn new(start: Vector2D) -> Self {
+        Self {
+            start,
+            segments: Vec::new(),
+            closed: false,
+        }
+    }
+}
+
+/// A helper for building paths with cursor tracking.
+///
+/// [`PathCursor`] maintains the current pen position, making it easier to build
+/// paths with relative movements.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::Vector2D;
+/// use manim_rs::renderer::PathCursor;
+///
+/// let mut cursor = PathCursor::new();
+/// cursor.move_to(Vector2D::new(0.0, 0.0))
+///       .line_to(Vector2D::new(1.0, 0.0))
+///       .relative_line_to(Vector2D::new(0.0, 1.0)); // Goes to (1.0, 1.0)
+///
+/// let path = cursor.into_path();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PathCursor {
+    path: Path,
+    current: Vector2D,
+}
+
+impl PathCursor {
+    /// Creates a new cursor at the origin.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            path: Path::new(),
+            current: Vector2D::ZERO,
+        }
+    }
+
+    /// Returns the current cursor position.
+    #[inline]
+    pub fn position(&self) -> Vector2D {
+        self.current
+    }
+
+    /// Moves the cursor to an absolute position.
+    #[inline]
+    pub fn move_to(&mut self, point: Vector2D) -> &mut Self {
+        self.current = point;
+        self.path.move_to(point);
+        self
+    }
+
+    /// Draws a line to an absolute position.
+    #[inline]
+    pub fn line_to(&mut self, point: Vector2D) -> &mut Self {
+        self.current = point;
+        self.path.line_to(point);
+        self
+    }
+
+    /// Draws a line relative to the current position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::PathCursor;
+    ///
+    /// let mut cursor = PathCursor::new();
+    /// cursor.move_to(Vector2D::new(1.0, 1.0))
+    ///       .relative_line_to(Vector2D::new(2.0, 3.0)); // Goes to (3.0, 4.0)
+    ///
+    /// assert_eq!(cursor.position(), Vector2D::new(3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn relative_line_to(&mut self, delta: Vector2D) -> &mut Self {
+        self.current = self.current + delta;
+        self.path.line_to(self.current);
+        self
+    }
+
+    /// Draws a quadratic Bézier curve to an absolute position.
     #[inline]
-    pub fn relative_line_to(&mut self, delta: Vector2D) -> &mut Self {
-        self.current = self.current + delta;
-        self.path.line_to(self.current);
-        self
-    }
-
-    /// Draws a quadratic Bézier curve to an absolute position.
-    #[inline]
     pub fn quadratic_to(&mut self, control: Vector2D, to: Vector2D) -> &mut Self {
         self.current = to;
         self.path.quadratic_to(control, to);
         self
     }
 
-    /// Draws a cubic Bézier curve to an absolute position.
-    #[inline]
-    pub fn cubic_to(&mut self, control1: Vector2D, control2: Vector2D, to: Vector2D) -> &mut Self {
-        self.current = to;
-        self.path.cubic_to(control1, control2, to);
+    /// Draws a cubic Bézier curve to an absolute position.
+    #[inline]
+    pub fn cubic_to(&mut self, control1: Vector2D, control2: Vector2D, to: Vector2D) -> &mut Self {
+        self.current = to;
+        self.path.cubic_to(control1, control2, to);
+        self
+    }
+
+    /// Closes the current subpath.
+    #[inline]
+    pub fn close(&mut self) -> &mut Self {
+        self.path.close();
+        self
+    }
+
+    /// Appends a circular arc as a new subpath.
+    ///
+    /// The arc runs from angle `start` to `end` around `center`,
+    /// counter-clockwise when `end > start` and clockwise otherwise. It is
+    /// split into cubic Bézier segments spanning at most a quarter turn, and
+    /// the cursor is left where the last segment ends.
+    ///
+    /// A sweep longer than a full turn is clamped to one turn, since further
+    /// turns would only retrace the circle. The arc then closes back on its
+    /// start point, so the cursor ends there rather than at the point for
+    /// `end`. Non-finite arguments append nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::{Radians, Vector2D};
+    /// use manim_rs::renderer::PathCursor;
+    ///
+    /// let mut cursor = PathCursor::new();
+    /// cursor.arc(Vector2D::ZERO, 1.0, Radians(0.0), Radians(std::f64::consts::PI));
+    ///
+    /// assert!((cursor.position() - Vector2D::new(-1.0, 0.0)).magnitude() < 1e-10);
+    /// ```
+    pub fn arc(
+        &mut self,
+        center: Vector2D,
+        radius: f64,
+        start: Radians,
+        end: Radians,
+    ) -> &mut Self {
+        let finite = [center.x, center.y, radius, start.0, end.0]
+            .iter()
+            .all(|value| value.is_finite());
+        if !finite {
+            return self;
+        }
+
+        let point_at =
+            |angle: f64| center + Vector2D::new(radius * angle.cos(), radius * angle.sin());
+        self.move_to(point_at(start.0));
+
+        let sweep = (end.0 - start.0).clamp(-TAU, TAU);
+        let segments = ((sweep.abs() / FRAC_PI_2).ceil() as usize).max(1);
+        let step = sweep / segments as f64;
+        // Control handle length for a segment of `step` radians
+        let handle = 4.0 / 3.0 * (step / 4.0).tan() * radius;
+
+        for i in 0..segments {
+            let from = start.0 + i as f64 * step;
+            let to = from + step;
+            let tangent = |angle: f64| Vector2D::new(-angle.sin(), angle.cos()) * handle;
+            self.cubic_to(
+                point_at(from) + tangent(from),
+                point_at(to) - tangent(to),
+                point_at(to),
+            );
+        }
+        self
+    }
+
+    /// Appends a full circle as a new closed subpath.
+    ///
+    /// The circle starts and ends at its rightmost point, where the cursor
+    /// is left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::PathCursor;
+    ///
+    /// let mut cursor = PathCursor::new();
+    /// cursor.circle(Vector2D::ZERO, 2.0);
+    ///
+    /// assert_eq!(cursor.position(), Vector2D::new(2.0, 0.0));
+    /// assert_eq!(cursor.path().len(), 6); // move, 4 cubics, close
+    /// ```
+    pub fn circle(&mut self, center: Vector2D, radius: f64) -> &mut Self {
+        self.arc(center, radius, Radians(0.0), Radians(TAU));
+        self.close();
+        // The last cubic lands within rounding of the start; snap to it
+        self.current = center + Vector2D::new(radius, 0.0);
         self
     }
 
-    /// Closes the current subpath.
-    #[inline]
-    pub fn close(&mut self) -> &mut Self {
-        self.path.close();
+    /// Appends an axis-aligned rectangle as a new closed subpath.
+    ///
+    /// The outline runs counter-clockwise from `min`, where the cursor is
+    /// left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::PathCursor;
+    ///
+    /// let mut cursor = PathCursor::new();
+    /// cursor.rectangle(Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 1.0));
+    ///
+    /// assert_eq!(cursor.path().bounding_box().width(), 2.0);
+    /// assert_eq!(cursor.position(), Vector2D::new(0.0, 0.0));
+    /// ```
+    pub fn rectangle(&mut self, min: Vector2D, max: Vector2D) -> &mut Self {
+        self.move_to(min)
+            .line_to(Vector2D::new(max.x, min.y))
+            .line_to(max)
+            .line_to(Vector2D::new(min.x, max.y))
+            .close();
+        self.current = min;
         self
     }
 
@@ -602,6 +1773,32 @@ mod tests {
         assert_relative_eq!(bounds.height(), 3.0);
     }
 
+    #[test]
+    fn test_path_bounding_box_ignores_control_points() {
+        let magic = 0.551_915_024_493_510_6;
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(1.0, 0.0))
+            .cubic_to(
+                Vector2D::new(1.0, magic),
+                Vector2D::new(magic, 1.0),
+                Vector2D::new(0.0, 1.0),
+            )
+            .cubic_to(
+                Vector2D::new(-magic, 1.0),
+                Vector2D::new(-1.0, magic),
+                Vector2D::new(-1.0, 0.0),
+            )
+            .quadratic_to(Vector2D::new(-1.0, -2.0), Vector2D::new(1.0, -1.0))
+            .close();
+
+        let bounds = path.bounding_box();
+        assert_relative_eq!(bounds.width(), 2.0, epsilon = 1e-10);
+        assert_relative_eq!(bounds.max().y, 1.0, epsilon = 1e-10);
+        // The quadratic dips below its end point but not to its control point
+        assert!(bounds.min().y < -1.0);
+        assert!(bounds.min().y > -2.0);
+    }
+
     #[test]
     fn test_path_bounding_box_cached() {
         let mut path = Path::new();
@@ -653,6 +1850,143 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_path_transformed_leaves_original() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(1.0, 0.0));
+        let original = path.clone();
+
+        let rotated = path.transformed(&Transform::rotate(std::f64::consts::PI / 2.0));
+
+        assert_eq!(path, original);
+        match rotated.commands()[1] {
+            PathCommand::LineTo(p) => {
+                assert_relative_eq!(p.x, 0.0, epsilon = 1e-10);
+                assert_relative_eq!(p.y, 1.0);
+            }
+            _ => panic!("Expected LineTo"),
+        }
+    }
+
+    #[test]
+    fn test_path_polyline() {
+        let points = [
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+        ];
+        let path = Path::polyline(&points, true);
+
+        assert_eq!(path.len(), 4);
+        assert_eq!(path.commands()[0], PathCommand::MoveTo(points[0]));
+        assert_eq!(path.commands().last(), Some(&PathCommand::Close));
+        assert_eq!(path.points(), points.to_vec());
+
+        let open = Path::polyline(&points, false);
+        assert_eq!(
+            open.commands().last(),
+            Some(&PathCommand::LineTo(points[2]))
+        );
+        assert!(Path::polyline(&[], true).is_empty());
+    }
+
+    #[test]
+    fn test_path_points_skips_controls() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .cubic_to(
+                Vector2D::new(1.0, 1.0),
+                Vector2D::new(2.0, 1.0),
+                Vector2D::new(3.0, 0.0),
+            )
+            .close();
+
+        assert_eq!(
+            path.points(),
+            vec![Vector2D::new(0.0, 0.0), Vector2D::new(3.0, 0.0)]
+        );
+    }
+
+    fn ccw_square() -> Path {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(-1.0, -1.0))
+            .line_to(Vector2D::new(1.0, -1.0))
+            .line_to(Vector2D::new(1.0, 1.0))
+            .line_to(Vector2D::new(-1.0, 1.0))
+            .close();
+        path
+    }
+
+    #[test]
+    fn test_path_orientation_ccw() {
+        let path = ccw_square();
+
+        assert!(!path.is_clockwise());
+        assert_relative_eq!(path.signed_area(), 4.0);
+        assert_eq!(path.winding_number(Vector2D::new(0.2, 0.3)), 1);
+        assert_eq!(path.winding_number(Vector2D::new(3.0, 0.0)), 0);
+    }
+
+    #[test]
+    fn test_path_orientation_cw() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(-1.0, -1.0))
+            .line_to(Vector2D::new(-1.0, 1.0))
+            .line_to(Vector2D::new(1.0, 1.0))
+            .line_to(Vector2D::new(1.0, -1.0))
+            .close();
+
+        assert!(path.is_clockwise());
+        assert_relative_eq!(path.signed_area(), -4.0);
+        assert_eq!(path.winding_number(Vector2D::new(0.2, 0.3)), -1);
+    }
+
+    #[test]
+    fn test_path_winding_number_nested() {
+        // Two CCW loops around the same point wind twice
+        let mut path = ccw_square();
+        path.move_to(Vector2D::new(-0.5, -0.5))
+            .line_to(Vector2D::new(0.5, -0.5))
+            .line_to(Vector2D::new(0.5, 0.5))
+            .line_to(Vector2D::new(-0.5, 0.5))
+            .close();
+
+        assert_eq!(path.winding_number(Vector2D::ZERO), 2);
+        assert_eq!(path.winding_number(Vector2D::new(0.8, 0.0)), 1);
+    }
+
+    #[test]
+    fn test_path_signed_area_curves() {
+        let magic = 0.551_915_024_493_510_6;
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(1.0, 0.0))
+            .cubic_to(
+                Vector2D::new(1.0, magic),
+                Vector2D::new(magic, 1.0),
+                Vector2D::new(0.0, 1.0),
+            )
+            .cubic_to(
+                Vector2D::new(-magic, 1.0),
+                Vector2D::new(-1.0, magic),
+                Vector2D::new(-1.0, 0.0),
+            )
+            .cubic_to(
+                Vector2D::new(-1.0, -magic),
+                Vector2D::new(-magic, -1.0),
+                Vector2D::new(0.0, -1.0),
+            )
+            .cubic_to(
+                Vector2D::new(magic, -1.0),
+                Vector2D::new(1.0, -magic),
+                Vector2D::new(1.0, 0.0),
+            )
+            .close();
+
+        assert_relative_eq!(path.signed_area(), std::f64::consts::PI, epsilon = 0.01);
+        assert_eq!(path.winding_number(Vector2D::ZERO), 1);
+    }
+
     #[test]
     fn test_path_clone() {
         let mut path1 = Path::new();
@@ -750,4 +2084,464 @@ mod tests {
 
         assert_eq!(cursor.position(), Vector2D::new(1.0, 0.0));
     }
+
+    #[test]
+    fn test_path_translate_shifts_anchors() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .quadratic_to(Vector2D::new(1.0, 2.0), Vector2D::new(2.0, 0.0))
+            .line_to(Vector2D::new(3.0, 1.0));
+        let before = path.points();
+
+        let delta = Vector2D::new(-1.5, 4.0);
+        path.translate(delta);
+
+        let expected: Vec<Vector2D> = before.iter().map(|&p| p + delta).collect();
+        assert_eq!(path.points(), expected);
+    }
+
+    #[test]
+    fn test_path_scale_then_translate() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(1.0, 1.0))
+            .line_to(Vector2D::new(2.0, -1.0));
+
+        path.scale(2.0).translate(Vector2D::new(1.0, 0.0));
+        assert_eq!(
+            path.points(),
+            vec![Vector2D::new(3.0, 2.0), Vector2D::new(5.0, -2.0)]
+        );
+
+        path.rotate(Radians(std::f64::consts::PI));
+        let points = path.points();
+        assert_relative_eq!(points[0].x, -3.0, epsilon = 1e-10);
+        assert_relative_eq!(points[0].y, -2.0, epsilon = 1e-10);
+        assert_relative_eq!(path.bounding_box().max.x, -3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_add_cubic_round_trips_through_cubics() {
+        let curve = CubicBezier::new(
+            Vector2D::new(1.0, 2.0),
+            Vector2D::new(3.0, 5.0),
+            Vector2D::new(-2.0, 4.0),
+            Vector2D::new(0.5, -1.0),
+        );
+
+        let mut path = Path::new();
+        path.add_cubic(&curve);
+
+        assert_eq!(path.commands()[0], PathCommand::MoveTo(curve.p0));
+        let cubics = path.cubics();
+        assert_eq!(cubics.len(), 1);
+        assert_eq!(cubics[0].p0, curve.p0);
+        assert_eq!(cubics[0].p1, curve.p1);
+        assert_eq!(cubics[0].p2, curve.p2);
+        assert_eq!(cubics[0].p3, curve.p3);
+    }
+
+    #[test]
+    fn test_add_quadratic_is_elevated_by_cubics() {
+        let quad = QuadraticBezier::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(3.0, 3.0),
+            Vector2D::new(6.0, 0.0),
+        );
+
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(-1.0, 0.0))
+            .line_to(Vector2D::new(0.0, 0.0))
+            .add_quadratic(&quad);
+
+        // The pen is already at the start, so no MoveTo is inserted
+        assert_eq!(path.len(), 3);
+        assert_eq!(path.cubics(), vec![quad.to_cubic()]);
+    }
+
+    #[test]
+    fn test_add_cubic_moves_when_pen_is_elsewhere() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(5.0, 5.0));
+        path.add_cubic(&CubicBezier::new(
+            Vector2D::ZERO,
+            Vector2D::new(0.0, 1.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(1.0, 0.0),
+        ));
+
+        assert_eq!(path.commands()[1], PathCommand::MoveTo(Vector2D::ZERO));
+    }
+
+    #[test]
+    fn test_add_cubic_tracks_pen_through_close_and_transform() {
+        let curve = CubicBezier::new(
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(2.0, 1.0),
+            Vector2D::new(2.0, 0.0),
+        );
+
+        // Closing returns the pen to the subpath start
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(1.0, 0.0))
+            .line_to(Vector2D::new(1.0, 3.0))
+            .close();
+        path.add_cubic(&curve);
+        assert_eq!(path.len(), 4);
+
+        // The pen follows the transformed geometry
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO);
+        path.apply_transform(&Transform::translate(1.0, 0.0));
+        path.add_cubic(&curve);
+        assert_eq!(path.len(), 2);
+
+        let mut path: Path = [PathCommand::MoveTo(Vector2D::new(1.0, 0.0))]
+            .into_iter()
+            .collect();
+        path.extend([PathCommand::LineTo(Vector2D::new(2.0, 2.0))]);
+        path.add_cubic(&curve);
+        assert_eq!(
+            path.commands()[2],
+            PathCommand::MoveTo(Vector2D::new(1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_cursor_circle_is_closed_four_cubic_subpath() {
+        let mut cursor = PathCursor::new();
+        cursor.circle(Vector2D::new(1.0, 1.0), 2.0);
+        let path = cursor.into_path();
+
+        let commands = path.commands();
+        assert_eq!(commands.len(), 6);
+        assert_eq!(commands[0], PathCommand::MoveTo(Vector2D::new(3.0, 1.0)));
+        assert_eq!(
+            commands
+                .iter()
+                .filter(|c| matches!(c, PathCommand::CubicTo { .. }))
+                .count(),
+            4
+        );
+        assert_eq!(commands[5], PathCommand::Close);
+
+        let bounds = path.bounding_box();
+        assert_relative_eq!(bounds.min().x, -1.0, epsilon = 1e-9);
+        assert_relative_eq!(bounds.max().y, 3.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_cursor_rectangle_and_circle_make_two_subpaths() {
+        let mut cursor = PathCursor::new();
+        cursor
+            .rectangle(Vector2D::new(-2.0, -2.0), Vector2D::new(2.0, 2.0))
+            .circle(Vector2D::ZERO, 1.0);
+        let path = cursor.into_path();
+
+        let move_count = path
+            .commands()
+            .iter()
+            .filter(|c| matches!(c, PathCommand::MoveTo(_)))
+            .count();
+        let close_count = path
+            .commands()
+            .iter()
+            .filter(|c| matches!(c, PathCommand::Close))
+            .count();
+        assert_eq!(move_count, 2);
+        assert_eq!(close_count, 2);
+    }
+
+    #[test]
+    fn test_cursor_arc_clamps_long_and_rejects_non_finite_sweeps() {
+        let mut cursor = PathCursor::new();
+        cursor.arc(Vector2D::ZERO, 1.0, Radians(0.0), Radians(1e300));
+        // Move plus one full turn of quarter-turn cubics
+        assert_eq!(cursor.path().len(), 5);
+
+        let mut cursor = PathCursor::new();
+        cursor
+            .arc(Vector2D::ZERO, 1.0, Radians(0.0), Radians(f64::INFINITY))
+            .arc(Vector2D::ZERO, f64::NAN, Radians(0.0), Radians(1.0));
+        assert!(cursor.path().is_empty());
+    }
+
+    #[test]
+    fn test_cursor_arc_clockwise_ends_at_end_angle() {
+        let mut cursor = PathCursor::new();
+        cursor.arc(Vector2D::ZERO, 1.0, Radians(0.0), Radians(-FRAC_PI_2));
+
+        assert_eq!(cursor.path().len(), 2);
+        assert_relative_eq!(cursor.position().x, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(cursor.position().y, -1.0, epsilon = 1e-12);
+        // Stays in the lower half
+        assert!(cursor.path().bounding_box().max().y < 1e-12);
+    }
+
+    #[test]
+    fn test_partial_splits_curves_and_close() {
+        let mut cursor = PathCursor::new();
+        cursor.circle(Vector2D::ZERO, 1.0);
+        let circle = cursor.into_path();
+
+        let half = circle.partial(0.5);
+        let end = half.current_point().unwrap();
+        assert!((end - Vector2D::new(-1.0, 0.0)).magnitude() < 1e-3);
+        assert_eq!(circle.partial(1.0).commands(), circle.commands());
+
+        let mut square = Path::new();
+        square
+            .move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(1.0, 0.0))
+            .line_to(Vector2D::new(1.0, 1.0))
+            .line_to(Vector2D::new(0.0, 1.0))
+            .close();
+        // The closing edge counts toward the length
+        let almost = square.partial(0.875);
+        assert_eq!(almost.current_point(), Some(Vector2D::new(0.0, 0.5)));
+        assert!(!almost.commands().contains(&PathCommand::Close));
+    }
+
+    #[test]
+    fn test_from_commands_matches_builder() {
+        let mut built = Path::new();
+        built
+            .move_to(Vector2D::new(0.0, 0.0))
+            .quadratic_to(Vector2D::new(1.0, 1.0), Vector2D::new(2.0, 0.0))
+            .close();
+
+        let commands = built.commands().to_vec();
+        assert_eq!(Path::from_commands(commands.clone()), built);
+        assert_eq!(commands.into_iter().collect::<Path>(), built);
+    }
+
+    #[test]
+    fn test_extend_appends_and_clears_cache() {
+        let mut path = Path::polyline(&[Vector2D::ZERO, Vector2D::new(1.0, 1.0)], false);
+        path.cached_bounds = Some(path.bounding_box());
+
+        path.extend([
+            PathCommand::LineTo(Vector2D::new(3.0, -1.0)),
+            PathCommand::Close,
+        ]);
+        assert!(path.cached_bounds.is_none());
+        assert_eq!(path.len(), 4);
+        assert_eq!(path.commands()[3], PathCommand::Close);
+        assert_eq!(path.bounding_box().max(), Vector2D::new(3.0, 1.0));
+        assert_eq!(path.bounding_box().min(), Vector2D::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn test_reversed_swaps_controls_and_keeps_closed() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .cubic_to(
+                Vector2D::new(0.0, 1.0),
+                Vector2D::new(1.0, 1.0),
+                Vector2D::new(1.0, 0.0),
+            )
+            .quadratic_to(Vector2D::new(0.5, -1.0), Vector2D::new(0.0, -0.5))
+            .close()
+            .move_to(Vector2D::new(5.0, 5.0))
+            .line_to(Vector2D::new(6.0, 5.0));
+
+        let reversed = path.reversed();
+        assert_eq!(
+            reversed.commands(),
+            &[
+                PathCommand::MoveTo(Vector2D::new(6.0, 5.0)),
+                PathCommand::LineTo(Vector2D::new(5.0, 5.0)),
+                PathCommand::MoveTo(Vector2D::new(0.0, -0.5)),
+                PathCommand::QuadraticTo {
+                    control: Vector2D::new(0.5, -1.0),
+                    to: Vector2D::new(1.0, 0.0),
+                },
+                PathCommand::CubicTo {
+                    control1: Vector2D::new(1.0, 1.0),
+                    control2: Vector2D::new(0.0, 1.0),
+                    to: Vector2D::ZERO,
+                },
+                PathCommand::Close,
+            ]
+        );
+        assert_eq!(reversed.reversed(), path);
+    }
+
+    #[test]
+    fn test_append_reversed_builds_closed_region() {
+        let top = Path::polyline(
+            &[
+                Vector2D::new(0.0, 1.0),
+                Vector2D::new(1.0, 2.0),
+                Vector2D::new(2.0, 1.0),
+            ],
+            false,
+        );
+        let bottom = Path::polyline(&[Vector2D::new(0.0, -1.0), Vector2D::new(2.0, 0.0)], false);
+
+        let mut region = top.clone();
+        region.append_reversed(&bottom).close();
+
+        let moves = region
+            .commands()
+            .iter()
+            .filter(|cmd| matches!(cmd, PathCommand::MoveTo(_)))
+            .count();
+        assert_eq!(moves, 1);
+        assert_eq!(region.commands().last(), Some(&PathCommand::Close));
+        assert_eq!(region.points().last(), Some(&Vector2D::new(0.0, -1.0)));
+
+        let bbox = region.bounding_box();
+        assert_eq!(bbox.min(), Vector2D::new(0.0, -1.0));
+        assert_eq!(bbox.max(), Vector2D::new(2.0, 2.0));
+    }
+
+    #[test]
+    fn test_subpaths_of_annulus() {
+        let mut cursor = PathCursor::new();
+        cursor
+            .circle(Vector2D::ZERO, 2.0)
+            .circle(Vector2D::ZERO, 1.0);
+        let annulus = cursor.into_path();
+
+        assert_eq!(annulus.subpath_count(), 2);
+        let parts = annulus.subpaths();
+        assert_eq!(parts.len(), 2);
+        assert_relative_eq!(parts[0].bounding_box().width(), 4.0, epsilon = 1e-10);
+        assert_relative_eq!(parts[1].bounding_box().width(), 2.0, epsilon = 1e-10);
+
+        let rejoined: Path = parts.iter().flat_map(|p| p.commands().to_vec()).collect();
+        assert_eq!(rejoined, annulus);
+    }
+
+    #[test]
+    fn test_subpaths_without_leading_move() {
+        let path = Path::from_commands([
+            PathCommand::LineTo(Vector2D::new(1.0, 0.0)),
+            PathCommand::MoveTo(Vector2D::new(2.0, 0.0)),
+            PathCommand::LineTo(Vector2D::new(3.0, 0.0)),
+        ]);
+        assert_eq!(path.subpath_count(), 2);
+        assert_eq!(path.subpaths().len(), 2);
+        assert_eq!(Path::new().subpaths().len(), 0);
+    }
+
+    #[test]
+    fn test_subpaths_split_after_close() {
+        // The line after the close draws a new subpath from the start point
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(1.0, 1.0))
+            .line_to(Vector2D::new(2.0, 1.0))
+            .line_to(Vector2D::new(2.0, 2.0))
+            .close()
+            .line_to(Vector2D::new(0.0, 1.0));
+
+        assert_eq!(path.subpath_count(), 2);
+        let parts = path.subpaths();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 4);
+        assert_eq!(
+            parts[1].commands(),
+            &[
+                PathCommand::MoveTo(Vector2D::new(1.0, 1.0)),
+                PathCommand::LineTo(Vector2D::new(0.0, 1.0)),
+            ]
+        );
+
+        // Reversing agrees on the split: the open line comes first
+        let reversed = path.reversed();
+        assert_eq!(reversed.subpath_count(), 2);
+        assert_eq!(
+            reversed.subpaths()[0].points(),
+            vec![Vector2D::new(0.0, 1.0), Vector2D::new(1.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_content_hash_equal_paths() {
+        let mut a = PathCursor::new();
+        a.circle(Vector2D::new(1.0, 2.0), 3.0);
+        let mut b = PathCursor::new();
+        b.circle(Vector2D::new(1.0, 2.0), 3.0);
+        assert_eq!(a.path(), b.path());
+        assert_eq!(a.path().content_hash(), b.path().content_hash());
+    }
+
+    #[test]
+    fn test_content_hash_changes_with_geometry() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .quadratic_to(Vector2D::new(1.0, 1.0), Vector2D::new(2.0, 0.0));
+        let hash = path.content_hash();
+
+        assert_ne!(
+            path.transformed(&Transform::scale(2.0, 2.0)).content_hash(),
+            hash
+        );
+        assert_ne!(path.reversed().content_hash(), hash);
+
+        // Same points with a different command
+        let mut closed = path.clone();
+        closed.close();
+        assert_ne!(closed.content_hash(), hash);
+    }
+
+    #[test]
+    fn test_content_hash_is_fixed() {
+        // FNV-1a of the empty path's eight-byte command count
+        assert_eq!(Path::new().content_hash(), 0xa8c7_f832_281a_39c5);
+    }
+
+    #[test]
+    fn test_content_hash_ignores_jitter() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.25, 0.5))
+            .cubic_to(
+                Vector2D::new(1.0, 1.0),
+                Vector2D::new(2.0, 1.0),
+                Vector2D::new(3.0, 0.0),
+            )
+            .close();
+        let mut jittered = path.clone();
+        jittered.apply_function(|p| p + Vector2D::new(1e-9, -1e-9));
+        assert_ne!(path, jittered);
+        assert_eq!(path.content_hash(), jittered.content_hash());
+    }
+
+    #[test]
+    fn test_is_clockwise_ignores_hole_winding() {
+        let mut outer = PathCursor::new();
+        outer.rectangle(Vector2D::new(-2.0, -2.0), Vector2D::new(2.0, 2.0));
+        let outer = outer.into_path();
+        let mut hole = PathCursor::new();
+        hole.rectangle(Vector2D::new(-1.0, -1.0), Vector2D::new(1.0, 1.0));
+        let hole = hole.into_path();
+        assert!(!outer.is_clockwise());
+
+        // Hole wound the same way as the outer contour
+        let mut same = outer.clone();
+        same.extend(hole.commands().iter().cloned());
+        assert!(!same.is_clockwise());
+
+        // Hole wound the opposite way
+        let mut opposite = outer.clone();
+        opposite.extend(hole.reversed().commands().iter().cloned());
+        assert!(!opposite.is_clockwise());
+
+        assert!(opposite.reversed().is_clockwise());
+    }
+
+    #[test]
+    fn test_bounding_box_curve_after_close() {
+        // The curve starts at the subpath start (0, 0), not at (4, 0)
+        let mut path = Path::new();
+        path.move_to(Vector2D::new(0.0, 0.0))
+            .line_to(Vector2D::new(4.0, 0.0))
+            .line_to(Vector2D::new(4.0, 1.0))
+            .close()
+            .quadratic_to(Vector2D::new(-2.0, 0.0), Vector2D::new(0.0, 1.0));
+
+        let bbox = path.bounding_box();
+        assert_relative_eq!(bbox.min().x, -1.0, epsilon = 1e-9);
+    }
 }
@@ -20,16 +20,43 @@
 //! ```
 
 use crate::core::Color;
+use crate::renderer::fnv::Fnv1a;
+
+/// Number of quantization steps per unit used when hashing style values.
+///
+/// Values closer together than `1 / STYLE_KEY_PRECISION` hash identically,
+/// which absorbs floating point noise from repeated arithmetic.
+const STYLE_KEY_PRECISION: f64 = 10_000.0;
+
+/// Quantizes a float so that nearly-equal values hash to the same bucket.
+#[inline]
+fn quantize(value: f64) -> i64 {
+    (value * STYLE_KEY_PRECISION).round() as i64
+}
+
+/// Feeds an optional color into a hasher, quantizing each channel.
+fn hash_color(color: Option<Color>, hasher: &mut Fnv1a) {
+    match color {
+        Some(c) => {
+            hasher.write_u8(1);
+            for channel in [c.r, c.g, c.b, c.a] {
+                hasher.write_i64(quantize(channel));
+            }
+        }
+        None => hasher.write_u8(0),
+    }
+}
 
 /// Fill rule for path rendering.
 ///
 /// Determines which areas are considered "inside" a path when filling.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum PathFillRule {
     /// Non-zero winding rule (default for most graphics systems).
     ///
     /// A point is inside if a ray from the point crosses a non-zero net number
     /// of path segments.
+    #[default]
     NonZero,
 
     /// Even-odd rule.
@@ -39,12 +66,6 @@ pub enum PathFillRule {
     EvenOdd,
 }
 
-impl Default for PathFillRule {
-    fn default() -> Self {
-        Self::NonZero
-    }
-}
-
 /// Style configuration for path rendering.
 ///
 /// Controls stroke, fill, opacity, and fill rules for vector paths.
@@ -86,6 +107,12 @@ pub struct PathStyle {
 
     /// Overall opacity (0.0 = transparent, 1.0 = opaque)
     pub opacity: f64,
+
+    /// Opacity applied to the fill only, multiplied with `opacity`
+    fill_opacity: f64,
+
+    /// Opacity applied to the stroke only, multiplied with `opacity`
+    stroke_opacity: f64,
 }
 
 impl PathStyle {
@@ -106,6 +133,8 @@ impl PathStyle {
             fill_color: None,
             fill_rule: PathFillRule::default(),
             opacity: 1.0,
+            fill_opacity: 1.0,
+            stroke_opacity: 1.0,
         }
     }
 
@@ -126,6 +155,8 @@ impl PathStyle {
             fill_color: Some(color),
             fill_rule: PathFillRule::default(),
             opacity: 1.0,
+            fill_opacity: 1.0,
+            stroke_opacity: 1.0,
         }
     }
 
@@ -162,6 +193,42 @@ impl PathStyle {
         self
     }
 
+    /// Removes the stroke.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::PathStyle;
+    ///
+    /// let style = PathStyle::default()
+    ///     .with_fill(Color::RED)
+    ///     .with_no_stroke();
+    /// assert_eq!(style.stroke_color, None);
+    /// ```
+    pub fn with_no_stroke(mut self) -> Self {
+        self.stroke_color = None;
+        self
+    }
+
+    /// Removes the fill.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::PathStyle;
+    ///
+    /// let style = PathStyle::fill(Color::RED)
+    ///     .with_stroke(Color::WHITE, 2.0)
+    ///     .with_no_fill();
+    /// assert_eq!(style.fill_color, None);
+    /// ```
+    pub fn with_no_fill(mut self) -> Self {
+        self.fill_color = None;
+        self
+    }
+
     /// Sets the fill rule.
     ///
     /// # Examples
@@ -191,6 +258,132 @@ impl PathStyle {
         self.opacity = opacity.clamp(0.0, 1.0);
         self
     }
+
+    /// Sets the fill opacity, leaving the stroke unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::PathStyle;
+    ///
+    /// let style = PathStyle::fill(Color::RED)
+    ///     .with_fill_opacity(0.3);
+    /// ```
+    pub fn with_fill_opacity(mut self, opacity: f64) -> Self {
+        self.fill_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the stroke opacity, leaving the fill unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::PathStyle;
+    ///
+    /// let style = PathStyle::stroke(Color::BLUE, 2.0)
+    ///     .with_stroke_opacity(0.5);
+    /// ```
+    pub fn with_stroke_opacity(mut self, opacity: f64) -> Self {
+        self.stroke_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the opacity applied to the fill only.
+    ///
+    /// The fill is drawn with this multiplied by
+    /// [`opacity`](PathStyle::opacity).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::PathStyle;
+    ///
+    /// let style = PathStyle::fill(Color::RED).with_fill_opacity(0.3);
+    /// assert_eq!(style.fill_opacity(), 0.3);
+    /// assert_eq!(style.stroke_opacity(), 1.0);
+    /// ```
+    pub fn fill_opacity(&self) -> f64 {
+        self.fill_opacity
+    }
+
+    /// Returns the opacity applied to the stroke only.
+    ///
+    /// The stroke is drawn with this multiplied by
+    /// [`opacity`](PathStyle::opacity).
+    pub fn stroke_opacity(&self) -> f64 {
+        self.stroke_opacity
+    }
+
+    /// Returns a hash key identifying this style.
+    ///
+    /// Floating point fields are quantized before hashing, so styles that
+    /// differ only by rounding noise share a key. Backends can use the key to
+    /// deduplicate paint objects or CSS classes. The key is a fixed FNV-1a
+    /// hash, so it is the same across builds and platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::PathStyle;
+    ///
+    /// let a = PathStyle::stroke(Color::BLUE, 2.0);
+    /// let b = PathStyle::stroke(Color::BLUE, 2.0 + 1e-9);
+    /// assert_eq!(a.cache_key(), b.cache_key());
+    ///
+    /// let c = PathStyle::stroke(Color::RED, 2.0);
+    /// assert_ne!(a.cache_key(), c.cache_key());
+    /// ```
+    pub fn cache_key(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+        hash_color(self.stroke_color, &mut hasher);
+        hasher.write_i64(quantize(self.stroke_width));
+        hash_color(self.fill_color, &mut hasher);
+        hasher.write_u8(match self.fill_rule {
+            PathFillRule::NonZero => 0,
+            PathFillRule::EvenOdd => 1,
+        });
+        hasher.write_i64(quantize(self.opacity));
+        hasher.write_i64(quantize(self.fill_opacity));
+        hasher.write_i64(quantize(self.stroke_opacity));
+        hasher.finish()
+    }
+
+    /// Returns a copy of this style with the set fields of `override_`
+    /// applied.
+    ///
+    /// Fields left as `None` in the override are inherited from `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::{PathStyle, PathStyleOverride};
+    ///
+    /// let base = PathStyle::stroke(Color::BLUE, 2.0);
+    /// let style = base.merge(&PathStyleOverride {
+    ///     stroke_width: Some(4.0),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(style.stroke_color, Some(Color::BLUE));
+    /// assert_eq!(style.stroke_width, 4.0);
+    /// ```
+    pub fn merge(&self, override_: &PathStyleOverride) -> PathStyle {
+        PathStyle {
+            stroke_color: override_.stroke_color.unwrap_or(self.stroke_color),
+            stroke_width: override_.stroke_width.unwrap_or(self.stroke_width),
+            fill_color: override_.fill_color.unwrap_or(self.fill_color),
+            fill_rule: override_.fill_rule.unwrap_or(self.fill_rule),
+            opacity: override_.opacity.unwrap_or(self.opacity),
+            fill_opacity: override_.fill_opacity.unwrap_or(self.fill_opacity),
+            stroke_opacity: override_.stroke_opacity.unwrap_or(self.stroke_opacity),
+        }
+    }
 }
 
 impl Default for PathStyle {
@@ -202,30 +395,58 @@ impl Default for PathStyle {
             fill_color: None,
             fill_rule: PathFillRule::default(),
             opacity: 1.0,
+            fill_opacity: 1.0,
+            stroke_opacity: 1.0,
         }
     }
 }
 
+/// A partial [`PathStyle`] applied on top of a base style with
+/// [`PathStyle::merge`].
+///
+/// `Some` fields replace the base value and `None` fields inherit it. The
+/// color fields are doubly optional so that an override can also remove a
+/// stroke or fill with `Some(None)`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PathStyleOverride {
+    /// Replacement stroke color
+    pub stroke_color: Option<Option<Color>>,
+
+    /// Replacement stroke width
+    pub stroke_width: Option<f64>,
+
+    /// Replacement fill color
+    pub fill_color: Option<Option<Color>>,
+
+    /// Replacement fill rule
+    pub fill_rule: Option<PathFillRule>,
+
+    /// Replacement overall opacity
+    pub opacity: Option<f64>,
+
+    /// Replacement fill opacity
+    pub fill_opacity: Option<f64>,
+
+    /// Replacement stroke opacity
+    pub stroke_opacity: Option<f64>,
+}
+
 /// Font weight for text rendering.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FontWeight {
     /// Normal weight (400)
+    #[default]
     Normal,
 
     /// Bold weight (700)
     Bold,
 }
 
-impl Default for FontWeight {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 /// Text alignment options.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TextAlignment {
     /// Align text to the left
+    #[default]
     Left,
 
     /// Center text
@@ -233,12 +454,30 @@ pub enum TextAlignment {
 
     /// Align text to the right
     Right,
+
+    /// Stretch lines to both edges by spreading whitespace.
+    ///
+    /// Best effort: justification needs a target width and multiple lines,
+    /// so backends that draw single lines lay justified text out like
+    /// [`TextAlignment::Left`].
+    Justify,
 }
 
-impl Default for TextAlignment {
-    fn default() -> Self {
-        Self::Left
-    }
+/// Vertical text alignment relative to the anchor point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlignment {
+    /// The anchor is at the top of the text
+    Top,
+
+    /// The anchor is at the vertical middle of the text
+    Middle,
+
+    /// The anchor is at the bottom of the text, including descenders
+    Bottom,
+
+    /// The anchor is on the alphabetic baseline
+    #[default]
+    Baseline,
 }
 
 /// Style configuration for text rendering.
@@ -273,8 +512,17 @@ pub struct TextStyle {
     /// Text alignment
     pub alignment: TextAlignment,
 
+    /// Vertical alignment relative to the anchor point
+    pub vertical_alignment: VerticalAlignment,
+
     /// Overall opacity (0.0 = transparent, 1.0 = opaque)
     pub opacity: f64,
+
+    /// Outline color drawn behind the glyph fill (None = no outline)
+    pub stroke_color: Option<Color>,
+
+    /// Outline width in pixels
+    pub stroke_width: f64,
 }
 
 impl TextStyle {
@@ -295,7 +543,10 @@ impl TextStyle {
             font_family: "sans-serif".to_string(),
             font_weight: FontWeight::default(),
             alignment: TextAlignment::default(),
+            vertical_alignment: VerticalAlignment::default(),
             opacity: 1.0,
+            stroke_color: None,
+            stroke_width: 0.0,
         }
     }
 
@@ -347,6 +598,22 @@ impl TextStyle {
         self
     }
 
+    /// Sets the vertical alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::{TextStyle, VerticalAlignment};
+    ///
+    /// let style = TextStyle::new(Color::WHITE, 48.0)
+    ///     .with_vertical_alignment(VerticalAlignment::Middle);
+    /// ```
+    pub fn with_vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
     /// Sets the opacity.
     ///
     /// # Examples
@@ -362,6 +629,72 @@ impl TextStyle {
         self.opacity = opacity.clamp(0.0, 1.0);
         self
     }
+
+    /// Sets an outline drawn behind the text fill.
+    ///
+    /// Outlines act as a halo that keeps labels readable over busy
+    /// backgrounds. Negative widths are clamped to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::TextStyle;
+    ///
+    /// let style = TextStyle::new(Color::WHITE, 48.0)
+    ///     .with_stroke(Color::BLACK, 4.0);
+    /// assert_eq!(style.stroke_color, Some(Color::BLACK));
+    /// ```
+    pub fn with_stroke(mut self, color: Color, width: f64) -> Self {
+        self.stroke_color = Some(color);
+        self.stroke_width = width.max(0.0);
+        self
+    }
+
+    /// Removes the text outline.
+    pub fn with_no_stroke(mut self) -> Self {
+        self.stroke_color = None;
+        self
+    }
+
+    /// Returns a copy of this style with the set fields of `override_`
+    /// applied.
+    ///
+    /// Fields left as `None` in the override are inherited from `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::renderer::{TextStyle, TextStyleOverride};
+    ///
+    /// let base = TextStyle::new(Color::WHITE, 48.0);
+    /// let style = base.merge(&TextStyleOverride {
+    ///     font_size: Some(24.0),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(style.color, Color::WHITE);
+    /// assert_eq!(style.font_size, 24.0);
+    /// ```
+    pub fn merge(&self, override_: &TextStyleOverride) -> TextStyle {
+        TextStyle {
+            color: override_.color.unwrap_or(self.color),
+            font_size: override_.font_size.unwrap_or(self.font_size),
+            font_family: override_
+                .font_family
+                .clone()
+                .unwrap_or_else(|| self.font_family.clone()),
+            font_weight: override_.font_weight.unwrap_or(self.font_weight),
+            alignment: override_.alignment.unwrap_or(self.alignment),
+            vertical_alignment: override_
+                .vertical_alignment
+                .unwrap_or(self.vertical_alignment),
+            opacity: override_.opacity.unwrap_or(self.opacity),
+            stroke_color: override_.stroke_color.unwrap_or(self.stroke_color),
+            stroke_width: override_.stroke_width.unwrap_or(self.stroke_width),
+        }
+    }
 }
 
 impl Default for TextStyle {
@@ -371,6 +704,42 @@ impl Default for TextStyle {
     }
 }
 
+/// A partial [`TextStyle`] applied on top of a base style with
+/// [`TextStyle::merge`].
+///
+/// `Some` fields replace the base value and `None` fields inherit it. The
+/// outline color is doubly optional so that an override can also remove the
+/// outline with `Some(None)`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TextStyleOverride {
+    /// Replacement text color
+    pub color: Option<Color>,
+
+    /// Replacement font size
+    pub font_size: Option<f64>,
+
+    /// Replacement font family
+    pub font_family: Option<String>,
+
+    /// Replacement font weight
+    pub font_weight: Option<FontWeight>,
+
+    /// Replacement horizontal alignment
+    pub alignment: Option<TextAlignment>,
+
+    /// Replacement vertical alignment
+    pub vertical_alignment: Option<VerticalAlignment>,
+
+    /// Replacement overall opacity
+    pub opacity: Option<f64>,
+
+    /// Replacement outline color
+    pub stroke_color: Option<Option<Color>>,
+
+    /// Replacement outline width
+    pub stroke_width: Option<f64>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -420,6 +789,17 @@ mod tests {
         assert_eq!(style.fill_color, Some(Color::YELLOW));
     }
 
+    #[test]
+    fn test_path_style_with_no_stroke_and_fill() {
+        let style = PathStyle::default().with_fill(Color::RED).with_no_stroke();
+        assert_eq!(style.stroke_color, None);
+        assert_eq!(style.fill_color, Some(Color::RED));
+
+        let style = style.with_stroke(Color::BLUE, 1.0).with_no_fill();
+        assert_eq!(style.stroke_color, Some(Color::BLUE));
+        assert_eq!(style.fill_color, None);
+    }
+
     #[test]
     fn test_path_style_with_fill_rule() {
         let style = PathStyle::default().with_fill_rule(PathFillRule::EvenOdd);
@@ -463,6 +843,56 @@ mod tests {
         assert_eq!(style1, style2);
     }
 
+    #[test]
+    fn test_path_style_fill_and_stroke_opacity() {
+        let style = PathStyle::default()
+            .with_stroke(Color::BLUE, 2.0)
+            .with_fill(Color::RED)
+            .with_fill_opacity(0.4)
+            .with_stroke_opacity(1.5)
+            .with_opacity(0.5);
+
+        assert_eq!(style.fill_opacity(), 0.4);
+        assert_eq!(style.stroke_opacity(), 1.0);
+        assert_eq!(style.opacity, 0.5);
+    }
+
+    // Cache key tests
+    #[test]
+    fn test_path_style_cache_key_near_identical() {
+        let a = PathStyle::default()
+            .with_stroke(Color::rgba(0.1, 0.2, 0.3, 1.0), 2.0)
+            .with_fill(Color::RED)
+            .with_opacity(0.7);
+        let b = PathStyle::default()
+            .with_stroke(Color::rgba(0.1 + 1e-9, 0.2, 0.3, 1.0), 2.0 - 1e-9)
+            .with_fill(Color::RED)
+            .with_opacity(0.7 + 1e-12);
+
+        assert_eq!(a.cache_key(), b.cache_key());
+    }
+
+    #[test]
+    fn test_path_style_cache_key_differs() {
+        let base = PathStyle::stroke(Color::BLUE, 2.0);
+
+        assert_ne!(
+            base.cache_key(),
+            PathStyle::stroke(Color::BLUE, 3.0).cache_key()
+        );
+        assert_ne!(
+            base.cache_key(),
+            base.clone().with_fill(Color::BLUE).cache_key()
+        );
+        assert_ne!(
+            base.cache_key(),
+            base.clone()
+                .with_fill_rule(PathFillRule::EvenOdd)
+                .cache_key()
+        );
+        assert_ne!(base.cache_key(), base.clone().with_opacity(0.5).cache_key());
+    }
+
     // FontWeight tests
     #[test]
     fn test_font_weight_default() {
@@ -475,6 +905,61 @@ mod tests {
         assert_eq!(TextAlignment::default(), TextAlignment::Left);
     }
 
+    // Override tests
+    #[test]
+    fn test_path_style_merge_stroke_width() {
+        let base = PathStyle::stroke(Color::BLUE, 2.0).with_fill(Color::RED);
+        let merged = base.merge(&PathStyleOverride {
+            stroke_width: Some(5.0),
+            ..Default::default()
+        });
+
+        assert_eq!(merged.stroke_color, Some(Color::BLUE));
+        assert_eq!(merged.fill_color, Some(Color::RED));
+        assert_eq!(merged.stroke_width, 5.0);
+    }
+
+    #[test]
+    fn test_path_style_merge_empty_override() {
+        let base = PathStyle::stroke(Color::BLUE, 2.0).with_opacity(0.5);
+        assert_eq!(base.merge(&PathStyleOverride::default()), base);
+    }
+
+    #[test]
+    fn test_path_style_merge_removes_stroke() {
+        let base = PathStyle::stroke(Color::BLUE, 2.0);
+        let merged = base.merge(&PathStyleOverride {
+            stroke_color: Some(None),
+            ..Default::default()
+        });
+        assert!(merged.stroke_color.is_none());
+    }
+
+    #[test]
+    fn test_text_style_merge() {
+        let base = TextStyle::new(Color::WHITE, 48.0).with_font_family("serif");
+        assert_eq!(base.merge(&TextStyleOverride::default()), base);
+
+        let merged = base.merge(&TextStyleOverride {
+            font_size: Some(12.0),
+            font_weight: Some(FontWeight::Bold),
+            ..Default::default()
+        });
+        assert_eq!(merged.color, Color::WHITE);
+        assert_eq!(merged.font_family, "serif");
+        assert_eq!(merged.font_size, 12.0);
+        assert_eq!(merged.font_weight, FontWeight::Bold);
+    }
+
+    #[test]
+    fn test_vertical_alignment_default() {
+        assert_eq!(VerticalAlignment::default(), VerticalAlignment::Baseline);
+        assert_eq!(
+            TextStyle::default().vertical_alignment,
+            VerticalAlignment::Baseline
+        );
+    }
+
     // TextStyle tests
     #[test]
     fn test_text_style_new() {
@@ -485,6 +970,7 @@ mod tests {
         assert_eq!(style.font_weight, FontWeight::Normal);
         assert_eq!(style.alignment, TextAlignment::Left);
         assert_eq!(style.opacity, 1.0);
+        assert_eq!(style.stroke_color, None);
     }
 
     #[test]
@@ -527,6 +1013,16 @@ mod tests {
         assert_eq!(style2.opacity, 1.0);
     }
 
+    #[test]
+    fn test_text_style_with_stroke() {
+        let style = TextStyle::default().with_stroke(Color::BLACK, 3.0);
+        assert_eq!(style.stroke_color, Some(Color::BLACK));
+        assert_eq!(style.stroke_width, 3.0);
+
+        let plain = style.with_no_stroke();
+        assert_eq!(plain.stroke_color, None);
+    }
+
     #[test]
     fn test_text_style_chaining() {
         let style = TextStyle::new(Color::BLACK, 24.0)
@@ -0,0 +1,419 @@
+//! Parsing of SVG path data (`d` attributes).
+
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use crate::core::{Error, Result, Vector2D};
+
+use super::path::Path;
+
+impl Path {
+    /// Parses SVG path data, as found in the `d` attribute of `<path>`.
+    ///
+    /// Every command of the SVG path grammar is supported in absolute and
+    /// relative form: `M`, `L`, `H`, `V`, `C`, `S`, `Q`, `T`, `A` and `Z`.
+    /// Elliptical arcs (`A`) become cubic Bézier segments of at most a
+    /// quarter turn each, since paths have no arc command. Coordinates are
+    /// taken as-is, without flipping the Y axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the data is malformed, for
+    /// example a missing coordinate or a drawing command before the first
+    /// `M`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::renderer::{Path, PathCommand};
+    ///
+    /// let path = Path::from_svg_d("M 0 0 h 2 v 1 Z").unwrap();
+    /// assert_eq!(
+    ///     path.commands(),
+    ///     &[
+    ///         PathCommand::MoveTo(Vector2D::new(0.0, 0.0)),
+    ///         PathCommand::LineTo(Vector2D::new(2.0, 0.0)),
+    ///         PathCommand::LineTo(Vector2D::new(2.0, 1.0)),
+    ///         PathCommand::Close,
+    ///     ]
+    /// );
+    ///
+    /// // A quarter of the unit circle
+    /// let arc = Path::from_svg_d("M 1 0 A 1 1 0 0 1 0 1").unwrap();
+    /// assert_eq!(arc.points().last(), Some(&Vector2D::new(0.0, 1.0)));
+    ///
+    /// assert!(Path::from_svg_d("L 1 1").is_err());
+    /// ```
+    pub fn from_svg_d(d: &str) -> Result<Path> {
+        let mut parser = Parser {
+            bytes: d.as_bytes(),
+            pos: 0,
+        };
+        let mut path = Path::new();
+        let mut current = Vector2D::ZERO;
+        let mut subpath_start = Vector2D::ZERO;
+        // Control point to reflect for S and T, with the command kind it came from
+        let mut last_control: Option<(u8, Vector2D)> = None;
+        let mut command: Option<u8> = None;
+
+        loop {
+            parser.skip_separators();
+            let Some(next) = parser.peek() else {
+                break;
+            };
+            if next.is_ascii_alphabetic() {
+                parser.pos += 1;
+                command = Some(next);
+            } else if command.is_none() {
+                return Err(parser.error("expected a command"));
+            }
+            let cmd = command.expect("command set above");
+            if path.is_empty() && !cmd.eq_ignore_ascii_case(&b'M') {
+                return Err(parser.error("path data must start with a move"));
+            }
+            let relative = cmd.is_ascii_lowercase();
+            let origin = if relative { current } else { Vector2D::ZERO };
+            let mut control = None;
+
+            match cmd.to_ascii_uppercase() {
+                b'M' => {
+                    let p = origin + parser.point()?;
+                    path.move_to(p);
+                    current = p;
+                    subpath_start = p;
+                    // Further coordinate pairs are implicit line-tos
+                    command = Some(if relative { b'l' } else { b'L' });
+                }
+                b'L' => {
+                    current = origin + parser.point()?;
+                    path.line_to(current);
+                }
+                b'H' => {
+                    let x = parser.number()?;
+                    current.x = if relative { current.x + x } else { x };
+                    path.line_to(current);
+                }
+                b'V' => {
+                    let y = parser.number()?;
+                    current.y = if relative { current.y + y } else { y };
+                    path.line_to(current);
+                }
+                b'C' => {
+                    let c1 = origin + parser.point()?;
+                    let c2 = origin + parser.point()?;
+                    let to = origin + parser.point()?;
+                    path.cubic_to(c1, c2, to);
+                    control = Some((b'C', c2));
+                    current = to;
+                }
+                b'S' => {
+                    let c1 = reflect(last_control, b'C', current);
+                    let c2 = origin + parser.point()?;
+                    let to = origin + parser.point()?;
+                    path.cubic_to(c1, c2, to);
+                    control = Some((b'C', c2));
+                    current = to;
+                }
+                b'Q' => {
+                    let c = origin + parser.point()?;
+                    let to = origin + parser.point()?;
+                    path.quadratic_to(c, to);
+                    control = Some((b'Q', c));
+                    current = to;
+                }
+                b'T' => {
+                    let c = reflect(last_control, b'Q', current);
+                    let to = origin + parser.point()?;
+                    path.quadratic_to(c, to);
+                    control = Some((b'Q', c));
+                    current = to;
+                }
+                b'A' => {
+                    let rx = parser.number()?;
+                    let ry = parser.number()?;
+                    let rotation = parser.number()?.to_radians();
+                    let large_arc = parser.flag()?;
+                    let sweep = parser.flag()?;
+                    let to = origin + parser.point()?;
+                    arc_to(
+                        &mut path,
+                        current,
+                        (rx, ry),
+                        rotation,
+                        (large_arc, sweep),
+                        to,
+                    );
+                    current = to;
+                }
+                b'Z' => {
+                    path.close();
+                    current = subpath_start;
+                    // Z takes no arguments, so numbers after it are an error
+                    command = None;
+                }
+                _ => {
+                    parser.pos -= 1;
+                    return Err(parser.error("unknown command"));
+                }
+            }
+            last_control = control;
+        }
+        Ok(path)
+    }
+}
+
+/// Returns the reflection of the previous control point about `current`,
+/// or `current` itself if the previous segment was not of kind `kind`.
+fn reflect(last: Option<(u8, Vector2D)>, kind: u8, current: Vector2D) -> Vector2D {
+    match last {
+        Some((k, control)) if k == kind => current * 2.0 - control,
+        _ => current,
+    }
+}
+
+/// Appends an SVG elliptical arc from `from` to `to` as cubic segments.
+///
+/// Follows the endpoint-to-center conversion of the SVG specification
+/// (appendix B.2.4), including scaling up radii that are too small to span
+/// the endpoints.
+fn arc_to(
+    path: &mut Path,
+    from: Vector2D,
+    (rx, ry): (f64, f64),
+    rotation: f64,
+    (large_arc, sweep): (bool, bool),
+    to: Vector2D,
+) {
+    if from == to {
+        return;
+    }
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 {
+        path.line_to(to);
+        return;
+    }
+
+    let (sin, cos) = rotation.sin_cos();
+    let half = (from - to) * 0.5;
+    let x1 = cos * half.x + sin * half.y;
+    let y1 = -sin * half.x + cos * half.y;
+
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+    let coef = sign * (numerator / denominator).max(0.0).sqrt();
+    let cx1 = coef * rx * y1 / ry;
+    let cy1 = -coef * ry * x1 / rx;
+    let mid = (from + to) * 0.5;
+    let center = Vector2D::new(cos * cx1 - sin * cy1 + mid.x, sin * cx1 + cos * cy1 + mid.y);
+
+    let angle = |x: f64, y: f64| y.atan2(x);
+    let start = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let end = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+    let mut sweep_angle = end - start;
+    if sweep && sweep_angle < 0.0 {
+        sweep_angle += TAU;
+    } else if !sweep && sweep_angle > 0.0 {
+        sweep_angle -= TAU;
+    }
+
+    // Maps a point on the unit circle onto the rotated ellipse
+    let map = |u: Vector2D| {
+        let (x, y) = (u.x * rx, u.y * ry);
+        center + Vector2D::new(cos * x - sin * y, sin * x + cos * y)
+    };
+    let segments = (sweep_angle.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+    let step = sweep_angle / segments as f64;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    for i in 0..segments {
+        let t0 = start + step * i as f64;
+        let t1 = t0 + step;
+        let (s0, c0) = t0.sin_cos();
+        let (s1, c1) = t1.sin_cos();
+        let control1 = map(Vector2D::new(c0 - k * s0, s0 + k * c0));
+        let control2 = map(Vector2D::new(c1 + k * s1, s1 - k * c1));
+        // Land exactly on the requested endpoint
+        let end_point = if i + 1 == segments {
+            to
+        } else {
+            map(Vector2D::new(c1, s1))
+        };
+        path.cubic_to(control1, control2, end_point);
+    }
+}
+
+/// Cursor over SVG path data.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_separators(&mut self) {
+        while matches!(self.peek(), Some(b) if b.is_ascii_whitespace() || b == b',') {
+            self.pos += 1;
+        }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::InvalidArgument(format!(
+            "invalid SVG path data at byte {}: {}",
+            self.pos, message
+        ))
+    }
+
+    fn number(&mut self) -> Result<f64> {
+        self.skip_separators();
+        let start = self.pos;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut seen_dot = false;
+        while let Some(b) = self.peek() {
+            match b {
+                b'0'..=b'9' => self.pos += 1,
+                // A second dot starts the next number, as in "0.5.5"
+                b'.' if !seen_dot => {
+                    seen_dot = true;
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mark = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if matches!(self.peek(), Some(b'0'..=b'9')) {
+                while matches!(self.peek(), Some(b'0'..=b'9')) {
+                    self.pos += 1;
+                }
+            } else {
+                self.pos = mark;
+            }
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| {
+                self.pos = start;
+                self.error("expected a number")
+            })
+    }
+
+    fn point(&mut self) -> Result<Vector2D> {
+        let x = self.number()?;
+        let y = self.number()?;
+        Ok(Vector2D::new(x, y))
+    }
+
+    /// Reads an arc flag, which may be written without a following separator.
+    fn flag(&mut self) -> Result<bool> {
+        self.skip_separators();
+        match self.peek() {
+            Some(b'0') => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.pos += 1;
+                Ok(true)
+            }
+            _ => Err(self.error("expected an arc flag")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::CubicBezier;
+    use crate::renderer::PathCommand;
+
+    #[test]
+    fn test_from_svg_d_relative_and_implicit_commands() {
+        let path = Path::from_svg_d("m1,1 2,0 0-2zM5 5L6 6 7 5").unwrap();
+        assert_eq!(
+            path.commands(),
+            &[
+                PathCommand::MoveTo(Vector2D::new(1.0, 1.0)),
+                PathCommand::LineTo(Vector2D::new(3.0, 1.0)),
+                PathCommand::LineTo(Vector2D::new(3.0, -1.0)),
+                PathCommand::Close,
+                PathCommand::MoveTo(Vector2D::new(5.0, 5.0)),
+                PathCommand::LineTo(Vector2D::new(6.0, 6.0)),
+                PathCommand::LineTo(Vector2D::new(7.0, 5.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_svg_d_smooth_curves_reflect_controls() {
+        let path = Path::from_svg_d("M0 0 C0 1 1 1 1 0 S2 -1 2 0 Q3 1 4 0 T6 0").unwrap();
+        let commands = path.commands();
+        assert_eq!(
+            commands[2],
+            PathCommand::CubicTo {
+                control1: Vector2D::new(1.0, -1.0),
+                control2: Vector2D::new(2.0, -1.0),
+                to: Vector2D::new(2.0, 0.0),
+            }
+        );
+        assert_eq!(
+            commands[4],
+            PathCommand::QuadraticTo {
+                control: Vector2D::new(5.0, -1.0),
+                to: Vector2D::new(6.0, 0.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_svg_d_quarter_arc() {
+        let radius = 2.0;
+        let path = Path::from_svg_d("M 2 0 A 2 2 0 0 1 0 2").unwrap();
+        let cubics = path.cubics();
+        assert_eq!(cubics.len(), 1);
+        assert_eq!(cubics[0].end(), Vector2D::new(0.0, 2.0));
+
+        let mid = cubics[0].evaluate(0.5);
+        assert!((mid.magnitude() - radius).abs() < 1e-3);
+        // The short arc with positive sweep bulges toward (2, 2), outside the
+        // chord from (2, 0) to (0, 2)
+        assert!(mid.x > 1.0 && mid.y > 1.0);
+    }
+
+    #[test]
+    fn test_from_svg_d_arc_flags_and_radius_scaling() {
+        // Compact flags and radii too small to span the endpoints
+        let path = Path::from_svg_d("M0 0a1 1 0 1010 0").unwrap();
+        let cubics: Vec<CubicBezier> = path.cubics();
+        assert_eq!(cubics.last().unwrap().end(), Vector2D::new(10.0, 0.0));
+        // Scaled up to a half circle of radius 5 about (5, 0)
+        for cubic in &cubics {
+            let mid = cubic.evaluate(0.5);
+            assert!(((mid - Vector2D::new(5.0, 0.0)).magnitude() - 5.0).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_from_svg_d_rejects_malformed_data() {
+        assert!(Path::from_svg_d("M 0").is_err());
+        assert!(Path::from_svg_d("L 1 1").is_err());
+        assert!(Path::from_svg_d("M 0 0 X 1").is_err());
+        assert!(Path::from_svg_d("M 0 0 Z 1").is_err());
+        assert!(Path::from_svg_d("").unwrap().is_empty());
+    }
+}
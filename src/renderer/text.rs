@@ -0,0 +1,171 @@
+//! Approximate text metrics.
+//!
+//! Backends do not expose font data, so layout code estimates text extents
+//! from the font size using typical proportions of a sans-serif face. The
+//! estimates are deterministic and good enough for spacing labels and table
+//! cells, but they are not exact glyph measurements.
+
+use crate::core::{BoundingBox, Vector2D};
+
+use super::{FontWeight, TextAlignment, TextStyle, VerticalAlignment};
+
+/// Average glyph advance of regular text, as a fraction of the font size.
+const REGULAR_ADVANCE: f64 = 0.6;
+
+/// Average glyph advance of bold text, as a fraction of the font size.
+const BOLD_ADVANCE: f64 = 0.65;
+
+/// Height above the baseline, as a fraction of the font size.
+const ASCENT: f64 = 0.8;
+
+/// Depth below the baseline, as a fraction of the font size.
+const DESCENT: f64 = 0.2;
+
+/// Estimates the size of a single line of text.
+///
+/// Returns the width and height as a vector. The height covers ascent and
+/// descent, which together equal the font size.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::Color;
+/// use manim_rs::renderer::{measure_text, TextStyle};
+///
+/// let style = TextStyle::new(Color::WHITE, 10.0);
+/// let size = measure_text("abcd", &style);
+/// assert!((size.x - 24.0).abs() < 1e-10);
+/// assert!((size.y - 10.0).abs() < 1e-10);
+/// ```
+pub fn measure_text(text: &str, style: &TextStyle) -> Vector2D {
+    let advance = match style.font_weight {
+        FontWeight::Normal => REGULAR_ADVANCE,
+        FontWeight::Bold => BOLD_ADVANCE,
+    };
+    let width = text.chars().count() as f64 * advance * style.font_size;
+    Vector2D::new(width, (ASCENT + DESCENT) * style.font_size)
+}
+
+/// Estimates the bounding box of text drawn at `position`.
+///
+/// `position` is the anchor passed to
+/// [`Renderer::draw_text`](super::Renderer::draw_text). Horizontally it is
+/// the left edge, center, or right edge depending on the style's
+/// [`TextAlignment`]; vertically it follows the [`VerticalAlignment`].
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::{Color, Vector2D};
+/// use manim_rs::renderer::{text_bounds, TextAlignment, TextStyle};
+///
+/// let style = TextStyle::new(Color::WHITE, 10.0).with_alignment(TextAlignment::Center);
+/// let bounds = text_bounds("ab", Vector2D::ZERO, &style);
+/// assert!((bounds.center().x).abs() < 1e-10);
+/// assert!(bounds.max().y > 0.0 && bounds.min().y < 0.0);
+/// ```
+pub fn text_bounds(text: &str, position: Vector2D, style: &TextStyle) -> BoundingBox {
+    let width = measure_text(text, style).x;
+    let left = match style.alignment {
+        TextAlignment::Left | TextAlignment::Justify => position.x,
+        TextAlignment::Center => position.x - width / 2.0,
+        TextAlignment::Right => position.x - width,
+    };
+    let height = (ASCENT + DESCENT) * style.font_size;
+    let bottom = match style.vertical_alignment {
+        VerticalAlignment::Top => position.y - height,
+        VerticalAlignment::Middle => position.y - height / 2.0,
+        VerticalAlignment::Bottom => position.y,
+        VerticalAlignment::Baseline => position.y - DESCENT * style.font_size,
+    };
+    BoundingBox::new(
+        Vector2D::new(left, bottom),
+        Vector2D::new(left + width, bottom + height),
+    )
+}
+
+/// Returns the baseline offset that vertically centers text on a point.
+///
+/// Adding the result to a center point gives the anchor to draw at with
+/// [`VerticalAlignment::Baseline`], which every backend supports.
+pub(crate) fn baseline_offset(style: &TextStyle) -> f64 {
+    -(ASCENT - DESCENT) / 2.0 * style.font_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Color;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_measure_text_scales_with_length_and_size() {
+        let style = TextStyle::new(Color::WHITE, 20.0);
+        let short = measure_text("ab", &style);
+        let long = measure_text("abcd", &style);
+
+        assert_relative_eq!(long.x, short.x * 2.0);
+        assert_relative_eq!(short.y, 20.0);
+        assert_eq!(measure_text("", &style).x, 0.0);
+
+        let bold = style.clone().with_weight(FontWeight::Bold);
+        assert!(measure_text("ab", &bold).x > short.x);
+    }
+
+    #[test]
+    fn test_text_bounds_alignment() {
+        let style = TextStyle::new(Color::WHITE, 10.0);
+        let anchor = Vector2D::new(5.0, 0.0);
+
+        let left = text_bounds("abc", anchor, &style);
+        assert_relative_eq!(left.min().x, 5.0);
+
+        let right = text_bounds(
+            "abc",
+            anchor,
+            &style.clone().with_alignment(TextAlignment::Right),
+        );
+        assert_relative_eq!(right.max().x, 5.0);
+        assert_relative_eq!(right.width(), left.width());
+    }
+
+    #[test]
+    fn test_baseline_offset_centers_text() {
+        let style = TextStyle::new(Color::WHITE, 10.0);
+        let center = Vector2D::new(0.0, 3.0);
+        let anchor = center + Vector2D::new(0.0, baseline_offset(&style));
+        let bounds = text_bounds("x", anchor, &style);
+        assert_relative_eq!(bounds.center().y, 3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_text_bounds_vertical_alignment() {
+        let style = TextStyle::new(Color::WHITE, 10.0);
+        let anchor = Vector2D::new(0.0, 5.0);
+
+        let top = text_bounds(
+            "a",
+            anchor,
+            &style
+                .clone()
+                .with_vertical_alignment(VerticalAlignment::Top),
+        );
+        assert_relative_eq!(top.max().y, 5.0);
+
+        let middle = text_bounds(
+            "a",
+            anchor,
+            &style
+                .clone()
+                .with_vertical_alignment(VerticalAlignment::Middle),
+        );
+        assert_relative_eq!(middle.center().y, 5.0);
+
+        let bottom = text_bounds(
+            "a",
+            anchor,
+            &style.with_vertical_alignment(VerticalAlignment::Bottom),
+        );
+        assert_relative_eq!(bottom.min().y, 5.0);
+    }
+}
@@ -0,0 +1,133 @@
+//! Nested coordinate frames shared by renderer backends.
+
+use std::borrow::Cow;
+
+use crate::core::{Error, Result, Transform, Vector2D};
+use crate::renderer::Path;
+
+/// Stack of transforms pushed through [`Renderer::push_transform`].
+///
+/// Backends that expose one from [`Renderer::transform_stack`] get working
+/// default [`Renderer::push_transform`] and [`Renderer::pop_transform`]
+/// implementations, and map their draw calls through
+/// [`TransformStack::apply_path`] and [`TransformStack::apply_point`].
+///
+/// [`Renderer::push_transform`]: crate::renderer::Renderer::push_transform
+/// [`Renderer::pop_transform`]: crate::renderer::Renderer::pop_transform
+/// [`Renderer::transform_stack`]: crate::renderer::Renderer::transform_stack
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::{Transform, Vector2D};
+/// use manim_rs::renderer::TransformStack;
+///
+/// let mut stack = TransformStack::new();
+/// stack.push(&Transform::translate(10.0, 0.0));
+/// stack.push(&Transform::scale(2.0, 2.0));
+///
+/// // The inner scale applies first, then the outer translate
+/// assert_eq!(stack.apply_point(Vector2D::new(1.0, 1.0)), Vector2D::new(12.0, 2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformStack {
+    /// Product in effect before each push, innermost last
+    saved: Vec<Transform>,
+    /// Product of all pushed transforms, outermost first
+    current: Transform,
+}
+
+impl Default for TransformStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransformStack {
+    /// Creates an empty stack, which maps points to themselves.
+    pub fn new() -> Self {
+        Self {
+            saved: Vec::new(),
+            current: Transform::identity(),
+        }
+    }
+
+    /// Pushes `transform` as the innermost coordinate frame.
+    pub fn push(&mut self, transform: &Transform) {
+        self.saved.push(self.current);
+        self.current = self.current * *transform;
+    }
+
+    /// Pops the innermost coordinate frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Render`] if the stack is empty.
+    pub fn pop(&mut self) -> Result<()> {
+        self.current = self
+            .saved
+            .pop()
+            .ok_or_else(|| Error::Render("No transform to pop".to_string()))?;
+        Ok(())
+    }
+
+    /// Returns the number of pushed transforms.
+    pub fn depth(&self) -> usize {
+        self.saved.len()
+    }
+
+    /// Returns the product of all pushed transforms, outermost first.
+    pub fn current(&self) -> Transform {
+        self.current
+    }
+
+    /// Maps a point from the innermost frame to the backend's frame.
+    pub fn apply_point(&self, point: Vector2D) -> Vector2D {
+        self.current.apply(point)
+    }
+
+    /// Maps a path from the innermost frame to the backend's frame.
+    ///
+    /// Borrows `path` unchanged when nothing is pushed.
+    pub fn apply_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        if self.current.is_identity(0.0) {
+            Cow::Borrowed(path)
+        } else {
+            Cow::Owned(path.transformed(&self.current))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_stack_push_and_pop() {
+        let mut stack = TransformStack::new();
+        stack.push(&Transform::translate(1.0, 2.0));
+        stack.push(&Transform::translate(3.0, 4.0));
+        assert_eq!(stack.depth(), 2);
+        assert_eq!(stack.apply_point(Vector2D::ZERO), Vector2D::new(4.0, 6.0));
+
+        stack.pop().unwrap();
+        assert_eq!(stack.apply_point(Vector2D::ZERO), Vector2D::new(1.0, 2.0));
+        stack.pop().unwrap();
+        assert!(stack.current().is_identity(0.0));
+        assert!(stack.pop().is_err());
+    }
+
+    #[test]
+    fn test_transform_stack_apply_path_borrows_when_empty() {
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .line_to(Vector2D::new(1.0, 0.0));
+
+        let mut stack = TransformStack::new();
+        assert!(matches!(stack.apply_path(&path), Cow::Borrowed(_)));
+
+        stack.push(&Transform::translate(0.0, 5.0));
+        let moved = stack.apply_path(&path);
+        assert_eq!(moved.bounding_box().min(), Vector2D::new(0.0, 5.0));
+    }
+}
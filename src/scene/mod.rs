@@ -1,6 +1,22 @@
 //! Scene management and composition.
+//!
+//! A [`Scene`] owns the mobjects to draw, organized into named layers that are
+//! rendered back-to-front.
 
-use crate::core::Error;
+use std::collections::HashMap;
+
+use crate::core::{BoundingBox, Error, Result};
+use crate::mobject::{draw_order, Mobject};
+use crate::renderer::Renderer;
+
+pub(crate) mod snapshot;
+mod theme;
+
+use snapshot::SnapshotRenderer;
+pub use theme::Theme;
+
+/// Name of the layer used by [`Scene::add`].
+pub const DEFAULT_LAYER: &str = "default";
 
 /// Configuration for a scene.
 #[derive(Debug, Clone)]
@@ -22,21 +38,1027 @@ impl Default for SceneConfig {
     }
 }
 
+/// A named compositing layer holding mobjects in draw order.
+struct Layer {
+    name: String,
+    opacity: f64,
+    mobjects: Vec<Box<dyn Mobject>>,
+}
+
+impl Layer {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            opacity: 1.0,
+            mobjects: Vec::new(),
+        }
+    }
+}
+
+/// A step on the scene timeline.
+enum TimelineEvent {
+    /// Holds the current state for the given number of seconds.
+    Wait(f64),
+}
+
 /// A scene containing animated objects.
+///
+/// Mobjects live in named layers. Layers are rendered in the order they were
+/// created, so later layers draw on top of earlier ones; within a layer,
+/// mobjects draw by ascending [`Mobject::z_index`], ties in insertion order.
+/// [`Scene::add`] uses the [`DEFAULT_LAYER`], which is created on first use
+/// like any other layer: layers declared before it, such as a background,
+/// draw underneath it. Declare it with [`Scene::add_layer`] to place it
+/// explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::mobject::geometry::{Circle, Rectangle};
+/// use manim_rs::scene::{Scene, SceneConfig};
+///
+/// let mut scene = Scene::new(SceneConfig::default());
+/// scene.add_layer("background").add_layer("overlay");
+///
+/// scene.add_to_layer("overlay", Box::new(Circle::new(1.0)));
+/// scene.add_to_layer("background", Box::new(Rectangle::new(4.0, 3.0)));
+/// scene.set_layer_opacity("background", 0.5);
+///
+/// assert_eq!(scene.len(), 2);
+/// ```
 pub struct Scene {
-    #[allow(dead_code)] // Will be used when rendering is implemented
     config: SceneConfig,
+    layers: Vec<Layer>,
+    timeline: Vec<TimelineEvent>,
+    theme: Option<Theme>,
+    /// Named mobjects as (layer index, index within the layer)
+    names: HashMap<String, (usize, usize)>,
 }
 
 impl Scene {
     /// Creates a new scene with the given configuration.
     pub fn new(config: SceneConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            layers: Vec::new(),
+            timeline: Vec::new(),
+            theme: None,
+            names: HashMap::new(),
+        }
+    }
+
+    /// Returns the scene configuration.
+    pub fn config(&self) -> &SceneConfig {
+        &self.config
+    }
+
+    /// Returns the scene configuration for editing.
+    ///
+    /// Changes apply to every render made afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.config_mut().background_color = Color::WHITE;
+    /// assert_eq!(scene.config().background_color, Color::WHITE);
+    /// ```
+    pub fn config_mut(&mut self) -> &mut SceneConfig {
+        &mut self.config
+    }
+
+    /// Sets the output size in pixels.
+    pub fn set_dimensions(&mut self, width: u32, height: u32) -> &mut Self {
+        self.config.width = width;
+        self.config.height = height;
+        self
+    }
+
+    /// Sets the frame rate used by [`Scene::render_frames`].
+    pub fn set_fps(&mut self, fps: u32) -> &mut Self {
+        self.config.fps = fps;
+        self
+    }
+
+    /// Adds a mobject to the default layer.
+    ///
+    /// Returns a mutable reference to self for method chaining.
+    pub fn add(&mut self, mobject: Box<dyn Mobject>) -> &mut Self {
+        self.add_to_layer(DEFAULT_LAYER, mobject)
+    }
+
+    /// Adds a mobject to the named layer.
+    ///
+    /// The layer is created on top of all existing layers if it does not
+    /// exist yet. Use [`Scene::add_layer`] to declare layers up front when
+    /// their stacking order matters.
+    ///
+    /// If a [`Theme`] is set, the mobject receives its path style through
+    /// [`Mobject::apply_default_style`] and its text style through
+    /// [`Mobject::apply_default_text_style`] first.
+    pub fn add_to_layer(&mut self, layer: &str, mut mobject: Box<dyn Mobject>) -> &mut Self {
+        if let Some(theme) = &self.theme {
+            mobject.apply_default_style(&theme.path_style);
+            mobject.apply_default_text_style(&theme.text_style);
+        }
+        self.layer_mut(layer).mobjects.push(mobject);
+        self
+    }
+
+    /// Adds a mobject to the default layer under `name`.
+    ///
+    /// The mobject can later be looked up with [`Scene::get_mobject`] or
+    /// [`Scene::get_mobject_mut`]. Reusing a name points it at the new
+    /// mobject; the previous one stays in the scene without a name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.add_named("dot", Box::new(Circle::new(0.1)));
+    ///
+    /// let dot = scene.get_mobject_mut("dot").unwrap();
+    /// dot.set_position(Vector2D::new(1.0, 2.0));
+    /// assert_eq!(scene.get_mobject("dot").unwrap().position(), Vector2D::new(1.0, 2.0));
+    /// ```
+    pub fn add_named(&mut self, name: &str, mobject: Box<dyn Mobject>) -> &mut Self {
+        self.add(mobject);
+        let layer = self
+            .layers
+            .iter()
+            .position(|l| l.name == DEFAULT_LAYER)
+            .expect("default layer exists");
+        let index = self.layers[layer].mobjects.len() - 1;
+        self.names.insert(name.to_string(), (layer, index));
+        self
+    }
+
+    /// Returns the mobject added under `name`, if any.
+    pub fn get_mobject(&self, name: &str) -> Option<&dyn Mobject> {
+        let &(layer, index) = self.names.get(name)?;
+        Some(self.layers[layer].mobjects[index].as_ref())
+    }
+
+    /// Returns the mobject added under `name` for modification, if any.
+    pub fn get_mobject_mut(&mut self, name: &str) -> Option<&mut dyn Mobject> {
+        let &(layer, index) = self.names.get(name)?;
+        Some(self.layers[layer].mobjects[index].as_mut())
+    }
+
+    /// Removes the mobject added under `name` from the scene and returns it.
+    ///
+    /// The name is freed and other named mobjects remain reachable.
+    pub fn remove_named(&mut self, name: &str) -> Option<Box<dyn Mobject>> {
+        let (layer, index) = self.names.remove(name)?;
+        for (l, i) in self.names.values_mut() {
+            if *l == layer && *i > index {
+                *i -= 1;
+            }
+        }
+        Some(self.layers[layer].mobjects.remove(index))
+    }
+
+    /// Sets the theme used to style mobjects added from now on.
+    ///
+    /// The scene's background color is replaced by the theme's. Mobjects that
+    /// are already in the scene keep their current styling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::scene::{Scene, SceneConfig, Theme};
+    ///
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.set_theme(Theme::light());
+    /// scene.add(Box::new(Circle::new(1.0)));
+    ///
+    /// assert_eq!(scene.theme().unwrap().background_color, Color::WHITE);
+    /// ```
+    pub fn set_theme(&mut self, theme: Theme) -> &mut Self {
+        self.config.background_color = theme.background_color;
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Returns the current theme, if one was set.
+    pub fn theme(&self) -> Option<&Theme> {
+        self.theme.as_ref()
+    }
+
+    /// Declares a layer on top of all existing layers.
+    ///
+    /// Does nothing if the layer already exists. The [`DEFAULT_LAYER`] can
+    /// be declared too, to fix where mobjects from [`Scene::add`] draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::{Circle, Rectangle};
+    /// use manim_rs::scene::{Scene, SceneConfig, DEFAULT_LAYER};
+    ///
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.add_layer("background");
+    /// scene.add(Box::new(Circle::new(1.0)));
+    /// scene.add_to_layer("background", Box::new(Rectangle::new(4.0, 3.0)));
+    ///
+    /// assert_eq!(scene.layer_names(), vec!["background", DEFAULT_LAYER]);
+    /// ```
+    pub fn add_layer(&mut self, layer: &str) -> &mut Self {
+        self.layer_mut(layer);
+        self
+    }
+
+    /// Sets the opacity applied to a whole layer when it is composited.
+    ///
+    /// The opacity is clamped to [0, 1] and the layer is created if needed.
+    pub fn set_layer_opacity(&mut self, layer: &str, opacity: f64) -> &mut Self {
+        self.layer_mut(layer).opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the opacity of a layer, or `None` if it does not exist.
+    pub fn layer_opacity(&self, layer: &str) -> Option<f64> {
+        self.layers
+            .iter()
+            .find(|l| l.name == layer)
+            .map(|l| l.opacity)
+    }
+
+    /// Returns the layer names from back to front.
+    pub fn layer_names(&self) -> Vec<&str> {
+        self.layers.iter().map(|l| l.name.as_str()).collect()
+    }
+
+    /// Returns the total number of mobjects across all layers.
+    pub fn len(&self) -> usize {
+        self.layers.iter().map(|l| l.mobjects.len()).sum()
+    }
+
+    /// Returns `true` if the scene contains no mobjects.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the union of the bounding boxes of every visible mobject in
+    /// every layer, or `None` if no mobject is visible.
+    ///
+    /// Hidden mobjects are skipped, as they are when drawing, so the bounds
+    /// cover only what a frame shows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Vector2D;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::mobject::Mobject;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// assert!(scene.content_bounds().is_none());
+    ///
+    /// let mut circle = Circle::builder().radius(1.0).no_stroke().build();
+    /// circle.set_position(Vector2D::new(3.0, 0.0));
+    /// scene.add(Box::new(circle));
+    ///
+    /// let bounds = scene.content_bounds().unwrap();
+    /// assert!((bounds.center().x - 3.0).abs() < 1e-10);
+    /// ```
+    pub fn content_bounds(&self) -> Option<BoundingBox> {
+        self.layers
+            .iter()
+            .flat_map(|layer| &layer.mobjects)
+            .filter(|mobject| mobject.is_visible())
+            .map(|mobject| mobject.bounding_box())
+            .reduce(|acc, bbox| acc.union(&bbox))
+    }
+
+    /// Holds the current state of the scene for `seconds` on the timeline.
+    ///
+    /// Negative and non-finite durations are ignored. The timeline is
+    /// consumed by [`Scene::render_frames`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.wait(1.0).wait(0.5);
+    /// assert_eq!(scene.duration(), 1.5);
+    /// ```
+    pub fn wait(&mut self, seconds: f64) -> &mut Self {
+        if seconds.is_finite() && seconds > 0.0 {
+            self.timeline.push(TimelineEvent::Wait(seconds));
+        }
+        self
+    }
+
+    /// Returns the total duration of the pending timeline in seconds.
+    pub fn duration(&self) -> f64 {
+        self.timeline
+            .iter()
+            .map(|event| match event {
+                TimelineEvent::Wait(seconds) => *seconds,
+            })
+            .sum()
+    }
+
+    /// Renders the whole timeline as numbered SVG frames in `dir`.
+    ///
+    /// Each timeline event spans `seconds * fps` frames, rounded to the
+    /// nearest whole frame. Frames are written as `frame_00000.svg`,
+    /// `frame_00001.svg`, ... and the directory is created if needed. The
+    /// timeline is emptied afterwards, so the next call starts from the
+    /// scene's current state.
+    ///
+    /// Returns the number of frames written.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error raised while drawing or writing a frame.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// # fn main() -> manim_rs::core::Result<()> {
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.add(Box::new(Circle::new(100.0)));
+    /// scene.wait(2.0);
+    /// let frames = scene.render_frames("output/frames")?;
+    /// assert_eq!(frames, 120);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "svg")]
+    pub fn render_frames(&mut self, dir: &str) -> Result<usize> {
+        std::fs::create_dir_all(dir)?;
+        let (width, height) = (self.config.width, self.config.height);
+        self.walk_timeline(|scene, index| {
+            let mut renderer = crate::backends::SvgRenderer::new(width, height);
+            scene.render_frame(&mut renderer)?;
+            let path = std::path::Path::new(dir).join(format!("frame_{:05}.svg", index));
+            renderer.save(&path.to_string_lossy())
+        })
+    }
+
+    /// Renders a single frame of the scene to a file.
+    ///
+    /// The backend is chosen from the file extension: `.svg` uses the SVG
+    /// backend and `.png` the raster backend (requires the `raster` feature).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the extension is not supported by the
+    /// enabled backends, or any error raised while drawing or saving.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// # fn main() -> manim_rs::core::Result<()> {
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.add(Box::new(Circle::new(100.0)));
+    /// scene.render("output/circle.svg")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render(&self, path: &str) -> std::result::Result<(), Error> {
+        let extension = std::path::Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+
+        match extension.as_deref() {
+            #[cfg(feature = "svg")]
+            Some("svg") => self.render_svg(path),
+            #[cfg(feature = "raster")]
+            Some("png") => self.render_png(path),
+            _ => Err(Error::Config(format!(
+                "Unsupported output format for '{}'",
+                path
+            ))),
+        }
+    }
+
+    /// Renders a single frame of the scene to an SVG file.
+    ///
+    /// Unlike [`Scene::render`], the format does not depend on the file
+    /// extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error raised while drawing or saving.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// # fn main() -> manim_rs::core::Result<()> {
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.add(Box::new(Circle::new(100.0)));
+    /// scene.render_svg("output/circle.svg")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "svg")]
+    pub fn render_svg(&self, path: &str) -> Result<()> {
+        let mut renderer = crate::backends::SvgRenderer::new(self.config.width, self.config.height);
+        self.render_frame(&mut renderer)?;
+        renderer.save(path)
+    }
+
+    /// Renders a single frame of the scene to a PNG file.
+    ///
+    /// Unlike [`Scene::render`], the format does not depend on the file
+    /// extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`](crate::core::Error::InvalidArgument)
+    /// if the configured dimensions cannot be allocated, and any error raised
+    /// while drawing or saving.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// # fn main() -> manim_rs::core::Result<()> {
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.add(Box::new(Circle::new(100.0)));
+    /// scene.render_png("output/circle.png")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "raster")]
+    pub fn render_png(&self, path: &str) -> Result<()> {
+        let mut renderer =
+            crate::backends::RasterRenderer::try_new(self.config.width, self.config.height)?;
+        self.render_frame(&mut renderer)?;
+        renderer.save_png(path)
+    }
+
+    /// Draws the scene through any renderer.
+    ///
+    /// Clears to the configured background color, then renders every layer
+    /// back-to-front. Frame boundaries are left to the caller, so call
+    /// [`Renderer::begin_frame`] and [`Renderer::end_frame`] around this as
+    /// the backend requires.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error raised by the renderer or a mobject.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::backends::SvgRenderer;
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::renderer::Renderer;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// # fn main() -> manim_rs::core::Result<()> {
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.add(Box::new(Circle::new(100.0)));
+    ///
+    /// let mut renderer = SvgRenderer::new(1920, 1080);
+    /// renderer.begin_frame()?;
+    /// scene.render_to_renderer(&mut renderer)?;
+    /// renderer.end_frame()?;
+    ///
+    /// assert!(renderer.to_svg_string().contains("<path"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_to_renderer(&self, renderer: &mut dyn Renderer) -> Result<()> {
+        renderer.clear(self.config.background_color)?;
+        self.render_layers(renderer)
+    }
+
+    /// Returns a canonical text snapshot of the current frame.
+    ///
+    /// The scene is drawn through a recording backend that writes one line
+    /// per renderer call, in draw order, with coordinates and style values
+    /// rounded to three decimals. A rendering error ends the snapshot with
+    /// an `error` line. Identical scenes always produce identical
+    /// snapshots, which makes them suitable for golden-file regression tests.
+    /// The exact line format is not part of the stable API and may change
+    /// between releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::mobject::geometry::Circle;
+    /// use manim_rs::scene::{Scene, SceneConfig};
+    ///
+    /// let mut scene = Scene::new(SceneConfig::default());
+    /// scene.add(Box::new(Circle::new(1.0)));
+    ///
+    /// let snapshot = scene.to_snapshot();
+    /// assert!(snapshot.starts_with("begin_frame\nclear"));
+    /// assert_eq!(snapshot.matches("\npath ").count(), 1);
+    /// ```
+    pub fn to_snapshot(&self) -> String {
+        let mut renderer = SnapshotRenderer::new(self.config.width, self.config.height);
+        // The snapshot renderer never fails, but a mobject may; record that
+        // in the snapshot instead of losing it
+        if let Err(error) = self.render_frame(&mut renderer) {
+            renderer.record_error(&error);
+        }
+        renderer.finish()
     }
 
-    /// Renders the scene (placeholder implementation).
-    pub fn render(&self, _path: &str) -> Result<(), Error> {
-        // TODO: Implement rendering
+    /// Renders one complete frame, including the frame boundaries.
+    fn render_frame(&self, renderer: &mut dyn Renderer) -> Result<()> {
+        renderer.begin_frame()?;
+        self.render_to_renderer(renderer)?;
+        renderer.end_frame()
+    }
+
+    /// Consumes the timeline, calling `on_frame` with the scene state and
+    /// index of every frame. Returns the number of frames visited.
+    #[allow(dead_code)] // Unused when no file backend is enabled
+    fn walk_timeline<F>(&mut self, mut on_frame: F) -> Result<usize>
+    where
+        F: FnMut(&Self, usize) -> Result<()>,
+    {
+        let fps = f64::from(self.config.fps);
+        let mut frame = 0;
+        for event in std::mem::take(&mut self.timeline) {
+            match event {
+                TimelineEvent::Wait(seconds) => {
+                    let frames = (seconds * fps).round() as usize;
+                    for _ in 0..frames {
+                        on_frame(self, frame)?;
+                        frame += 1;
+                    }
+                }
+            }
+        }
+        Ok(frame)
+    }
+
+    /// Draws every layer back-to-front, wrapping translucent layers in a
+    /// renderer opacity group.
+    fn render_layers(&self, renderer: &mut dyn Renderer) -> Result<()> {
+        for layer in &self.layers {
+            let grouped = layer.opacity < 1.0;
+            if grouped {
+                renderer.push_group_opacity(layer.opacity)?;
+            }
+            for mobject in draw_order(&layer.mobjects) {
+                mobject.render(renderer)?;
+            }
+            if grouped {
+                renderer.pop_group_opacity()?;
+            }
+        }
         Ok(())
     }
+
+    /// Returns the named layer, creating it on top if it does not exist.
+    fn layer_mut(&mut self, name: &str) -> &mut Layer {
+        let index = match self.layers.iter().position(|l| l.name == name) {
+            Some(index) => index,
+            None => {
+                self.layers.push(Layer::new(name));
+                self.layers.len() - 1
+            }
+        };
+        &mut self.layers[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::{DrawCommand, RecordingRenderer};
+    use crate::core::{Color, Vector2D};
+    use crate::mobject::geometry::Circle;
+    use crate::renderer::TextStyle;
+    use approx::assert_relative_eq;
+
+    /// Summarizes recorded draws as the stroke color of every path, the
+    /// color of every text, and clears and opacity groups.
+    fn events(renderer: &RecordingRenderer) -> Vec<String> {
+        renderer
+            .commands()
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Clear(_) => Some("clear".to_string()),
+                DrawCommand::Path { style, .. } => {
+                    Some(style.stroke_color.map(|c| c.to_hex()).unwrap_or_default())
+                }
+                DrawCommand::Text { style, .. } => Some(format!("text {}", style.color.to_hex())),
+                DrawCommand::PushGroupOpacity(opacity) => Some(format!("push {}", opacity)),
+                DrawCommand::PopGroupOpacity => Some("pop".to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn colored_circle(color: Color) -> Box<dyn Mobject> {
+        let mut circle = Circle::new(1.0);
+        circle.set_stroke(color, 1.0);
+        Box::new(circle)
+    }
+
+    #[test]
+    fn test_scene_add_uses_default_layer() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add(colored_circle(Color::RED));
+
+        assert_eq!(scene.len(), 1);
+        assert_eq!(scene.layer_names(), vec![DEFAULT_LAYER]);
+    }
+
+    #[test]
+    fn test_scene_layers_render_back_to_front() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add_layer("background").add_layer("overlay");
+
+        // Insert overlay content first; it must still draw last
+        scene.add_to_layer("overlay", colored_circle(Color::RED));
+        scene.add_to_layer("background", colored_circle(Color::BLUE));
+        scene.add_to_layer("overlay", colored_circle(Color::GREEN));
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        scene.render_layers(&mut renderer).unwrap();
+
+        assert_eq!(events(&renderer), vec!["#0000FF", "#FF0000", "#00FF00"]);
+    }
+
+    #[test]
+    fn test_scene_background_layer_draws_below_default() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add_layer("background");
+
+        scene.add(colored_circle(Color::RED));
+        scene.add_to_layer("background", colored_circle(Color::BLUE));
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        scene.render_layers(&mut renderer).unwrap();
+
+        assert_eq!(scene.layer_names(), vec!["background", DEFAULT_LAYER]);
+        assert_eq!(events(&renderer), vec!["#0000FF", "#FF0000"]);
+    }
+
+    #[test]
+    fn test_scene_layer_opacity_groups() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add(colored_circle(Color::RED));
+        scene.add_to_layer("overlay", colored_circle(Color::BLUE));
+        scene.set_layer_opacity("overlay", 0.5);
+
+        assert_eq!(scene.layer_opacity("overlay"), Some(0.5));
+        assert_eq!(scene.layer_opacity("missing"), None);
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        scene.render_layers(&mut renderer).unwrap();
+
+        assert_eq!(
+            events(&renderer),
+            vec!["#FF0000", "push 0.5", "#0000FF", "pop"]
+        );
+    }
+
+    #[test]
+    fn test_scene_render_to_renderer() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene
+            .add(colored_circle(Color::RED))
+            .add(colored_circle(Color::GREEN))
+            .add(colored_circle(Color::BLUE));
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        scene.render_to_renderer(&mut renderer).unwrap();
+
+        assert_eq!(
+            events(&renderer),
+            vec!["clear", "#FF0000", "#00FF00", "#0000FF"]
+        );
+    }
+
+    #[test]
+    fn test_scene_render_rejects_unknown_extension() {
+        let scene = Scene::new(SceneConfig::default());
+        assert!(matches!(scene.render("scene.bmp"), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_scene_z_index_orders_draws() {
+        let mut top = colored_circle(Color::RED);
+        top.set_z_index(1);
+
+        let mut scene = Scene::new(SceneConfig::default());
+        scene
+            .add(top)
+            .add(colored_circle(Color::GREEN))
+            .add(colored_circle(Color::BLUE));
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        scene.render_layers(&mut renderer).unwrap();
+
+        assert_eq!(events(&renderer), vec!["#00FF00", "#0000FF", "#FF0000"]);
+    }
+
+    #[test]
+    fn test_scene_add_layer_is_idempotent() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add_layer("overlay").add_layer("overlay");
+
+        assert_eq!(scene.layer_names(), vec!["overlay"]);
+        assert!(scene.is_empty());
+    }
+
+    #[test]
+    fn test_scene_wait_ignores_invalid_durations() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.wait(-1.0).wait(f64::NAN).wait(0.25);
+        assert_eq!(scene.duration(), 0.25);
+    }
+
+    #[test]
+    fn test_scene_walk_timeline_counts_frames() {
+        let mut scene = Scene::new(SceneConfig {
+            fps: 10,
+            ..SceneConfig::default()
+        });
+        scene.wait(1.0).wait(0.25);
+
+        let mut indices = Vec::new();
+        let frames = scene
+            .walk_timeline(|_, index| {
+                indices.push(index);
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(frames, 13);
+        assert_eq!(indices, (0..13).collect::<Vec<_>>());
+        assert_eq!(scene.duration(), 0.0);
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn test_scene_render_frames_wait_holds_state() {
+        let dir = std::env::temp_dir().join(format!("manim_rs_frames_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut scene = Scene::new(SceneConfig {
+            width: 80,
+            height: 60,
+            fps: 10,
+            ..SceneConfig::default()
+        });
+        scene.add(colored_circle(Color::RED));
+        scene.wait(1.0);
+
+        let frames = scene.render_frames(&dir.to_string_lossy()).unwrap();
+        assert_eq!(frames, 10);
+
+        let first = std::fs::read_to_string(dir.join("frame_00000.svg")).unwrap();
+        for index in 1..10 {
+            let frame = std::fs::read_to_string(dir.join(format!("frame_{:05}.svg", index)));
+            assert_eq!(frame.unwrap(), first);
+        }
+        assert!(!dir.join("frame_00010.svg").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_scene_snapshot_is_deterministic() {
+        let build = || {
+            let mut scene = Scene::new(SceneConfig::default());
+            scene.add(colored_circle(Color::RED));
+            scene.add_to_layer("overlay", colored_circle(Color::BLUE));
+            scene.set_layer_opacity("overlay", 0.5);
+            scene
+        };
+
+        let snapshot = build().to_snapshot();
+        assert_eq!(snapshot, build().to_snapshot());
+        assert!(snapshot.contains("push_opacity 0.5"));
+    }
+
+    #[test]
+    fn test_scene_snapshot_changes_when_moved() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add(colored_circle(Color::RED));
+        let before = scene.to_snapshot();
+
+        let mut moved = Scene::new(SceneConfig::default());
+        let mut circle = colored_circle(Color::RED);
+        circle.set_position(Vector2D::new(0.5, 0.0));
+        moved.add(circle);
+
+        assert_ne!(before, moved.to_snapshot());
+    }
+
+    #[test]
+    fn test_scene_theme_styles_unstyled_shapes() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.set_theme(Theme::light());
+        scene.add(Box::new(Circle::new(1.0)));
+        scene.add(colored_circle(Color::RED));
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        scene.render_to_renderer(&mut renderer).unwrap();
+        assert_eq!(
+            events(&renderer),
+            vec!["clear", &Color::BLACK.to_hex(), &Color::RED.to_hex()]
+        );
+        assert_eq!(scene.config.background_color, Color::WHITE);
+    }
+
+    #[test]
+    fn test_scene_theme_styles_unstyled_text() {
+        use crate::mobject::Text;
+
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.set_theme(Theme::light());
+        scene.add(Box::new(Text::new("plain", TextStyle::default())));
+        scene.add(Box::new(Text::new("red", TextStyle::new(Color::RED, 20.0))));
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        scene.render_to_renderer(&mut renderer).unwrap();
+        assert_eq!(
+            events(&renderer),
+            vec![
+                "clear".to_string(),
+                format!("text {}", Color::BLACK.to_hex()),
+                format!("text {}", Color::RED.to_hex()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scene_theme_does_not_restyle_existing() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add(Box::new(Circle::new(1.0)));
+        scene.set_theme(Theme::light());
+
+        let mut renderer = RecordingRenderer::new(800, 600);
+        scene.render_to_renderer(&mut renderer).unwrap();
+        assert_eq!(events(&renderer)[1], Color::WHITE.to_hex());
+    }
+
+    #[cfg(feature = "raster")]
+    #[test]
+    fn test_scene_render_png_writes_png() {
+        let path = std::env::temp_dir().join(format!("manim_rs_scene_{}.png", std::process::id()));
+
+        let mut scene = Scene::new(SceneConfig {
+            width: 40,
+            height: 30,
+            ..SceneConfig::default()
+        });
+        scene.add(colored_circle(Color::RED));
+        scene.render_png(&path.to_string_lossy()).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "raster")]
+    #[test]
+    fn test_scene_render_png_rejects_empty_frame() {
+        let scene = Scene::new(SceneConfig {
+            width: 0,
+            ..SceneConfig::default()
+        });
+        let result = scene.render_png("unused.png");
+        assert!(matches!(
+            result,
+            Err(crate::core::Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn test_scene_render_svg_ignores_extension() {
+        let path = std::env::temp_dir().join(format!("manim_rs_scene_{}.out", std::process::id()));
+
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add(colored_circle(Color::RED));
+        scene.render_svg(&path.to_string_lossy()).unwrap();
+
+        assert!(std::fs::read_to_string(&path).unwrap().contains("<svg"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn test_scene_config_changes_apply_to_renders() {
+        let path = std::env::temp_dir().join(format!("manim_rs_config_{}.svg", std::process::id()));
+
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add(colored_circle(Color::RED));
+        scene.config_mut().width = 320;
+        scene.config_mut().height = 240;
+        scene.render_svg(&path.to_string_lossy()).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        assert!(svg.contains("width=\"320\" height=\"240\""));
+
+        scene.set_dimensions(64, 48).set_fps(24);
+        scene.render_svg(&path.to_string_lossy()).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        assert!(svg.contains("width=\"64\" height=\"48\""));
+        assert_eq!(scene.config().fps, 24);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_named_mobject_lookup_and_mutation() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add(Box::new(Circle::new(1.0)));
+        scene.add_named("title", Box::new(Circle::new(2.0)));
+        assert!(scene.get_mobject("missing").is_none());
+
+        scene
+            .get_mobject_mut("title")
+            .unwrap()
+            .set_position(Vector2D::new(3.0, 0.0));
+        assert_eq!(
+            scene.get_mobject("title").unwrap().position(),
+            Vector2D::new(3.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_remove_named_clears_mapping() {
+        let mut scene = Scene::new(SceneConfig::default());
+        scene.add_named("a", Box::new(Circle::new(1.0)));
+        scene.add_named("b", Box::new(Circle::new(2.0)));
+
+        let removed = scene.remove_named("a").unwrap();
+        assert_relative_eq!(removed.bounding_box().width(), 4.0);
+        assert!(scene.get_mobject("a").is_none());
+        assert!(scene.remove_named("a").is_none());
+        assert_eq!(scene.len(), 1);
+
+        // Later names still resolve after indices shift
+        assert_relative_eq!(scene.get_mobject("b").unwrap().bounding_box().width(), 6.0);
+    }
+
+    #[test]
+    fn test_content_bounds_unions_all_layers() {
+        use crate::mobject::geometry::Square;
+
+        let mut scene = Scene::new(SceneConfig::default());
+        let mut left = Square::builder().side_length(2.0).no_stroke().build();
+        left.set_position(Vector2D::new(-3.0, 0.0));
+        let mut right = Square::builder().side_length(2.0).no_stroke().build();
+        right.set_position(Vector2D::new(4.0, 1.0));
+        let expected = left.bounding_box().union(&right.bounding_box());
+
+        scene.add(Box::new(left));
+        scene.add_to_layer("foreground", Box::new(right));
+
+        let bounds = scene.content_bounds().unwrap();
+        assert_relative_eq!(bounds.min().x, -4.0);
+        assert_relative_eq!(bounds.max().x, 5.0);
+        assert_relative_eq!(bounds.min().y, -1.0);
+        assert_relative_eq!(bounds.max().y, 2.0);
+        assert_eq!(bounds, expected);
+    }
+
+    #[test]
+    fn test_content_bounds_skips_hidden_mobjects() {
+        use crate::mobject::geometry::Square;
+
+        let mut scene = Scene::new(SceneConfig::default());
+        let visible = Square::builder().side_length(2.0).no_stroke().build();
+        let expected = visible.bounding_box();
+        let mut hidden = Square::builder().side_length(2.0).no_stroke().build();
+        hidden.set_position(Vector2D::new(10.0, 0.0));
+        hidden.set_visible(false);
+
+        scene.add(Box::new(hidden));
+        assert!(scene.content_bounds().is_none());
+
+        scene.add(Box::new(visible));
+        assert_eq!(scene.content_bounds().unwrap(), expected);
+    }
 }
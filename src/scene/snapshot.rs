@@ -0,0 +1,254 @@
+//! Deterministic textual snapshots of rendered scenes.
+//!
+//! The [`SnapshotRenderer`] records every draw call as one line of text with
+//! coordinates rounded to [`DECIMALS`] places, so snapshots are stable across
+//! platforms and insensitive to floating point noise.
+
+use crate::core::{Color, Result, Transform, Vector2D};
+use crate::renderer::{Path, PathCommand, PathStyle, Renderer, RendererCapabilities, TextStyle};
+
+/// Number of decimal places kept for coordinates and style values.
+const DECIMALS: usize = 3;
+
+/// Renderer that serializes draw calls into canonical text lines.
+pub(crate) struct SnapshotRenderer {
+    width: u32,
+    height: u32,
+    lines: Vec<String>,
+}
+
+impl SnapshotRenderer {
+    pub(crate) fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Records a rendering error as the final line.
+    pub(crate) fn record_error(&mut self, error: &crate::core::Error) {
+        self.lines.push(format!("error {}", error));
+    }
+
+    /// Returns the recorded lines joined by newlines, with a trailing newline.
+    pub(crate) fn finish(self) -> String {
+        let mut snapshot = self.lines.join("\n");
+        snapshot.push('\n');
+        snapshot
+    }
+}
+
+/// Formats a number rounded to [`DECIMALS`] places without trailing zeros.
+fn num(value: f64) -> String {
+    let formatted = format!("{:.*}", DECIMALS, value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "" | "-0" => "0".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn point(p: Vector2D) -> String {
+    format!("{},{}", num(p.x), num(p.y))
+}
+
+fn color(c: Option<Color>) -> String {
+    c.map(|c| c.to_hex_rgba())
+        .unwrap_or_else(|| "none".to_string())
+}
+
+impl Renderer for SnapshotRenderer {
+    fn begin_frame(&mut self) -> Result<()> {
+        self.lines.push("begin_frame".to_string());
+        Ok(())
+    }
+
+    fn end_frame(&mut self) -> Result<()> {
+        self.lines.push("end_frame".to_string());
+        Ok(())
+    }
+
+    fn clear(&mut self, c: Color) -> Result<()> {
+        self.lines.push(format!("clear {}", c.to_hex_rgba()));
+        Ok(())
+    }
+
+    fn draw_path(&mut self, path: &Path, style: &PathStyle) -> Result<()> {
+        let mut line = String::from("path");
+        for command in path.commands() {
+            line.push(' ');
+            match command {
+                PathCommand::MoveTo(p) => line.push_str(&format!("M{}", point(*p))),
+                PathCommand::LineTo(p) => line.push_str(&format!("L{}", point(*p))),
+                PathCommand::QuadraticTo { control, to } => {
+                    line.push_str(&format!("Q{} {}", point(*control), point(*to)))
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => line.push_str(&format!(
+                    "C{} {} {}",
+                    point(*control1),
+                    point(*control2),
+                    point(*to)
+                )),
+                PathCommand::Close => line.push('Z'),
+            }
+        }
+        line.push_str(&format!(
+            " | stroke={} width={} fill={} rule={:?} opacity={} fill_opacity={} stroke_opacity={}",
+            color(style.stroke_color),
+            num(style.stroke_width),
+            color(style.fill_color),
+            style.fill_rule,
+            num(style.opacity),
+            num(style.fill_opacity()),
+            num(style.stroke_opacity()),
+        ));
+        self.lines.push(line);
+        Ok(())
+    }
+
+    fn draw_text(&mut self, text: &str, position: Vector2D, style: &TextStyle) -> Result<()> {
+        // Destructured so a new style field cannot be left out of snapshots
+        let TextStyle {
+            color: fill,
+            font_size,
+            font_family,
+            font_weight,
+            alignment,
+            vertical_alignment,
+            opacity,
+            stroke_color,
+            stroke_width,
+        } = style;
+        self.lines.push(format!(
+            "text {:?} at {} | color={} size={} family={:?} weight={:?} align={:?} valign={:?} opacity={} stroke={} stroke_width={}",
+            text,
+            point(position),
+            fill.to_hex_rgba(),
+            num(*font_size),
+            font_family,
+            font_weight,
+            alignment,
+            vertical_alignment,
+            num(*opacity),
+            color(*stroke_color),
+            num(*stroke_width),
+        ));
+        Ok(())
+    }
+
+    fn draw_image(
+        &mut self,
+        _pixels: &[u8],
+        width: u32,
+        height: u32,
+        t: &Transform,
+        opacity: f64,
+    ) -> Result<()> {
+        self.lines.push(format!(
+            "image {}x{} matrix {} {} {} {} {} {} | opacity={}",
+            width,
+            height,
+            num(t.a),
+            num(t.b),
+            num(t.c),
+            num(t.d),
+            num(t.tx),
+            num(t.ty),
+            num(opacity)
+        ));
+        Ok(())
+    }
+
+    fn push_group_opacity(&mut self, opacity: f64) -> Result<()> {
+        self.lines.push(format!("push_opacity {}", num(opacity)));
+        Ok(())
+    }
+
+    fn pop_group_opacity(&mut self) -> Result<()> {
+        self.lines.push("pop_opacity".to_string());
+        Ok(())
+    }
+
+    fn push_transform(&mut self, t: &Transform) -> Result<()> {
+        self.lines.push(format!(
+            "push_transform {} {} {} {} {} {}",
+            num(t.a),
+            num(t.b),
+            num(t.c),
+            num(t.d),
+            num(t.tx),
+            num(t.ty)
+        ));
+        Ok(())
+    }
+
+    fn pop_transform(&mut self) -> Result<()> {
+        self.lines.push("pop_transform".to_string());
+        Ok(())
+    }
+
+    fn capabilities(&self) -> RendererCapabilities {
+        RendererCapabilities {
+            text: true,
+            images: true,
+            clipping: false,
+            gradients: false,
+            transparency: true,
+        }
+    }
+
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_rounds_and_trims() {
+        assert_eq!(num(1.0), "1");
+        assert_eq!(num(0.1 + 0.2), "0.3");
+        assert_eq!(num(-0.0001), "0");
+        assert_eq!(num(-2.5), "-2.5");
+    }
+
+    #[test]
+    fn test_snapshot_renderer_records_in_order() {
+        let mut renderer = SnapshotRenderer::new(10, 10);
+        let mut path = Path::new();
+        path.move_to(Vector2D::ZERO)
+            .line_to(Vector2D::new(1.0, 2.0))
+            .close();
+
+        renderer.clear(Color::BLACK).unwrap();
+        renderer.push_group_opacity(0.5).unwrap();
+        renderer
+            .draw_path(&path, &PathStyle::fill(Color::RED))
+            .unwrap();
+        renderer.pop_group_opacity().unwrap();
+
+        let snapshot = renderer.finish();
+        let lines: Vec<&str> = snapshot.lines().collect();
+        assert_eq!(lines[0], "clear #000000FF");
+        assert_eq!(lines[1], "push_opacity 0.5");
+        assert!(lines[2].starts_with("path M0,0 L1,2 Z | stroke=none"));
+        assert_eq!(lines[3], "pop_opacity");
+    }
+
+    #[test]
+    fn test_snapshot_text_includes_stroke() {
+        let mut renderer = SnapshotRenderer::new(10, 10);
+        let style = TextStyle::new(Color::WHITE, 12.0).with_stroke(Color::RED, 1.5);
+        renderer.draw_text("hi", Vector2D::ZERO, &style).unwrap();
+
+        let snapshot = renderer.finish();
+        assert!(snapshot.contains("stroke=#FF0000FF stroke_width=1.5"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Scene-wide default styling.
+
+use crate::core::Color;
+use crate::renderer::{PathStyle, TextStyle};
+
+/// Default styles shared by every mobject in a scene.
+///
+/// Install a theme with [`Scene::set_theme`](super::Scene::set_theme). The
+/// scene then clears to the theme's background color and hands the path
+/// style to each newly added mobject through
+/// [`Mobject::apply_default_style`](crate::mobject::Mobject::apply_default_style),
+/// so shapes that were never given anything but the default style pick up
+/// the theme's stroke and fill. Text still in the default style likewise
+/// picks up the text style
+/// through
+/// [`Mobject::apply_default_text_style`](crate::mobject::Mobject::apply_default_text_style).
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::Color;
+/// use manim_rs::renderer::PathStyle;
+/// use manim_rs::scene::Theme;
+///
+/// let theme = Theme::light();
+/// assert_eq!(theme.background_color, Color::WHITE);
+///
+/// let custom = Theme {
+///     path_style: PathStyle::stroke(Color::BLUE, 4.0),
+///     ..Theme::dark()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// Default stroke and fill for unstyled vector mobjects
+    pub path_style: PathStyle,
+
+    /// Default style for text
+    pub text_style: TextStyle,
+
+    /// Color the scene clears to before drawing
+    pub background_color: Color,
+}
+
+impl Theme {
+    /// Creates a theme with white strokes and text on a black background.
+    ///
+    /// This matches the styling mobjects and scenes have without a theme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::scene::Theme;
+    ///
+    /// let theme = Theme::dark();
+    /// assert_eq!(theme.path_style.stroke_color, Some(Color::WHITE));
+    /// ```
+    pub fn dark() -> Self {
+        Self {
+            path_style: PathStyle::default(),
+            text_style: TextStyle::default(),
+            background_color: Color::BLACK,
+        }
+    }
+
+    /// Creates a theme with black strokes and text on a white background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use manim_rs::core::Color;
+    /// use manim_rs::scene::Theme;
+    ///
+    /// let theme = Theme::light();
+    /// assert_eq!(theme.path_style.stroke_color, Some(Color::BLACK));
+    /// ```
+    pub fn light() -> Self {
+        Self {
+            path_style: PathStyle::stroke(Color::BLACK, 2.0),
+            text_style: TextStyle::new(Color::BLACK, 48.0),
+            background_color: Color::WHITE,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_default_is_dark() {
+        assert_eq!(Theme::default(), Theme::dark());
+    }
+
+    #[test]
+    fn test_theme_light_colors() {
+        let theme = Theme::light();
+        assert_eq!(theme.background_color, Color::WHITE);
+        assert_eq!(theme.path_style.stroke_color, Some(Color::BLACK));
+        assert_eq!(theme.text_style.color, Color::BLACK);
+    }
+}
@@ -0,0 +1,107 @@
+//! Formatting of numeric labels.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Controls how numeric values, such as tick labels, are written as text.
+///
+/// The default rounds to six decimal places and then drops trailing zeros,
+/// which hides floating point noise: `0.1 + 0.2` is written as `"0.3"`.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::utils::LabelFormatter;
+///
+/// let default = LabelFormatter::default();
+/// assert_eq!(default.format(0.1 + 0.2), "0.3");
+/// assert_eq!(default.format(2.0), "2");
+///
+/// let fixed = LabelFormatter::Decimals(2);
+/// assert_eq!(fixed.format(1.0 / 3.0), "0.33");
+///
+/// let pi = LabelFormatter::custom(|x| format!("{}π", x));
+/// assert_eq!(pi.format(2.0), "2π");
+/// ```
+#[derive(Clone)]
+pub enum LabelFormatter {
+    /// At most this many decimal places, with trailing zeros removed
+    Decimals(usize),
+
+    /// A caller-supplied formatting function
+    Custom(Arc<dyn Fn(f64) -> String + Send + Sync>),
+}
+
+impl LabelFormatter {
+    /// Creates a formatter from a function.
+    pub fn custom(f: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        LabelFormatter::Custom(Arc::new(f))
+    }
+
+    /// Formats `value` as a label.
+    pub fn format(&self, value: f64) -> String {
+        match self {
+            LabelFormatter::Decimals(places) => {
+                let formatted = format!("{:.*}", places, value);
+                let trimmed = if formatted.contains('.') {
+                    formatted.trim_end_matches('0').trim_end_matches('.')
+                } else {
+                    &formatted
+                };
+                // Rounding can leave a negative zero, as in "-0.0000001"
+                if trimmed == "-0" {
+                    "0".to_string()
+                } else {
+                    trimmed.to_string()
+                }
+            }
+            LabelFormatter::Custom(f) => f(value),
+        }
+    }
+}
+
+impl Default for LabelFormatter {
+    fn default() -> Self {
+        LabelFormatter::Decimals(6)
+    }
+}
+
+impl fmt::Debug for LabelFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LabelFormatter::Decimals(places) => f.debug_tuple("Decimals").field(places).finish(),
+            LabelFormatter::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_hides_float_noise() {
+        let formatter = LabelFormatter::default();
+        let labels: Vec<String> = (1..=3).map(|i| formatter.format(i as f64 * 0.1)).collect();
+        assert_eq!(labels, vec!["0.1", "0.2", "0.3"]);
+        assert_eq!(formatter.format(0.1 + 0.2), "0.3");
+        assert_eq!(formatter.format(10.0), "10");
+        assert_eq!(formatter.format(-1e-9), "0");
+        assert_eq!(formatter.format(-2.5), "-2.5");
+    }
+
+    #[test]
+    fn test_decimals_zero_keeps_integer_digits() {
+        let formatter = LabelFormatter::Decimals(0);
+        assert_eq!(formatter.format(100.0), "100");
+        assert_eq!(formatter.format(2.6), "3");
+    }
+
+    #[test]
+    fn test_custom_formatter_is_applied() {
+        let formatter =
+            LabelFormatter::custom(|x| format!("{}π", LabelFormatter::default().format(x)));
+        assert_eq!(formatter.format(0.5), "0.5π");
+        assert_eq!(format!("{:?}", formatter), "Custom(..)");
+    }
+}
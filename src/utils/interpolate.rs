@@ -0,0 +1,153 @@
+//! Interpolation helpers.
+//!
+//! Provides scalar tweening functions and the [`Lerp`] trait, which lets
+//! animations interpolate any supported value generically.
+
+use crate::core::{BoundingBox, Color, Vector2D};
+
+/// Linearly interpolates between `a` and `b`.
+///
+/// `t = 0.0` yields `a` and `t = 1.0` yields `b`. Values of `t` outside
+/// [0.0, 1.0] extrapolate.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::utils::interpolate;
+///
+/// assert_eq!(interpolate(2.0, 4.0, 0.5), 3.0);
+/// ```
+#[inline]
+pub fn interpolate(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Returns the factor `t` for which `interpolate(a, b, t) == value`.
+///
+/// Returns 0.0 when `a` and `b` are equal.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::utils::inverse_interpolate;
+///
+/// assert_eq!(inverse_interpolate(2.0, 4.0, 3.0), 0.5);
+/// ```
+#[inline]
+pub fn inverse_interpolate(a: f64, b: f64, value: f64) -> f64 {
+    let span = b - a;
+    if span.abs() < f64::EPSILON {
+        0.0
+    } else {
+        (value - a) / span
+    }
+}
+
+/// Restricts `value` to the range [`lo`, `hi`].
+///
+/// Unlike [`f64::clamp`], this never panics: when `lo > hi` the result is `hi`.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::utils::clamp;
+///
+/// assert_eq!(clamp(1.5, 0.0, 1.0), 1.0);
+/// assert_eq!(clamp(-0.5, 0.0, 1.0), 0.0);
+/// ```
+#[inline]
+pub fn clamp(value: f64, lo: f64, hi: f64) -> f64 {
+    value.max(lo).min(hi)
+}
+
+/// Types that can be linearly interpolated.
+///
+/// # Examples
+///
+/// ```
+/// use manim_rs::core::Vector2D;
+/// use manim_rs::utils::Lerp;
+///
+/// fn midpoint<T: Lerp>(a: T, b: T) -> T {
+///     Lerp::lerp(a, b, 0.5)
+/// }
+///
+/// assert_eq!(midpoint(0.0, 2.0), 1.0);
+/// assert_eq!(
+///     midpoint(Vector2D::ZERO, Vector2D::new(2.0, 4.0)),
+///     Vector2D::new(1.0, 2.0)
+/// );
+/// ```
+pub trait Lerp: Sized {
+    /// Interpolates from `self` (at `t = 0.0`) to `other` (at `t = 1.0`).
+    fn lerp(self, other: Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    #[inline]
+    fn lerp(self, other: Self, t: f64) -> Self {
+        interpolate(self, other, t)
+    }
+}
+
+impl Lerp for Vector2D {
+    #[inline]
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Vector2D::lerp(self, other, t)
+    }
+}
+
+impl Lerp for Color {
+    #[inline]
+    fn lerp(self, other: Self, t: f64) -> Self {
+        Color::lerp(self, other, t)
+    }
+}
+
+impl Lerp for BoundingBox {
+    #[inline]
+    fn lerp(self, other: Self, t: f64) -> Self {
+        BoundingBox::lerp(&self, &other, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(interpolate(0.0, 10.0, 0.0), 0.0);
+        assert_eq!(interpolate(0.0, 10.0, 1.0), 10.0);
+        assert_eq!(interpolate(0.0, 10.0, 0.25), 2.5);
+        assert_eq!(interpolate(0.0, 10.0, 1.5), 15.0);
+    }
+
+    #[test]
+    fn test_inverse_interpolate() {
+        assert_relative_eq!(inverse_interpolate(0.0, 10.0, 2.5), 0.25);
+        assert_relative_eq!(inverse_interpolate(10.0, 0.0, 2.5), 0.75);
+        assert_eq!(inverse_interpolate(5.0, 5.0, 7.0), 0.0);
+    }
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(clamp(0.5, 0.0, 1.0), 0.5);
+        assert_eq!(clamp(-1.0, 0.0, 1.0), 0.0);
+        assert_eq!(clamp(2.0, 0.0, 1.0), 1.0);
+        assert_eq!(clamp(0.5, 1.0, 0.0), 0.0);
+    }
+
+    fn assert_boundaries<T: Lerp + Copy + PartialEq + std::fmt::Debug>(a: T, b: T) {
+        assert_eq!(Lerp::lerp(a, b, 0.0), a);
+        assert_eq!(Lerp::lerp(a, b, 1.0), b);
+    }
+
+    #[test]
+    fn test_lerp_boundaries() {
+        assert_boundaries(1.0, 3.0);
+        assert_boundaries(Vector2D::new(1.0, 2.0), Vector2D::new(5.0, -3.0));
+        assert_boundaries(Color::RED, Color::BLUE);
+    }
+}
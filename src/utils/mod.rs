@@ -1,3 +1,14 @@
 //! Common utilities and helper functions.
+//!
+//! - [`interpolate`]/[`inverse_interpolate`]/[`clamp`] - Scalar tweening helpers
+//! - [`Lerp`] - Generic linear interpolation for animatable values
+//! - [`Rng`] - Seedable random numbers and points for reproducible scenes
+//! - [`LabelFormatter`] - Text formatting for numeric labels
 
-// TODO: Add utility functions as needed
+mod format;
+mod interpolate;
+mod random;
+
+pub use format::LabelFormatter;
+pub use interpolate::{clamp, interpolate, inverse_interpolate, Lerp};
+pub use random::Rng;
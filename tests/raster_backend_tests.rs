@@ -4,7 +4,7 @@
 
 use manim_rs::backends::RasterRenderer;
 use manim_rs::core::{Color, Vector2D};
-use manim_rs::renderer::{Path, PathStyle, Renderer};
+use manim_rs::renderer::{Path, PathFillRule, PathStyle, Renderer};
 use std::fs;
 use std::path::Path as FilePath;
 
@@ -146,3 +146,42 @@ fn test_render_with_transparency() {
 
     assert_eq!(renderer.dimensions(), (400, 300));
 }
+
+/// Renders a five-pointed star drawn in one stroke (so its center is wound
+/// twice) and returns the RGBA of the pixels at the center and in the top arm.
+fn render_pentagram(rule: PathFillRule) -> ([u8; 4], [u8; 4]) {
+    let mut renderer = RasterRenderer::new(100, 100);
+    let points: Vec<Vector2D> = (0..5)
+        .map(|i| {
+            // Every second vertex of a regular pentagon, starting at the top
+            let angle = std::f64::consts::FRAC_PI_2 + i as f64 * 4.0 * std::f64::consts::PI / 5.0;
+            Vector2D::new(40.0 * angle.cos(), 40.0 * angle.sin())
+        })
+        .collect();
+    let star = Path::polyline(&points, true);
+    let style = PathStyle::fill(Color::BLACK).with_fill_rule(rule);
+
+    renderer.clear(Color::WHITE).unwrap();
+    renderer.draw_path(&star, &style).unwrap();
+
+    let pixel = |x: usize, y: usize| {
+        let i = (y * 100 + x) * 4;
+        let data = renderer.data();
+        [data[i], data[i + 1], data[i + 2], data[i + 3]]
+    };
+    (pixel(50, 50), pixel(50, 20))
+}
+
+#[test]
+fn test_fill_rule_even_odd_leaves_hole() {
+    const BLACK: [u8; 4] = [0, 0, 0, 255];
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+
+    let (center, arm) = render_pentagram(PathFillRule::NonZero);
+    assert_eq!(center, BLACK);
+    assert_eq!(arm, BLACK);
+
+    let (center, arm) = render_pentagram(PathFillRule::EvenOdd);
+    assert_eq!(center, WHITE);
+    assert_eq!(arm, BLACK);
+}
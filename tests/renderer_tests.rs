@@ -1,63 +1,19 @@
 //! Integration tests for the rendering system.
 
+use manim_rs::backends::{DrawCommand, RecordingRenderer};
 use manim_rs::core::{Color, Transform, Vector2D};
 use manim_rs::renderer::{
     FontWeight, Path, PathFillRule, PathStyle, Renderer, TextAlignment, TextStyle,
 };
 
-/// Mock renderer for testing purposes.
-struct MockRenderer {
-    width: u32,
-    height: u32,
-    cleared: bool,
-    clear_color: Option<Color>,
-    paths_drawn: usize,
-    texts_drawn: usize,
-}
-
-impl MockRenderer {
-    fn new(width: u32, height: u32) -> Self {
-        Self {
-            width,
-            height,
-            cleared: false,
-            clear_color: None,
-            paths_drawn: 0,
-            texts_drawn: 0,
-        }
-    }
-}
-
-impl Renderer for MockRenderer {
-    fn clear(&mut self, color: Color) -> manim_rs::core::Result<()> {
-        self.cleared = true;
-        self.clear_color = Some(color);
-        Ok(())
-    }
-
-    fn draw_path(&mut self, _path: &Path, _style: &PathStyle) -> manim_rs::core::Result<()> {
-        self.paths_drawn += 1;
-        Ok(())
-    }
-
-    fn draw_text(
-        &mut self,
-        _text: &str,
-        _position: Vector2D,
-        _style: &TextStyle,
-    ) -> manim_rs::core::Result<()> {
-        self.texts_drawn += 1;
-        Ok(())
-    }
-
-    fn dimensions(&self) -> (u32, u32) {
-        (self.width, self.height)
-    }
+/// Counts recorded commands matching a predicate.
+fn count(renderer: &RecordingRenderer, predicate: impl Fn(&DrawCommand) -> bool) -> usize {
+    renderer.commands().iter().filter(|c| predicate(c)).count()
 }
 
 #[test]
 fn test_render_circle() {
-    let mut renderer = MockRenderer::new(1920, 1080);
+    let mut renderer = RecordingRenderer::new(1920, 1080);
 
     // Create a circle-like path using 4 cubic bezier curves
     let mut path = Path::new();
@@ -105,9 +61,11 @@ fn test_render_circle() {
     renderer.end_frame().unwrap();
 
     assert_eq!(renderer.dimensions(), (1920, 1080));
-    assert!(renderer.cleared);
-    assert_eq!(renderer.clear_color, Some(Color::BLACK));
-    assert_eq!(renderer.paths_drawn, 1);
+    assert_eq!(renderer.commands()[1], DrawCommand::Clear(Color::BLACK));
+    assert_eq!(
+        count(&renderer, |c| matches!(c, DrawCommand::Path { .. })),
+        1
+    );
 
     // Verify the path has the right structure
     assert_eq!(path.len(), 6); // MoveTo + 4 CubicTo + Close
@@ -115,7 +73,7 @@ fn test_render_circle() {
 
 #[test]
 fn test_render_rectangle() {
-    let mut renderer = MockRenderer::new(800, 600);
+    let mut renderer = RecordingRenderer::new(800, 600);
 
     let mut path = Path::new();
     path.move_to(Vector2D::new(0.0, 0.0))
@@ -131,7 +89,10 @@ fn test_render_rectangle() {
 
     renderer.draw_path(&path, &style).unwrap();
 
-    assert_eq!(renderer.paths_drawn, 1);
+    assert_eq!(
+        count(&renderer, |c| matches!(c, DrawCommand::Path { .. })),
+        1
+    );
 
     // Verify bounding box
     let bounds = path.bounding_box();
@@ -141,7 +102,7 @@ fn test_render_rectangle() {
 
 #[test]
 fn test_render_text() {
-    let mut renderer = MockRenderer::new(1920, 1080);
+    let mut renderer = RecordingRenderer::new(1920, 1080);
 
     let style = TextStyle::new(Color::WHITE, 48.0)
         .with_font_family("Arial")
@@ -152,7 +113,10 @@ fn test_render_text() {
         .draw_text("Hello, World!", Vector2D::new(960.0, 540.0), &style)
         .unwrap();
 
-    assert_eq!(renderer.texts_drawn, 1);
+    assert_eq!(
+        count(&renderer, |c| matches!(c, DrawCommand::Text { .. })),
+        1
+    );
 }
 
 #[test]
@@ -179,7 +143,7 @@ fn test_transform_application() {
 
 #[test]
 fn test_multiple_shapes() {
-    let mut renderer = MockRenderer::new(1920, 1080);
+    let mut renderer = RecordingRenderer::new(1920, 1080);
 
     // Create multiple shapes
     let mut circle = Path::new();
@@ -209,7 +173,10 @@ fn test_multiple_shapes() {
     renderer.draw_path(&square, &square_style).unwrap();
     renderer.end_frame().unwrap();
 
-    assert_eq!(renderer.paths_drawn, 2);
+    assert_eq!(
+        count(&renderer, |c| matches!(c, DrawCommand::Path { .. })),
+        2
+    );
 }
 
 #[test]
@@ -108,6 +108,28 @@ fn test_render_rectangle_to_svg() {
     assert!(svg.contains("fill"));
 }
 
+#[test]
+fn test_vmobject_fill_opacity_to_svg() {
+    use manim_rs::mobject::{Mobject, VMobject};
+
+    let mut renderer = SvgRenderer::new(800, 600);
+
+    let mut circle = VMobject::new(create_circle_path(2.0));
+    circle
+        .set_stroke(Color::BLUE, 2.0)
+        .set_fill(Color::RED)
+        .set_fill_opacity(0.3)
+        .set_stroke_opacity(1.0);
+
+    renderer.begin_frame().unwrap();
+    circle.render(&mut renderer).unwrap();
+    renderer.end_frame().unwrap();
+
+    let svg = renderer.to_svg_string();
+    assert!(svg.contains("fill-opacity=\"0.300\""));
+    assert!(!svg.contains("stroke-opacity"));
+}
+
 #[test]
 fn test_render_text_to_svg() {
     let mut renderer = SvgRenderer::new(1920, 1080);